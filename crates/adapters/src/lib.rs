@@ -3,7 +3,8 @@
 //! This crate provides two main capabilities:
 //!
 //! 1. **Benchmark Targets**: The canonical `BenchTarget` trait and registry
-//!    for benchmark targets across the LLM Observatory project.
+//!    for benchmark targets across the LLM Observatory project, plus
+//!    `AsyncBenchTarget` for targets that need to await I/O.
 //!
 //! 2. **Upstream Integrations**: Consumption adapters for the LLM-Dev-Ops
 //!    ecosystem, providing integration with:
@@ -35,6 +36,44 @@
 //! }
 //! ```
 //!
+//! # Async Benchmark Targets
+//!
+//! Targets that do network I/O implement [`AsyncBenchTarget`] instead of
+//! [`BenchTarget`]. Every `BenchTarget` is also an `AsyncBenchTarget`, so
+//! sync and async targets can be registered side by side:
+//!
+//! ```ignore
+//! use llm_observatory_adapters::{run_all_benchmarks, AsyncBenchTarget, BenchmarkResult};
+//! use std::sync::Arc;
+//!
+//! struct PingTarget;
+//!
+//! #[async_trait::async_trait]
+//! impl AsyncBenchTarget for PingTarget {
+//!     fn id(&self) -> String {
+//!         "observatory/ping".to_string()
+//!     }
+//!
+//!     async fn run(&self) -> BenchmarkResult {
+//!         let started = std::time::Instant::now();
+//!         reqwest::get("http://localhost:8080/health").await.ok();
+//!         BenchmarkResult::new(
+//!             self.id(),
+//!             serde_json::json!({"latency_ms": started.elapsed().as_millis()}),
+//!         )
+//!     }
+//! }
+//!
+//! let targets: Vec<Arc<dyn AsyncBenchTarget>> = vec![Arc::new(PingTarget)];
+//! let results = run_all_benchmarks(&targets);
+//! ```
+//!
+//! Use [`run_all_benchmarks_with`] and [`RunOptions::with_timeout`] to fail
+//! targets that hang instead of blocking the whole run, and
+//! [`run_all_benchmarks_parallel`] to run independent targets concurrently.
+//! Targets are `Send + Sync` and may run on any thread, so any adapter state
+//! they share must be behind a lock or otherwise thread-safe.
+//!
 //! # Quick Start - Upstream Integrations
 //!
 //! ```ignore
@@ -66,7 +105,15 @@
 
 pub mod upstream;
 
-pub use llm_observatory_benchmarks::BenchmarkResult;
+pub use llm_observatory_benchmarks::{BenchmarkResult, BenchmarkStatus};
+
+use async_trait::async_trait;
+use llm_observatory_core::span::LlmSpan;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 /// Canonical benchmark target trait.
 ///
@@ -102,6 +149,112 @@ pub trait BenchTarget: Send + Sync {
 
     /// Run the benchmark and return results.
     fn run(&self) -> BenchmarkResult;
+
+    /// Categories used to group and filter targets (e.g. "latency", "cost").
+    fn tags(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Human-readable description of what the target measures.
+    fn description(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: BenchTarget + ?Sized> BenchTarget for Box<T> {
+    fn id(&self) -> String {
+        (**self).id()
+    }
+
+    fn run(&self) -> BenchmarkResult {
+        (**self).run()
+    }
+
+    fn tags(&self) -> Vec<String> {
+        (**self).tags()
+    }
+
+    fn description(&self) -> Option<String> {
+        (**self).description()
+    }
+}
+
+/// Benchmark target whose run awaits I/O.
+///
+/// A blanket impl makes every [`BenchTarget`] an `AsyncBenchTarget`, so the
+/// async registry accepts both kinds of target.
+#[async_trait]
+pub trait AsyncBenchTarget: Send + Sync {
+    /// Returns the unique identifier for this benchmark target.
+    fn id(&self) -> String;
+
+    /// Run the benchmark and return results.
+    async fn run(&self) -> BenchmarkResult;
+
+    /// Categories used to group and filter targets (e.g. "latency", "cost").
+    fn tags(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Human-readable description of what the target measures.
+    fn description(&self) -> Option<String> {
+        None
+    }
+}
+
+#[async_trait]
+impl<T: BenchTarget + ?Sized> AsyncBenchTarget for T {
+    fn id(&self) -> String {
+        BenchTarget::id(self)
+    }
+
+    async fn run(&self) -> BenchmarkResult {
+        // Give up the worker while blocking so timers (and timeouts) keep running
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(|| BenchTarget::run(self))
+            }
+            _ => BenchTarget::run(self),
+        }
+    }
+
+    fn tags(&self) -> Vec<String> {
+        BenchTarget::tags(self)
+    }
+
+    fn description(&self) -> Option<String> {
+        BenchTarget::description(self)
+    }
+}
+
+/// Build a [`BenchmarkResult`] from an [`LlmSpan`] recorded during a benchmark.
+///
+/// Bring this trait into scope to call `BenchmarkResult::from_span`.
+pub trait FromLlmSpan {
+    /// Create a result whose metrics describe `span`.
+    fn from_span(target_id: impl Into<String>, span: &LlmSpan) -> Self;
+}
+
+impl FromLlmSpan for BenchmarkResult {
+    /// Metrics hold `latency_ms`, `ttft_ms`, `prompt_tokens`,
+    /// `completion_tokens`, `total_tokens`, `cost_usd`, `provider`, `model`
+    /// and `status`; values the span lacks are `null`. `duration_ms` is set
+    /// from the span latency.
+    fn from_span(target_id: impl Into<String>, span: &LlmSpan) -> Self {
+        let usage = span.token_usage.as_ref();
+        let metrics = serde_json::json!({
+            "latency_ms": span.latency.total_ms,
+            "ttft_ms": span.latency.ttft_ms,
+            "prompt_tokens": usage.map(|u| u.prompt_tokens),
+            "completion_tokens": usage.map(|u| u.completion_tokens),
+            "total_tokens": usage.map(|u| u.total_tokens),
+            "cost_usd": span.cost.as_ref().map(|c| c.amount_usd),
+            "provider": span.provider.to_string(),
+            "model": span.model,
+            "status": span.status,
+        });
+        BenchmarkResult::new(target_id, metrics).with_duration_ms(span.latency.total_ms)
+    }
 }
 
 /// Registry of all available benchmark targets.
@@ -114,6 +267,158 @@ pub fn all_targets() -> Vec<Box<dyn BenchTarget>> {
     Vec::new()
 }
 
+/// Registry of all benchmark targets, sync and async.
+///
+/// Includes every target from [`all_targets`], adapted to [`AsyncBenchTarget`].
+pub fn all_async_targets() -> Vec<Arc<dyn AsyncBenchTarget>> {
+    all_targets()
+        .into_iter()
+        .map(|target| Arc::new(target) as Arc<dyn AsyncBenchTarget>)
+        .collect()
+}
+
+/// Options controlling how benchmark targets are run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Wall-clock limit for each target; `None` waits indefinitely
+    pub timeout: Option<Duration>,
+    /// Maximum number of targets run at once; 0 or 1 runs them sequentially
+    pub concurrency: usize,
+}
+
+impl RunOptions {
+    /// Fail any target that runs longer than `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Run up to `concurrency` targets at once.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+}
+
+/// Run a single target, enforcing the timeout in `options`.
+///
+/// The target runs on its own task, so a target that exceeds the timeout is
+/// abandoned and reported as a failed result ("timed out after ...") rather
+/// than awaited. A panicking target is reported as failed too.
+///
+/// Sync targets only yield to the timeout on a multi-threaded runtime, where
+/// they run via [`tokio::task::block_in_place`].
+pub async fn run_target(
+    target: Arc<dyn AsyncBenchTarget>,
+    options: &RunOptions,
+) -> BenchmarkResult {
+    let id = target.id();
+    let tags = target.tags();
+    let mut task = tokio::spawn(async move { target.run().await });
+
+    let joined = match options.timeout {
+        Some(limit) => match tokio::time::timeout(limit, &mut task).await {
+            Ok(joined) => joined,
+            Err(_) => {
+                task.abort();
+                let reason = format!("timed out after {:?}", limit);
+                return BenchmarkResult::failed(id, reason).with_tags(tags);
+            }
+        },
+        None => task.await,
+    };
+
+    let mut result = joined.unwrap_or_else(|e| BenchmarkResult::failed(id, e.to_string()));
+    if result.tags.is_empty() {
+        result.tags = tags;
+    }
+    result
+}
+
+/// Run the built-in benchmarks followed by each of `targets`.
+///
+/// Sequential runs return results in registration order. With a
+/// [`RunOptions::concurrency`] above 1, up to that many targets run on
+/// separate tasks at once and results are sorted by `target_id` so the
+/// order is deterministic.
+///
+/// Each target's tags are copied onto its result unless the result
+/// already carries tags of its own.
+pub async fn run_all_benchmarks_async(
+    targets: &[Arc<dyn AsyncBenchTarget>],
+    options: &RunOptions,
+) -> Vec<BenchmarkResult> {
+    let mut results = llm_observatory_benchmarks::run_all_benchmarks();
+    if options.concurrency <= 1 {
+        for target in targets {
+            results.push(run_target(Arc::clone(target), options).await);
+        }
+        return results;
+    }
+
+    let permits = Arc::new(Semaphore::new(options.concurrency));
+    let mut tasks = JoinSet::new();
+    for target in targets {
+        let permits = Arc::clone(&permits);
+        let target = Arc::clone(target);
+        let options = options.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            run_target(target, &options).await
+        });
+    }
+    while let Some(joined) = tasks.join_next().await {
+        // run_target reports panics as failed results, so tasks always complete
+        results.push(joined.expect("benchmark task was cancelled"));
+    }
+    results.sort_by(|a, b| a.target_id.cmp(&b.target_id));
+    results
+}
+
+/// Run the built-in benchmarks and `targets` from synchronous code.
+///
+/// Equivalent to [`run_all_benchmarks_with`] using default [`RunOptions`].
+pub fn run_all_benchmarks(targets: &[Arc<dyn AsyncBenchTarget>]) -> Vec<BenchmarkResult> {
+    run_all_benchmarks_with(targets, &RunOptions::default())
+}
+
+/// Run the built-in benchmarks and `targets` from synchronous code.
+///
+/// Targets are driven on a multi-threaded Tokio runtime created for the
+/// call. Use [`run_all_benchmarks_async`] when already inside a runtime.
+///
+/// # Panics
+///
+/// Panics if the runtime cannot be created, or if called from within an
+/// async context.
+pub fn run_all_benchmarks_with(
+    targets: &[Arc<dyn AsyncBenchTarget>],
+    options: &RunOptions,
+) -> Vec<BenchmarkResult> {
+    if targets.is_empty() {
+        return llm_observatory_benchmarks::run_all_benchmarks();
+    }
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build benchmark runtime");
+    let results = runtime.block_on(run_all_benchmarks_async(targets, options));
+    // Don't wait on timed-out targets that are still blocking a thread
+    runtime.shutdown_background();
+    results
+}
+
+/// Run the built-in benchmarks and `targets`, up to `concurrency` at a time.
+///
+/// Results are sorted by `target_id`. See [`run_all_benchmarks_async`].
+pub fn run_all_benchmarks_parallel(
+    targets: &[Arc<dyn AsyncBenchTarget>],
+    concurrency: usize,
+) -> Vec<BenchmarkResult> {
+    let options = RunOptions::default().with_concurrency(concurrency);
+    run_all_benchmarks_with(targets, &options)
+}
+
 // Re-export upstream adapters at crate root for convenience
 // Phase 2A - Compile-time dependency adapters
 pub use upstream::{ConfigAdapter, CostAdapter, LatencyAdapter, SchemaAdapter, SentinelAdapter};
@@ -121,3 +426,203 @@ pub use upstream::{ConfigAdapter, CostAdapter, LatencyAdapter, SchemaAdapter, Se
 pub use upstream::{EdgeAgentAdapter, InferenceGatewayAdapter, OrchestratorAdapter};
 // Phase 2B - Infra integration adapters
 pub use upstream::InfraAdapter;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    struct SyncTarget;
+
+    impl BenchTarget for SyncTarget {
+        fn id(&self) -> String {
+            "test/sync".to_string()
+        }
+
+        fn run(&self) -> BenchmarkResult {
+            BenchmarkResult::new("test/sync", serde_json::json!({"kind": "sync"}))
+        }
+    }
+
+    struct SleepTarget;
+
+    #[async_trait]
+    impl AsyncBenchTarget for SleepTarget {
+        fn id(&self) -> String {
+            "test/sleep".to_string()
+        }
+
+        async fn run(&self) -> BenchmarkResult {
+            let started = Instant::now();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            BenchmarkResult::new(
+                self.id(),
+                serde_json::json!({"elapsed_ms": started.elapsed().as_millis() as u64}),
+            )
+        }
+    }
+
+    #[test]
+    fn test_run_all_benchmarks_drives_async_targets() {
+        let targets: Vec<Arc<dyn AsyncBenchTarget>> =
+            vec![Arc::new(SyncTarget), Arc::new(SleepTarget)];
+
+        let results = run_all_benchmarks(&targets);
+        let ids: Vec<&str> = results.iter().map(|r| r.target_id.as_str()).collect();
+        assert_eq!(ids, ["observatory/system", "test/sync", "test/sleep"]);
+        assert!(results[2].metrics["elapsed_ms"].as_u64().unwrap() >= 10);
+    }
+
+    struct TaggedTarget;
+
+    impl BenchTarget for TaggedTarget {
+        fn id(&self) -> String {
+            "test/tagged".to_string()
+        }
+
+        fn run(&self) -> BenchmarkResult {
+            BenchmarkResult::new("test/tagged", serde_json::json!({}))
+        }
+
+        fn tags(&self) -> Vec<String> {
+            vec!["latency".to_string()]
+        }
+
+        fn description(&self) -> Option<String> {
+            Some("Tagged test target".to_string())
+        }
+    }
+
+    #[test]
+    fn test_filter_results_by_target_tag() {
+        let targets: Vec<Arc<dyn AsyncBenchTarget>> =
+            vec![Arc::new(SyncTarget), Arc::new(TaggedTarget)];
+        let description = targets[1].description();
+        assert_eq!(description.as_deref(), Some("Tagged test target"));
+
+        let results = run_all_benchmarks(&targets);
+        let latency: Vec<_> = results
+            .iter()
+            .filter(|r| r.matches_filter("latency"))
+            .collect();
+        assert_eq!(latency.len(), 1);
+        assert_eq!(latency[0].target_id, "test/tagged");
+        assert_eq!(latency[0].tags, ["latency"]);
+    }
+
+    struct HangingTarget;
+
+    impl BenchTarget for HangingTarget {
+        fn id(&self) -> String {
+            "test/hang".to_string()
+        }
+
+        fn run(&self) -> BenchmarkResult {
+            std::thread::sleep(Duration::from_secs(5));
+            BenchmarkResult::new("test/hang", serde_json::json!({}))
+        }
+    }
+
+    #[test]
+    fn test_timeout_fails_hanging_target() {
+        let targets: Vec<Arc<dyn AsyncBenchTarget>> =
+            vec![Arc::new(HangingTarget), Arc::new(SleepTarget)];
+        let options = RunOptions::default().with_timeout(Duration::from_millis(200));
+
+        let started = Instant::now();
+        let results = run_all_benchmarks_with(&targets, &options);
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        assert_eq!(results[1].target_id, "test/hang");
+        assert_eq!(results[1].status, BenchmarkStatus::Failed);
+        assert_eq!(results[1].error.as_deref(), Some("timed out after 200ms"));
+        assert!(!results[2].is_failed());
+    }
+
+    #[test]
+    fn test_parallel_run_matches_sequential() {
+        let targets: Vec<Arc<dyn AsyncBenchTarget>> = vec![
+            Arc::new(TaggedTarget),
+            Arc::new(SleepTarget),
+            Arc::new(SyncTarget),
+        ];
+        let summarize = |results: Vec<BenchmarkResult>| {
+            let mut summary: Vec<(String, Vec<String>, bool)> = results
+                .into_iter()
+                .map(|r| (r.target_id.clone(), r.tags.clone(), r.is_failed()))
+                .collect();
+            summary.sort();
+            summary
+        };
+
+        let parallel = run_all_benchmarks_parallel(&targets, 4);
+        let ids: Vec<&str> = parallel.iter().map(|r| r.target_id.as_str()).collect();
+        let mut sorted = ids.clone();
+        sorted.sort_unstable();
+        assert_eq!(ids, sorted);
+        assert_eq!(ids.len(), 4);
+
+        let sequential = run_all_benchmarks(&targets);
+        assert_eq!(summarize(parallel), summarize(sequential));
+    }
+
+    #[test]
+    fn test_result_from_span_metrics() {
+        use chrono::Utc;
+        use llm_observatory_core::span::{LlmInput, SpanStatus};
+        use llm_observatory_core::types::{Cost, Latency, Provider, TokenUsage};
+
+        let start = Utc::now();
+        let end = start + chrono::Duration::milliseconds(350);
+        let span = LlmSpan::builder()
+            .span_id("span_1")
+            .trace_id("trace_1")
+            .name("llm.completion")
+            .provider(Provider::Anthropic)
+            .model("claude-3-5-sonnet")
+            .input(LlmInput::Text {
+                prompt: "Hello".to_string(),
+            })
+            .token_usage(TokenUsage::new(120, 80))
+            .cost(Cost::new(0.0042))
+            .latency(Latency::new(start, end))
+            .status(SpanStatus::Ok)
+            .build()
+            .unwrap();
+
+        let result = BenchmarkResult::from_span("llm/completion", &span);
+        assert_eq!(result.target_id, "llm/completion");
+        assert_eq!(result.duration_ms, Some(350));
+
+        let metrics = result.metrics.as_object().unwrap();
+        let mut keys: Vec<&str> = metrics.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "completion_tokens",
+                "cost_usd",
+                "latency_ms",
+                "model",
+                "prompt_tokens",
+                "provider",
+                "status",
+                "total_tokens",
+                "ttft_ms",
+            ]
+        );
+        assert_eq!(metrics["latency_ms"], 350);
+        assert_eq!(metrics["total_tokens"], 200);
+        assert_eq!(metrics["cost_usd"], 0.0042);
+        assert_eq!(metrics["provider"], "anthropic");
+        assert_eq!(metrics["status"], "OK");
+        assert!(metrics["ttft_ms"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_sync_target_satisfies_async_registry() {
+        let target: Arc<dyn AsyncBenchTarget> = Arc::new(SyncTarget);
+        assert_eq!(target.id(), "test/sync");
+        assert_eq!(target.run().await.metrics["kind"], "sync");
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pluggable time source for adapter timestamps.
+//!
+//! Adapters read the current time through a [`Clock`] instead of calling
+//! `Utc::now()` directly, so timestamp-dependent behavior can be tested
+//! deterministically.
+//!
+//! # Features
+//!
+//! - [`SystemClock`]: the wall clock, used by default
+//! - [`MockClock`]: a manually set and advanced clock for tests
+//!
+//! # Example
+//!
+//! ```ignore
+//! use llm_observatory_adapters::upstream::prelude::*;
+//! use std::sync::Arc;
+//!
+//! let clock = Arc::new(MockClock::new(start));
+//! let mut adapter = OrchestratorAdapter::with_clock("orch-1", clock.clone());
+//! clock.advance(chrono::Duration::seconds(5));
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Mutex;
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    /// Current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the system wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that only moves when told to.
+///
+/// Share it with an adapter through an `Arc` and keep a handle to set or
+/// advance the time between calls.
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl MockClock {
+    /// Create a clock stopped at `now`.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Set the current time.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Move the current time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += duration;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_set_and_advance() {
+        let start = Utc::now();
+        let clock = MockClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::milliseconds(1500));
+        assert_eq!(clock.now(), start + Duration::milliseconds(1500));
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+        assert!(SystemClock.now() >= start);
+    }
+}
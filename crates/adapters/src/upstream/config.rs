@@ -9,7 +9,8 @@
 //! # Features
 //!
 //! - Configuration loading from Config Manager
-//! - Environment-specific configuration retrieval
+//! - Environment-specific configuration retrieval, layering per-environment
+//!   overrides over base values over built-in defaults
 //! - Secret management support
 //! - Configuration versioning
 //!
@@ -59,6 +60,19 @@ pub enum ConfigAdapterError {
     /// Environment parse error
     #[error("Invalid environment: {0}")]
     InvalidEnvironment(String),
+
+    /// Configuration value out of range or otherwise invalid
+    #[error("Invalid configuration value for {key}: {reason}")]
+    InvalidValue {
+        /// Configuration key
+        key: String,
+        /// Why the value was rejected
+        reason: String,
+    },
+
+    /// Persistence error while saving or reloading configuration
+    #[error("Config persistence error: {0}")]
+    PersistenceError(String),
 }
 
 impl From<ConfigError> for ConfigAdapterError {
@@ -70,6 +84,9 @@ impl From<ConfigError> for ConfigAdapterError {
 /// Result type for configuration operations.
 pub type Result<T> = std::result::Result<T, ConfigAdapterError>;
 
+/// File name used to persist the configuration cache under the storage path.
+pub const CONFIG_FILE_NAME: &str = "observatory-config.json";
+
 /// Observatory-specific configuration keys.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ObservatoryConfigKey {
@@ -96,6 +113,29 @@ pub enum ObservatoryConfigKey {
 }
 
 impl ObservatoryConfigKey {
+    /// All known configuration keys.
+    pub fn all() -> [Self; 10] {
+        [
+            Self::OtlpEndpoint,
+            Self::OtlpPort,
+            Self::SamplingRate,
+            Self::EnablePiiRedaction,
+            Self::EnableCostCalculation,
+            Self::BatchSize,
+            Self::BatchTimeoutMs,
+            Self::DatabaseUrl,
+            Self::RedisUrl,
+            Self::LogLevel,
+        ]
+    }
+
+    /// Look up a key by its namespace and key name.
+    pub fn from_path(namespace: &str, key: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|k| k.namespace() == namespace && k.key() == key)
+    }
+
     /// Get the configuration namespace for this key.
     pub fn namespace(&self) -> &'static str {
         match self {
@@ -140,10 +180,154 @@ impl ObservatoryConfigKey {
             Self::LogLevel => ConfigValue::String("info".to_string()),
         }
     }
+
+    /// Validate a value for this key.
+    ///
+    /// - `SamplingRate` must be a float in `0.0..=1.0`
+    /// - `OtlpPort` must be an integer in `1..=65535`
+    /// - `BatchSize` and `BatchTimeoutMs` must be positive integers
+    /// - Feature flags must be booleans
+    /// - Endpoints, URLs and the log level must be non-empty strings
+    pub fn validate(&self, value: &ConfigValue) -> Result<()> {
+        let invalid = |reason: String| ConfigAdapterError::InvalidValue {
+            key: self.key().to_string(),
+            reason,
+        };
+        let wrong_type = |expected: &str| ConfigAdapterError::InvalidType {
+            key: self.key().to_string(),
+            expected: expected.to_string(),
+            actual: value_type_name(value).to_string(),
+        };
+
+        match self {
+            Self::SamplingRate => match value {
+                ConfigValue::Float(f) if (0.0..=1.0).contains(f) => Ok(()),
+                ConfigValue::Float(f) => Err(invalid(format!("{} is outside 0.0-1.0", f))),
+                _ => Err(wrong_type("float")),
+            },
+            Self::OtlpPort => match value {
+                ConfigValue::Integer(i) if (1..=65535).contains(i) => Ok(()),
+                ConfigValue::Integer(i) => Err(invalid(format!("{} is outside 1-65535", i))),
+                _ => Err(wrong_type("integer")),
+            },
+            Self::BatchSize | Self::BatchTimeoutMs => match value {
+                ConfigValue::Integer(i) if *i > 0 => Ok(()),
+                ConfigValue::Integer(i) => Err(invalid(format!("{} must be positive", i))),
+                _ => Err(wrong_type("integer")),
+            },
+            Self::EnablePiiRedaction | Self::EnableCostCalculation => match value {
+                ConfigValue::Boolean(_) => Ok(()),
+                _ => Err(wrong_type("boolean")),
+            },
+            // Endpoints, URLs and the log level
+            _ => match value {
+                ConfigValue::String(s) if !s.trim().is_empty() => Ok(()),
+                ConfigValue::String(_) => Err(invalid("must not be empty".to_string())),
+                _ => Err(wrong_type("string")),
+            },
+        }
+    }
+
+    /// Parse a raw string (e.g. from an environment variable) into a value
+    /// of the type expected by this key.
+    pub fn parse_value(&self, raw: &str) -> Result<ConfigValue> {
+        let invalid = |expected: &str| ConfigAdapterError::InvalidValue {
+            key: self.key().to_string(),
+            reason: format!("expected {}, got {:?}", expected, raw),
+        };
+
+        match self {
+            Self::OtlpPort | Self::BatchSize | Self::BatchTimeoutMs => raw
+                .trim()
+                .parse::<i64>()
+                .map(ConfigValue::Integer)
+                .map_err(|_| invalid("an integer")),
+            Self::SamplingRate => raw
+                .trim()
+                .parse::<f64>()
+                .map(ConfigValue::Float)
+                .map_err(|_| invalid("a float")),
+            Self::EnablePiiRedaction | Self::EnableCostCalculation => {
+                match raw.trim().to_lowercase().as_str() {
+                    "true" | "1" | "yes" => Ok(ConfigValue::Boolean(true)),
+                    "false" | "0" | "no" => Ok(ConfigValue::Boolean(false)),
+                    _ => Err(invalid("a boolean")),
+                }
+            }
+            _ => Ok(ConfigValue::String(raw.to_string())),
+        }
+    }
+
+    /// Convert a scalar from a structured config file into a value of the
+    /// type expected by this key.
+    ///
+    /// Strings are parsed as with [`Self::parse_value`], and integers are
+    /// widened to floats for float-typed keys.
+    pub fn coerce_value(&self, value: &serde_json::Value) -> Result<ConfigValue> {
+        match value {
+            serde_json::Value::String(s) => self.parse_value(s),
+            serde_json::Value::Bool(b) => Ok(ConfigValue::Boolean(*b)),
+            serde_json::Value::Number(n) => match (self, n.as_i64()) {
+                (Self::SamplingRate, _) | (_, None) => {
+                    Ok(ConfigValue::Float(n.as_f64().unwrap_or(f64::NAN)))
+                }
+                (_, Some(i)) => Ok(ConfigValue::Integer(i)),
+            },
+            other => Err(ConfigAdapterError::InvalidValue {
+                key: self.key().to_string(),
+                reason: format!("expected a scalar, got {}", other),
+            }),
+        }
+    }
+}
+
+/// Human-readable type name of a configuration value.
+fn value_type_name(value: &ConfigValue) -> &'static str {
+    match value {
+        ConfigValue::String(_) => "string",
+        ConfigValue::Integer(_) => "integer",
+        ConfigValue::Float(_) => "float",
+        ConfigValue::Boolean(_) => "boolean",
+        _ => "other",
+    }
+}
+
+/// Strongly-typed snapshot of the resolved Observatory configuration.
+///
+/// Produced by [`ConfigAdapter::snapshot`]; every field is resolved from the
+/// cache, falling back to [`ObservatoryConfigKey::default_value`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObservatoryConfig {
+    /// OTLP receiver endpoint
+    pub otlp_endpoint: String,
+    /// OTLP receiver port
+    pub otlp_port: u16,
+    /// Sampling rate (0.0 - 1.0)
+    pub sampling_rate: f64,
+    /// Enable PII redaction
+    pub enable_pii_redaction: bool,
+    /// Enable cost calculation
+    pub enable_cost_calculation: bool,
+    /// Batch size for processing
+    pub batch_size: u64,
+    /// Batch timeout in milliseconds
+    pub batch_timeout_ms: u64,
+    /// Database connection URL
+    pub database_url: String,
+    /// Redis connection URL
+    pub redis_url: String,
+    /// Log level
+    pub log_level: String,
+}
+
+impl Default for ObservatoryConfig {
+    fn default() -> Self {
+        ConfigAdapter::in_memory().snapshot()
+    }
 }
 
 /// Parsed environment for Observatory.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ObservatoryEnvironment {
     /// Development environment
     Development,
@@ -187,6 +371,8 @@ pub struct ConfigAdapter {
     default_environment: ObservatoryEnvironment,
     /// In-memory configuration cache
     cache: HashMap<String, ConfigValue>,
+    /// Per-environment overrides of `cache`
+    env_overrides: HashMap<ObservatoryEnvironment, HashMap<String, ConfigValue>>,
 }
 
 impl ConfigAdapter {
@@ -204,6 +390,7 @@ impl ConfigAdapter {
             storage_path: path.to_string_lossy().to_string(),
             default_environment: ObservatoryEnvironment::Development,
             cache: HashMap::new(),
+            env_overrides: HashMap::new(),
         })
     }
 
@@ -213,6 +400,7 @@ impl ConfigAdapter {
             storage_path: String::new(),
             default_environment: ObservatoryEnvironment::Development,
             cache: HashMap::new(),
+            env_overrides: HashMap::new(),
         }
     }
 
@@ -233,20 +421,103 @@ impl ConfigAdapter {
     }
 
     /// Get a configuration value using an Observatory config key.
+    ///
+    /// Resolves for the default environment; see [`Self::get_for`].
     pub fn get(&self, key: ObservatoryConfigKey) -> ConfigValue {
+        self.get_for(self.default_environment, key)
+    }
+
+    /// Get a configuration value as seen by `env`.
+    ///
+    /// An override set for `env` wins, then the base value, then the key's
+    /// built-in default.
+    pub fn get_for(&self, env: ObservatoryEnvironment, key: ObservatoryConfigKey) -> ConfigValue {
         let cache_key = format!("{}/{}", key.namespace(), key.key());
-        self.cache
-            .get(&cache_key)
+        self.env_overrides
+            .get(&env)
+            .and_then(|overrides| overrides.get(&cache_key))
+            .or_else(|| self.cache.get(&cache_key))
             .cloned()
             .unwrap_or_else(|| key.default_value())
     }
 
+    /// Override a configuration value for one environment only.
+    ///
+    /// Like [`Self::set`], the value is stored without validation.
+    pub fn set_for(
+        &mut self,
+        env: ObservatoryEnvironment,
+        key: ObservatoryConfigKey,
+        value: ConfigValue,
+    ) {
+        let cache_key = format!("{}/{}", key.namespace(), key.key());
+        self.env_overrides
+            .entry(env)
+            .or_default()
+            .insert(cache_key, value);
+    }
+
     /// Set a configuration value in the cache.
+    ///
+    /// The value is stored without validation; prefer [`Self::set_checked`].
     pub fn set(&mut self, key: ObservatoryConfigKey, value: ConfigValue) {
         let cache_key = format!("{}/{}", key.namespace(), key.key());
         self.cache.insert(cache_key, value);
     }
 
+    /// Validate and set a configuration value in the cache.
+    ///
+    /// See [`ObservatoryConfigKey::validate`] for the rules applied.
+    pub fn set_checked(&mut self, key: ObservatoryConfigKey, value: ConfigValue) -> Result<()> {
+        key.validate(&value)?;
+        self.set(key, value);
+        Ok(())
+    }
+
+    /// Resolve every configuration key into a typed [`ObservatoryConfig`].
+    ///
+    /// Cached values that fail [`ObservatoryConfigKey::validate`] (e.g. ones
+    /// stored through the unchecked [`Self::set`]) fall back to the default.
+    pub fn snapshot(&self) -> ObservatoryConfig {
+        use ObservatoryConfigKey as Key;
+
+        let resolve = |key: Key| {
+            let value = self.get(key);
+            if key.validate(&value).is_ok() {
+                value
+            } else {
+                key.default_value()
+            }
+        };
+        let string = |key: Key| match resolve(key) {
+            ConfigValue::String(s) => s,
+            _ => String::new(),
+        };
+        let integer = |key: Key| match resolve(key) {
+            ConfigValue::Integer(i) => i,
+            _ => 0,
+        };
+        let boolean = |key: Key| matches!(resolve(key), ConfigValue::Boolean(true));
+        let float = |key: Key| match resolve(key) {
+            ConfigValue::Float(f) => f,
+            _ => 0.0,
+        };
+
+        // Validation guarantees the integer ranges below fit their targets
+        ObservatoryConfig {
+            otlp_endpoint: string(Key::OtlpEndpoint),
+            otlp_port: integer(Key::OtlpPort) as u16,
+            sampling_rate: float(Key::SamplingRate),
+            enable_pii_redaction: boolean(Key::EnablePiiRedaction),
+            enable_cost_calculation: boolean(Key::EnableCostCalculation),
+            batch_size: integer(Key::BatchSize) as u64,
+            batch_timeout_ms: integer(Key::BatchTimeoutMs) as u64,
+            database_url: string(Key::DatabaseUrl),
+            redis_url: string(Key::RedisUrl),
+            log_level: string(Key::LogLevel),
+        }
+    }
+
     /// Get a string configuration value.
     pub fn get_string(&self, key: ObservatoryConfigKey) -> Option<String> {
         match self.get(key) {
@@ -281,8 +552,10 @@ impl ConfigAdapter {
 
     /// Load configuration from environment variables.
     ///
-    /// Environment variables should be prefixed with `LLMOBS_`.
-    pub fn load_from_env(&mut self) {
+    /// Environment variables should be prefixed with `LLMOBS_`. Values are
+    /// parsed and validated through [`Self::set_checked`]; the first invalid
+    /// value aborts loading with that validation error, naming the key.
+    pub fn load_from_env(&mut self) -> Result<()> {
         // Map environment variables to config keys
         let env_mappings = [
             ("LLMOBS_OTLP_ENDPOINT", ObservatoryConfigKey::OtlpEndpoint),
@@ -308,36 +581,100 @@ impl ConfigAdapter {
 
         for (env_var, key) in env_mappings {
             if let Ok(value) = std::env::var(env_var) {
-                let config_value = match key {
-                    ObservatoryConfigKey::OtlpPort
-                    | ObservatoryConfigKey::BatchSize
-                    | ObservatoryConfigKey::BatchTimeoutMs => {
-                        if let Ok(i) = value.parse::<i64>() {
-                            ConfigValue::Integer(i)
-                        } else {
-                            continue;
-                        }
-                    }
-                    ObservatoryConfigKey::SamplingRate => {
-                        if let Ok(f) = value.parse::<f64>() {
-                            ConfigValue::Float(f)
-                        } else {
-                            continue;
-                        }
-                    }
-                    ObservatoryConfigKey::EnablePiiRedaction
-                    | ObservatoryConfigKey::EnableCostCalculation => {
-                        match value.to_lowercase().as_str() {
-                            "true" | "1" | "yes" => ConfigValue::Boolean(true),
-                            "false" | "0" | "no" => ConfigValue::Boolean(false),
-                            _ => continue,
-                        }
-                    }
-                    _ => ConfigValue::String(value),
-                };
-                self.set(key, config_value);
+                let config_value = key.parse_value(&value)?;
+                self.set_checked(key, config_value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Load configuration from a TOML file.
+    ///
+    /// The file is organized by namespace, e.g.:
+    ///
+    /// ```toml
+    /// [collector]
+    /// otlp_endpoint = "http://collector:4317"
+    /// sampling_rate = 0.25
+    /// ```
+    ///
+    /// Values are validated through [`Self::set_checked`]. Keys that do not
+    /// correspond to an [`ObservatoryConfigKey`] are ignored.
+    pub fn load_from_toml(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let contents = Self::read_config_file(path)?;
+        let value: serde_json::Value = toml::from_str(&contents).map_err(|e| {
+            ConfigAdapterError::PersistenceError(format!(
+                "Failed to parse {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        self.load_from_value(&value)
+    }
+
+    /// Load configuration from a YAML file.
+    ///
+    /// Uses the same `namespace.key` layout as [`Self::load_from_toml`].
+    pub fn load_from_yaml(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let contents = Self::read_config_file(path)?;
+        let value: serde_json::Value = serde_yaml::from_str(&contents).map_err(|e| {
+            ConfigAdapterError::PersistenceError(format!(
+                "Failed to parse {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        self.load_from_value(&value)
+    }
+
+    /// Load configuration from a file, then from environment variables.
+    ///
+    /// The file format is chosen by extension (`.toml`, `.yaml` or `.yml`).
+    /// Precedence is file first, then environment: any `LLMOBS_*` variable
+    /// that is set overrides the value from the file.
+    pub fn load_layered(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => self.load_from_toml(path)?,
+            Some("yaml") | Some("yml") => self.load_from_yaml(path)?,
+            _ => {
+                return Err(ConfigAdapterError::InvalidPath(format!(
+                    "Unsupported config file format: {}",
+                    path.display()
+                )))
+            }
+        }
+        self.load_from_env()
+    }
+
+    fn read_config_file(path: &Path) -> Result<String> {
+        std::fs::read_to_string(path).map_err(|e| {
+            ConfigAdapterError::InvalidPath(format!("Failed to read {}: {}", path.display(), e))
+        })
+    }
+
+    /// Populate the cache from a parsed `namespace -> key -> scalar` tree.
+    fn load_from_value(&mut self, root: &serde_json::Value) -> Result<()> {
+        let Some(namespaces) = root.as_object() else {
+            return Ok(());
+        };
+
+        for (namespace, entries) in namespaces {
+            let Some(entries) = entries.as_object() else {
+                continue;
+            };
+            for (name, raw) in entries {
+                if let Some(key) = ObservatoryConfigKey::from_path(namespace, name) {
+                    let value = key.coerce_value(raw)?;
+                    self.set_checked(key, value)?;
+                }
             }
         }
+
+        Ok(())
     }
 
     /// Get all configuration values as a HashMap.
@@ -345,20 +682,7 @@ impl ConfigAdapter {
         let mut config = HashMap::new();
 
         // Add all default values
-        let all_keys = [
-            ObservatoryConfigKey::OtlpEndpoint,
-            ObservatoryConfigKey::OtlpPort,
-            ObservatoryConfigKey::SamplingRate,
-            ObservatoryConfigKey::EnablePiiRedaction,
-            ObservatoryConfigKey::EnableCostCalculation,
-            ObservatoryConfigKey::BatchSize,
-            ObservatoryConfigKey::BatchTimeoutMs,
-            ObservatoryConfigKey::DatabaseUrl,
-            ObservatoryConfigKey::RedisUrl,
-            ObservatoryConfigKey::LogLevel,
-        ];
-
-        for key in all_keys {
+        for key in ObservatoryConfigKey::all() {
             let cache_key = format!("{}/{}", key.namespace(), key.key());
             config.insert(cache_key, self.get(key));
         }
@@ -367,10 +691,13 @@ impl ConfigAdapter {
     }
 
     /// Create a Config object from current settings.
+    ///
+    /// Overrides for the default environment replace base values.
     pub fn to_config(&self, namespace: &str) -> Config {
         let mut config = Config::new(namespace, self.default_environment.into());
 
-        for (key, value) in &self.cache {
+        let overrides = self.env_overrides.get(&self.default_environment);
+        for (key, value) in self.cache.iter().chain(overrides.into_iter().flatten()) {
             if key.starts_with(&format!("{}/", namespace)) {
                 let short_key = key.strip_prefix(&format!("{}/", namespace)).unwrap_or(key);
                 config.set(short_key, value.clone());
@@ -380,6 +707,62 @@ impl ConfigAdapter {
         config
     }
 
+    /// Path of the persisted configuration file, or `None` in in-memory mode.
+    fn config_file_path(&self) -> Option<std::path::PathBuf> {
+        if self.storage_path.is_empty() {
+            None
+        } else {
+            Some(Path::new(&self.storage_path).join(CONFIG_FILE_NAME))
+        }
+    }
+
+    /// Persist the configuration cache as JSON under the storage path.
+    ///
+    /// Only base values are persisted, not per-environment overrides. This
+    /// is a no-op for in-memory adapters.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = self.config_file_path() else {
+            return Ok(());
+        };
+
+        let json = serde_json::to_string_pretty(&self.cache).map_err(|e| {
+            ConfigAdapterError::PersistenceError(format!("Failed to serialize config: {}", e))
+        })?;
+        std::fs::write(&path, json).map_err(|e| {
+            ConfigAdapterError::PersistenceError(format!(
+                "Failed to write {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Reload the configuration cache from the storage path.
+    ///
+    /// Replaces the cache with the persisted values. A missing file leaves the
+    /// cache untouched. This is a no-op for in-memory adapters.
+    pub fn reload(&mut self) -> Result<()> {
+        let Some(path) = self.config_file_path() else {
+            return Ok(());
+        };
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let json = std::fs::read_to_string(&path).map_err(|e| {
+            ConfigAdapterError::PersistenceError(format!(
+                "Failed to read {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        self.cache = serde_json::from_str(&json).map_err(|e| {
+            ConfigAdapterError::PersistenceError(format!("Failed to parse config: {}", e))
+        })?;
+
+        Ok(())
+    }
+
     /// Get supported environments.
     pub fn supported_environments() -> Vec<ObservatoryEnvironment> {
         vec![
@@ -438,6 +821,273 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_environment_overrides_layer_over_base() {
+        use ObservatoryEnvironment::{Development, Production, Staging};
+        let key = ObservatoryConfigKey::SamplingRate;
+        let float = |value: ConfigValue| match value {
+            ConfigValue::Float(f) => Some(f),
+            _ => None,
+        };
+
+        let mut adapter = ConfigAdapter::in_memory().with_environment(Production);
+        assert_eq!(adapter.get_float(key), Some(1.0));
+
+        adapter.set(key, ConfigValue::Float(0.5));
+        adapter.set_for(Production, key, ConfigValue::Float(0.05));
+
+        assert_eq!(adapter.get_float(key), Some(0.05));
+        assert_eq!(adapter.snapshot().sampling_rate, 0.05);
+        assert_eq!(float(adapter.get_for(Staging, key)), Some(0.5));
+        assert_eq!(float(adapter.get_for(Development, key)), Some(0.5));
+
+        let dev = adapter.with_environment(Development);
+        assert_eq!(dev.get_float(key), Some(0.5));
+        assert_eq!(float(dev.get_for(Production, key)), Some(0.05));
+    }
+
+    #[test]
+    fn test_snapshot_defaults() {
+        let config = ConfigAdapter::in_memory().snapshot();
+
+        assert_eq!(
+            config,
+            ObservatoryConfig {
+                otlp_endpoint: "http://localhost:4317".to_string(),
+                otlp_port: 4317,
+                sampling_rate: 1.0,
+                enable_pii_redaction: true,
+                enable_cost_calculation: true,
+                batch_size: 1000,
+                batch_timeout_ms: 10000,
+                database_url: "postgresql://localhost:5432/observatory".to_string(),
+                redis_url: "redis://localhost:6379".to_string(),
+                log_level: "info".to_string(),
+            }
+        );
+        assert_eq!(config, ObservatoryConfig::default());
+    }
+
+    #[test]
+    fn test_snapshot_reflects_overrides() {
+        let mut adapter = ConfigAdapter::in_memory();
+        adapter
+            .set_checked(ObservatoryConfigKey::OtlpPort, ConfigValue::Integer(9000))
+            .unwrap();
+        adapter
+            .set_checked(ObservatoryConfigKey::SamplingRate, ConfigValue::Float(0.1))
+            .unwrap();
+
+        let config = adapter.snapshot();
+        assert_eq!(config.otlp_port, 9000);
+        assert_eq!(config.sampling_rate, 0.1);
+
+        // Invalid values stored through the unchecked setter fall back
+        adapter.set(ObservatoryConfigKey::BatchSize, ConfigValue::Integer(-5));
+        assert_eq!(adapter.snapshot().batch_size, 1000);
+    }
+
+    #[test]
+    fn test_set_checked_validation() {
+        let mut adapter = ConfigAdapter::in_memory();
+
+        assert!(adapter
+            .set_checked(ObservatoryConfigKey::SamplingRate, ConfigValue::Float(0.5))
+            .is_ok());
+        assert!(matches!(
+            adapter.set_checked(ObservatoryConfigKey::SamplingRate, ConfigValue::Float(5.0)),
+            Err(ConfigAdapterError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            adapter.set_checked(ObservatoryConfigKey::OtlpPort, ConfigValue::Integer(70000)),
+            Err(ConfigAdapterError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            adapter.set_checked(ObservatoryConfigKey::BatchSize, ConfigValue::Integer(-1)),
+            Err(ConfigAdapterError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            adapter.set_checked(
+                ObservatoryConfigKey::LogLevel,
+                ConfigValue::String(String::new())
+            ),
+            Err(ConfigAdapterError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            adapter.set_checked(ObservatoryConfigKey::OtlpPort, ConfigValue::Boolean(true)),
+            Err(ConfigAdapterError::InvalidType { .. })
+        ));
+
+        // Rejected values never reach the cache
+        assert_eq!(
+            adapter.get_float(ObservatoryConfigKey::SamplingRate),
+            Some(0.5)
+        );
+        assert_eq!(
+            adapter.get_integer(ObservatoryConfigKey::OtlpPort),
+            Some(4317)
+        );
+    }
+
+    /// Serializes tests that set `LLMOBS_*` variables, which are process-wide
+    static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn test_load_from_env_returns_validation_error() {
+        let _env = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        std::env::set_var("LLMOBS_BATCH_TIMEOUT_MS", "-5");
+        let result = ConfigAdapter::in_memory().load_from_env();
+        std::env::remove_var("LLMOBS_BATCH_TIMEOUT_MS");
+
+        let err = result.unwrap_err();
+        match &err {
+            ConfigAdapterError::InvalidValue { key, reason } => {
+                assert_eq!(key, "batch_timeout_ms");
+                assert_eq!(reason, "-5 must be positive");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration value for batch_timeout_ms: -5 must be positive"
+        );
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert!(matches!(
+            ObservatoryConfigKey::OtlpPort.parse_value("8080"),
+            Ok(ConfigValue::Integer(8080))
+        ));
+        assert!(matches!(
+            ObservatoryConfigKey::EnablePiiRedaction.parse_value("no"),
+            Ok(ConfigValue::Boolean(false))
+        ));
+        assert!(ObservatoryConfigKey::SamplingRate
+            .parse_value("lots")
+            .is_err());
+    }
+
+    fn fixture_path(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    fn assert_fixture_loaded(adapter: &ConfigAdapter) {
+        assert_eq!(
+            adapter.get_string(ObservatoryConfigKey::OtlpEndpoint),
+            Some("http://collector:4317".to_string())
+        );
+        assert_eq!(
+            adapter.get_float(ObservatoryConfigKey::SamplingRate),
+            Some(0.25)
+        );
+        assert_eq!(
+            adapter.get_bool(ObservatoryConfigKey::EnablePiiRedaction),
+            Some(false)
+        );
+        assert_eq!(
+            adapter.get_integer(ObservatoryConfigKey::BatchSize),
+            Some(500)
+        );
+    }
+
+    #[test]
+    fn test_load_from_toml() {
+        let mut adapter = ConfigAdapter::in_memory();
+        adapter
+            .load_from_toml(fixture_path("observatory.toml"))
+            .unwrap();
+        assert_fixture_loaded(&adapter);
+    }
+
+    #[test]
+    fn test_load_layered_env_overrides_file() {
+        let _env = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        std::env::set_var("LLMOBS_BATCH_SIZE", "250");
+        let mut adapter = ConfigAdapter::in_memory();
+        let result = adapter.load_layered(fixture_path("observatory.toml"));
+        std::env::remove_var("LLMOBS_BATCH_SIZE");
+        result.unwrap();
+
+        assert_eq!(
+            adapter.get_integer(ObservatoryConfigKey::BatchSize),
+            Some(250)
+        );
+        assert_eq!(
+            adapter.get_string(ObservatoryConfigKey::OtlpEndpoint),
+            Some("http://collector:4317".to_string())
+        );
+        assert_eq!(
+            adapter.get_float(ObservatoryConfigKey::SamplingRate),
+            Some(0.25)
+        );
+        assert_eq!(
+            adapter.get_bool(ObservatoryConfigKey::EnablePiiRedaction),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_load_from_yaml() {
+        let mut adapter = ConfigAdapter::in_memory();
+        adapter
+            .load_from_yaml(fixture_path("observatory.yaml"))
+            .unwrap();
+        assert_fixture_loaded(&adapter);
+    }
+
+    #[test]
+    fn test_load_from_toml_rejects_invalid_values() {
+        let dir = std::env::temp_dir().join(format!("observatory-config-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("invalid.toml");
+        std::fs::write(&path, "[collector]\nsampling_rate = 5.0\n").unwrap();
+
+        let mut adapter = ConfigAdapter::in_memory();
+        assert!(matches!(
+            adapter.load_from_toml(&path),
+            Err(ConfigAdapterError::InvalidValue { .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_reload() {
+        let dir = std::env::temp_dir().join(format!("observatory-config-{}", uuid::Uuid::new_v4()));
+
+        let mut adapter = ConfigAdapter::new(&dir).unwrap();
+        adapter.set(
+            ObservatoryConfigKey::OtlpEndpoint,
+            ConfigValue::String("http://persisted:4317".to_string()),
+        );
+        adapter.save().unwrap();
+
+        let mut restored = ConfigAdapter::new(&dir).unwrap();
+        restored.reload().unwrap();
+        assert_eq!(
+            restored.get_string(ObservatoryConfigKey::OtlpEndpoint),
+            Some("http://persisted:4317".to_string())
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_reload_in_memory_noop() {
+        let mut adapter = ConfigAdapter::in_memory();
+        adapter.set(ObservatoryConfigKey::BatchSize, ConfigValue::Integer(5));
+
+        assert!(adapter.save().is_ok());
+        assert!(adapter.reload().is_ok());
+        assert_eq!(
+            adapter.get_integer(ObservatoryConfigKey::BatchSize),
+            Some(5)
+        );
+    }
+
     #[test]
     fn test_environment_conversion() {
         assert_eq!(
@@ -9,9 +9,12 @@
 //! # Features
 //!
 //! - Cost calculation from token usage
-//! - Token normalization across providers
+//! - Model name normalization (dated and aliased variants price as their family)
+//! - Token normalization across providers (llm-cost-ops' `TokenNormalizer`
+//!   estimates usage from text when a span has no reported token counts)
 //! - Cost aggregation for analytics
 //! - Usage record creation
+//! - Spend budgets with overage reporting
 //!
 //! # Example
 //!
@@ -27,14 +30,16 @@
 //! let usage = adapter.create_usage_record(&span, "org_123")?;
 //! ```
 
+use chrono::{DateTime, Utc};
 use llm_cost_ops::{
     CostAggregator, CostCalculator, CostRecord, CostSummary, Currency, IngestionSource,
-    ModelIdentifier, PricingStructure, PricingTable, Provider as CostOpsProvider,
-    TokenNormalizer, UsageRecord,
+    ModelIdentifier, PricingStructure, PricingTable, Provider as CostOpsProvider, TokenNormalizer,
+    UsageRecord,
 };
-use llm_observatory_core::span::LlmSpan;
+use llm_observatory_core::span::{ContentPart, LlmInput, LlmSpan};
 use llm_observatory_core::types::{Cost, Provider as ObsProvider, TokenUsage};
-use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -63,6 +68,10 @@ pub enum CostAdapterError {
     #[error("Cost calculation error: {0}")]
     CalculationError(String),
 
+    /// Exchange rate missing for a currency conversion
+    #[error("Exchange rate not provided for currency: {0}")]
+    MissingExchangeRate(String),
+
     /// Internal error
     #[error("Internal error: {0}")]
     InternalError(String),
@@ -71,12 +80,18 @@ pub enum CostAdapterError {
 /// Result type for cost operations.
 pub type Result<T> = std::result::Result<T, CostAdapterError>;
 
+/// Metadata attribute key used to attribute a span's cost to a project.
+pub const PROJECT_ATTRIBUTE_KEY: &str = "project_id";
+
+/// [`CostBreakdown::pricing_source`] for costs priced from [`DefaultPricing`].
+pub const DEFAULT_PRICING_SOURCE: &str = "default_table";
+
 /// Cost breakdown with detailed information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostBreakdown {
     /// Total cost in USD
     pub total_usd: f64,
-    /// Input/prompt cost
+    /// Input/prompt cost, including any image surcharge
     pub input_cost: f64,
     /// Output/completion cost
     pub output_cost: f64,
@@ -88,6 +103,80 @@ pub struct CostBreakdown {
     pub model: String,
     /// Token counts
     pub tokens: TokenBreakdown,
+    /// Project or organization the cost is attributed to (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Whether token counts were estimated from text rather than reported
+    #[serde(default)]
+    pub estimated: bool,
+    /// Where the prices came from (e.g. [`DEFAULT_PRICING_SOURCE`]), if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_source: Option<String>,
+    /// Number of input images (multimodal spans)
+    #[serde(default)]
+    pub image_count: u32,
+    /// Image surcharge included in `input_cost` and `total_usd`
+    #[serde(default)]
+    pub image_cost: f64,
+}
+
+impl CostBreakdown {
+    /// One-line summary for logs: the [`Display`](std::fmt::Display) form
+    /// prefixed by the provider, followed by the project, any image
+    /// surcharge and whether tokens were estimated.
+    ///
+    /// e.g. `openai $0.0123 (in: $0.004 / out: $0.0083) gpt-4o [150→500 tok] project=acme`
+    pub fn to_summary_line(&self) -> String {
+        let mut line = format!("{} {}", self.provider, self);
+        if let Some(project) = &self.project {
+            line.push_str(&format!(" project={}", project));
+        }
+        if self.image_count > 0 {
+            line.push_str(&format!(
+                " images={} ({})",
+                self.image_count,
+                format_amount(self.image_cost, &self.currency)
+            ));
+        }
+        if self.estimated {
+            line.push_str(" (estimated)");
+        }
+        line
+    }
+}
+
+impl std::fmt::Display for CostBreakdown {
+    /// `$0.0123 (in: $0.004 / out: $0.0083) gpt-4o [150→500 tok]`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (in: {} / out: {}) {} [{}→{} tok]",
+            format_amount(self.total_usd, &self.currency),
+            format_amount(self.input_cost, &self.currency),
+            format_amount(self.output_cost, &self.currency),
+            self.model,
+            self.tokens.input_tokens,
+            self.tokens.output_tokens
+        )
+    }
+}
+
+/// Format `amount` with its currency symbol (or code) and up to four decimal
+/// places, dropping trailing zeros.
+fn format_amount(amount: f64, currency: &str) -> String {
+    let prefix = match currency {
+        "USD" => "$".to_string(),
+        "EUR" => "€".to_string(),
+        "GBP" => "£".to_string(),
+        "JPY" => "¥".to_string(),
+        code => format!("{} ", code),
+    };
+    if amount > 0.0 && amount < 0.0001 {
+        return format!("<{}0.0001", prefix);
+    }
+    let digits = format!("{:.4}", amount);
+    let digits = digits.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", prefix, digits)
 }
 
 /// Token usage breakdown.
@@ -118,29 +207,78 @@ pub struct CostReport {
     pub by_model: HashMap<String, f64>,
     /// Cost by project (if available)
     pub by_project: HashMap<String, f64>,
+    /// Portion of `total_cost` whose token counts were estimated
+    #[serde(default)]
+    pub estimated_cost: f64,
+    /// Currency all amounts in the report are denominated in
+    #[serde(default = "default_currency")]
+    pub currency: String,
     /// Period start
     pub period_start: DateTime<Utc>,
     /// Period end
     pub period_end: DateTime<Utc>,
 }
 
+fn default_currency() -> String {
+    "USD".to_string()
+}
+
+/// Release date or revision suffixes: `-2024-08-06`, `-20240620`,
+/// `@20240620`, `-0125`, `-002` and `-latest`.
+/// Shared normalizer for [`CostAdapter::normalize_tokens`].
+static TOKEN_NORMALIZER: Lazy<TokenNormalizer> = Lazy::new(TokenNormalizer::new);
+
+static MODEL_VERSION_SUFFIX_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:[-@](?:\d{4}-\d{2}-\d{2}|\d{8}|\d{4}|\d{3})|-latest)$").unwrap());
+
+/// Normalize a raw model string to its canonical family name for pricing.
+///
+/// Lowercases, drops any `provider/` routing prefix, strips release date and
+/// revision suffixes (`gpt-4o-2024-08-06` becomes `gpt-4o`,
+/// `claude-3-5-sonnet@20240620` becomes `claude-3-5-sonnet`) and maps known
+/// aliases such as `chatgpt-4o-latest` or `gpt-4-1106-preview` to the family
+/// they are billed as.
+pub fn normalize_model(raw: &str) -> String {
+    let lowered = raw.trim().to_ascii_lowercase();
+    let mut model = lowered.rsplit('/').next().unwrap_or_default().to_string();
+    while let Some(suffix) = MODEL_VERSION_SUFFIX_REGEX.find(&model) {
+        model.truncate(suffix.start());
+    }
+
+    match model.as_str() {
+        "chatgpt-4o" => "gpt-4o".to_string(),
+        m if m.starts_with("gpt-4-") && m.ends_with("-preview") => "gpt-4-turbo".to_string(),
+        m if m.starts_with("claude-3.5-") => m.replacen("claude-3.5-", "claude-3-5-", 1),
+        _ => model,
+    }
+}
+
 /// Default pricing data for common models (per 1M tokens).
+///
+/// Image prices approximate the provider's charge for one typical
+/// (~1024x1024) input image.
 #[derive(Debug, Clone)]
 pub struct DefaultPricing {
     /// Input price per 1M tokens
     pub input_price_per_million: f64,
     /// Output price per 1M tokens
     pub output_price_per_million: f64,
+    /// Surcharge per input image (USD), for vision-capable models
+    pub image_price_usd: Option<f64>,
 }
 
 impl DefaultPricing {
     /// Get default pricing for a model.
+    ///
+    /// The model name is passed through [`normalize_model`] first, so dated
+    /// and aliased variants resolve to their family's price.
     pub fn for_model(provider: &ObsProvider, model: &str) -> Option<Self> {
+        let model = normalize_model(model);
         match provider {
-            ObsProvider::OpenAI => Self::openai_pricing(model),
-            ObsProvider::Anthropic => Self::anthropic_pricing(model),
-            ObsProvider::Google => Self::google_pricing(model),
-            ObsProvider::Mistral => Self::mistral_pricing(model),
+            ObsProvider::OpenAI => Self::openai_pricing(&model),
+            ObsProvider::Anthropic => Self::anthropic_pricing(&model),
+            ObsProvider::Google => Self::google_pricing(&model),
+            ObsProvider::Mistral => Self::mistral_pricing(&model),
             _ => None,
         }
     }
@@ -150,30 +288,37 @@ impl DefaultPricing {
             m if m.starts_with("gpt-4o-mini") => Some(Self {
                 input_price_per_million: 0.15,
                 output_price_per_million: 0.60,
+                image_price_usd: Some(0.003825),
             }),
             m if m.starts_with("gpt-4o") => Some(Self {
                 input_price_per_million: 2.50,
                 output_price_per_million: 10.00,
+                image_price_usd: Some(0.001913),
             }),
             m if m.starts_with("gpt-4-turbo") => Some(Self {
                 input_price_per_million: 10.00,
                 output_price_per_million: 30.00,
+                image_price_usd: Some(0.00765),
             }),
             m if m.starts_with("gpt-4") => Some(Self {
                 input_price_per_million: 30.00,
                 output_price_per_million: 60.00,
+                image_price_usd: None,
             }),
             m if m.starts_with("gpt-3.5-turbo") => Some(Self {
                 input_price_per_million: 0.50,
                 output_price_per_million: 1.50,
+                image_price_usd: None,
             }),
             m if m.starts_with("o1-preview") => Some(Self {
                 input_price_per_million: 15.00,
                 output_price_per_million: 60.00,
+                image_price_usd: None,
             }),
             m if m.starts_with("o1-mini") => Some(Self {
                 input_price_per_million: 3.00,
                 output_price_per_million: 12.00,
+                image_price_usd: None,
             }),
             _ => None,
         }
@@ -184,22 +329,27 @@ impl DefaultPricing {
             m if m.contains("claude-3-5-sonnet") || m.contains("claude-sonnet-4") => Some(Self {
                 input_price_per_million: 3.00,
                 output_price_per_million: 15.00,
+                image_price_usd: Some(0.0048),
             }),
             m if m.contains("claude-3-5-haiku") => Some(Self {
                 input_price_per_million: 0.80,
                 output_price_per_million: 4.00,
+                image_price_usd: Some(0.00128),
             }),
             m if m.contains("claude-3-opus") => Some(Self {
                 input_price_per_million: 15.00,
                 output_price_per_million: 75.00,
+                image_price_usd: Some(0.024),
             }),
             m if m.contains("claude-3-sonnet") => Some(Self {
                 input_price_per_million: 3.00,
                 output_price_per_million: 15.00,
+                image_price_usd: Some(0.0048),
             }),
             m if m.contains("claude-3-haiku") => Some(Self {
                 input_price_per_million: 0.25,
                 output_price_per_million: 1.25,
+                image_price_usd: Some(0.0004),
             }),
             _ => None,
         }
@@ -210,18 +360,22 @@ impl DefaultPricing {
             m if m.contains("gemini-2") && m.contains("pro") => Some(Self {
                 input_price_per_million: 1.25,
                 output_price_per_million: 5.00,
+                image_price_usd: Some(0.0003225),
             }),
             m if m.contains("gemini-2") && m.contains("flash") => Some(Self {
                 input_price_per_million: 0.075,
                 output_price_per_million: 0.30,
+                image_price_usd: Some(0.0000194),
             }),
             m if m.contains("gemini-1.5-pro") => Some(Self {
                 input_price_per_million: 1.25,
                 output_price_per_million: 5.00,
+                image_price_usd: Some(0.0003225),
             }),
             m if m.contains("gemini-1.5-flash") => Some(Self {
                 input_price_per_million: 0.075,
                 output_price_per_million: 0.30,
+                image_price_usd: Some(0.0000194),
             }),
             _ => None,
         }
@@ -232,10 +386,12 @@ impl DefaultPricing {
             m if m.contains("large") => Some(Self {
                 input_price_per_million: 2.00,
                 output_price_per_million: 6.00,
+                image_price_usd: None,
             }),
             m if m.contains("small") => Some(Self {
                 input_price_per_million: 0.20,
                 output_price_per_million: 0.60,
+                image_price_usd: None,
             }),
             _ => None,
         }
@@ -259,10 +415,24 @@ impl DefaultPricing {
                 total_tokens: input_tokens + output_tokens,
                 cached_tokens: None,
             },
+            project: None,
+            estimated: false,
+            pricing_source: Some(DEFAULT_PRICING_SOURCE.to_string()),
+            image_count: 0,
+            image_cost: 0.0,
         }
     }
 }
 
+/// Budget state after recording a cost.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BudgetStatus {
+    /// Whether cumulative spend is within the budget (always true with no budget)
+    pub within: bool,
+    /// Amount by which cumulative spend exceeds the budget, in USD
+    pub overage_usd: f64,
+}
+
 /// Adapter for consuming llm-cost-ops functionality.
 ///
 /// Provides a simplified interface for Observatory to interact with
@@ -272,6 +442,12 @@ pub struct CostAdapter {
     default_org_id: Option<String>,
     /// Cost records for aggregation
     cost_records: Vec<CostBreakdown>,
+    /// Spend budget in USD
+    budget_usd: Option<f64>,
+    /// Whether annotating a span with an unpriced model is an error
+    require_pricing: bool,
+    /// Whether annotating replaces a cost the span already carries
+    overwrite_cost: bool,
 }
 
 impl Default for CostAdapter {
@@ -286,6 +462,9 @@ impl CostAdapter {
         Self {
             default_org_id: None,
             cost_records: Vec::new(),
+            budget_usd: None,
+            require_pricing: false,
+            overwrite_cost: false,
         }
     }
 
@@ -294,6 +473,9 @@ impl CostAdapter {
         Self {
             default_org_id: Some(org_id.into()),
             cost_records: Vec::new(),
+            budget_usd: None,
+            require_pricing: false,
+            overwrite_cost: false,
         }
     }
 
@@ -303,27 +485,149 @@ impl CostAdapter {
     }
 
     /// Calculate cost from an LLM span.
+    ///
+    /// Image parts of a multimodal input add the model's per-image surcharge.
+    /// When the span has no `token_usage`, token counts are estimated from the
+    /// prompt and output text with [`Self::normalize_tokens`] and the breakdown
+    /// is marked `estimated`. Returns `MissingTokenUsage` only when there is
+    /// neither reported usage nor any text to estimate from.
     pub fn calculate_cost(&self, span: &LlmSpan) -> Result<CostBreakdown> {
-        let token_usage = span
-            .token_usage
-            .as_ref()
-            .ok_or(CostAdapterError::MissingTokenUsage)?;
+        let pricing = DefaultPricing::for_model(&span.provider, &span.model);
+        Self::price_span(span, pricing.as_ref())
+    }
 
-        let pricing = DefaultPricing::for_model(&span.provider, &span.model).ok_or_else(|| {
+    /// Calculate the cost of each span, as [`Self::calculate_cost`] would.
+    ///
+    /// Pricing is resolved once per (provider, model) pair within the call.
+    /// Errors, such as an unpriced model, are reported per span and do not
+    /// stop the rest of the batch.
+    pub fn calculate_costs(&self, spans: &[LlmSpan]) -> Vec<Result<CostBreakdown>> {
+        let mut pricing: HashMap<(String, &str), Option<DefaultPricing>> = HashMap::new();
+        spans
+            .iter()
+            .map(|span| {
+                let pricing = pricing
+                    .entry((span.provider.to_string(), span.model.as_str()))
+                    .or_insert_with(|| DefaultPricing::for_model(&span.provider, &span.model));
+                Self::price_span(span, pricing.as_ref())
+            })
+            .collect()
+    }
+
+    /// Total cost in USD of the spans that could be priced.
+    pub fn calculate_total(&self, spans: &[LlmSpan]) -> f64 {
+        self.calculate_costs(spans)
+            .iter()
+            .filter_map(|cost| cost.as_ref().ok())
+            .map(|cost| cost.total_usd)
+            .sum()
+    }
+
+    /// Price `span` with its already resolved model pricing.
+    fn price_span(span: &LlmSpan, pricing: Option<&DefaultPricing>) -> Result<CostBreakdown> {
+        let pricing = pricing.ok_or_else(|| {
             CostAdapterError::PricingNotFound(format!("{}:{}", span.provider, span.model))
         })?;
 
-        let mut breakdown = pricing.calculate(
-            token_usage.prompt_tokens as u64,
-            token_usage.completion_tokens as u64,
-        );
+        let (input_tokens, output_tokens, estimated) = match &span.token_usage {
+            Some(usage) => (
+                usage.prompt_tokens as u64,
+                usage.completion_tokens as u64,
+                false,
+            ),
+            None => {
+                let prompt = input_text(&span.input);
+                let completion = span.output.as_ref().map_or("", |o| o.content.as_str());
+                if prompt.is_empty() && completion.is_empty() {
+                    return Err(CostAdapterError::MissingTokenUsage);
+                }
+                (
+                    Self::normalize_tokens(&span.provider, &span.model, &prompt),
+                    Self::normalize_tokens(&span.provider, &span.model, completion),
+                    true,
+                )
+            }
+        };
+
+        let mut breakdown = pricing.calculate(input_tokens, output_tokens);
 
         breakdown.provider = span.provider.to_string();
         breakdown.model = span.model.clone();
+        breakdown.estimated = estimated;
+
+        // Images are input, billed on top of the text tokens
+        let images = image_count(&span.input);
+        if images > 0 {
+            breakdown.image_count = images;
+            breakdown.image_cost = f64::from(images) * pricing.image_price_usd.unwrap_or(0.0);
+            breakdown.input_cost += breakdown.image_cost;
+            breakdown.total_usd += breakdown.image_cost;
+        }
 
         Ok(breakdown)
     }
 
+    /// Whether [`Self::annotate_span_cost`] fails for models without pricing.
+    pub fn requires_pricing(&self) -> bool {
+        self.require_pricing
+    }
+
+    /// Make [`Self::annotate_span_cost`] return `PricingNotFound` for models
+    /// without pricing instead of leaving the span unchanged (the default).
+    pub fn set_require_pricing(&mut self, required: bool) {
+        self.require_pricing = required;
+    }
+
+    /// Whether [`Self::annotate_span_cost`] replaces existing span costs.
+    pub fn overwrites_cost(&self) -> bool {
+        self.overwrite_cost
+    }
+
+    /// Make [`Self::annotate_span_cost`] replace a cost the span already
+    /// carries, e.g. one reported by the provider, with the calculated one.
+    /// By default existing costs are kept.
+    pub fn set_overwrite_cost(&mut self, overwrite: bool) {
+        self.overwrite_cost = overwrite;
+    }
+
+    /// Calculate the span's cost and write it to `span.cost` when the span has
+    /// none, so downstream consumers see a populated cost.
+    ///
+    /// A cost already on the span (typically reported by the provider) is
+    /// kept unless [`Self::set_overwrite_cost`] is enabled. Spans whose model
+    /// has no pricing are left unchanged unless [`Self::set_require_pricing`]
+    /// is enabled. Other calculation errors, such as `MissingTokenUsage`, are
+    /// always returned.
+    pub fn annotate_span_cost(&self, span: &mut LlmSpan) -> Result<()> {
+        if span.cost.is_some() && !self.overwrite_cost {
+            return Ok(());
+        }
+        match self.calculate_cost(span) {
+            Ok(breakdown) => {
+                span.cost = Some(Self::to_observatory_cost(&breakdown));
+                Ok(())
+            }
+            Err(CostAdapterError::PricingNotFound(_)) if !self.require_pricing => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Estimate the number of tokens `text` uses with a provider's tokenizer.
+    ///
+    /// Delegates to llm-cost-ops' `TokenNormalizer` for the provider (mapped
+    /// with [`Self::map_provider`]) and model, so estimates match the counts
+    /// CostOps itself would bill. Non-empty text always counts as at least
+    /// one token.
+    pub fn normalize_tokens(provider: &ObsProvider, model: &str, text: &str) -> u64 {
+        if text.is_empty() {
+            return 0;
+        }
+
+        TOKEN_NORMALIZER
+            .estimate_tokens(&Self::map_provider(provider), model, text)
+            .max(1)
+    }
+
     /// Calculate cost from token usage.
     pub fn calculate_cost_from_usage(
         &self,
@@ -331,9 +635,8 @@ impl CostAdapter {
         model: &str,
         token_usage: &TokenUsage,
     ) -> Result<CostBreakdown> {
-        let pricing = DefaultPricing::for_model(provider, model).ok_or_else(|| {
-            CostAdapterError::PricingNotFound(format!("{}:{}", provider, model))
-        })?;
+        let pricing = DefaultPricing::for_model(provider, model)
+            .ok_or_else(|| CostAdapterError::PricingNotFound(format!("{}:{}", provider, model)))?;
 
         let mut breakdown = pricing.calculate(
             token_usage.prompt_tokens as u64,
@@ -361,6 +664,11 @@ impl CostAdapter {
                 total_tokens: 0,
                 cached_tokens: None,
             },
+            project: None,
+            estimated: false,
+            pricing_source: None,
+            image_count: 0,
+            image_cost: 0.0,
         }
     }
 
@@ -374,16 +682,66 @@ impl CostAdapter {
         }
     }
 
+    /// Set a spend budget in USD for the costs recorded by this adapter.
+    pub fn set_budget(&mut self, usd: f64) {
+        self.budget_usd = Some(usd);
+    }
+
+    /// Get the spend budget in USD, if one is set.
+    pub fn budget(&self) -> Option<f64> {
+        self.budget_usd
+    }
+
+    /// Budget left before spend exceeds it, or `None` when no budget is set.
+    ///
+    /// Never negative; see [`Self::budget_status`] for the overage.
+    pub fn remaining_budget(&self) -> Option<f64> {
+        self.budget_usd
+            .map(|budget| (budget - self.total_cost()).max(0.0))
+    }
+
+    /// Compare cumulative spend against the budget.
+    pub fn budget_status(&self) -> BudgetStatus {
+        let overage_usd = self
+            .budget_usd
+            .map_or(0.0, |budget| (self.total_cost() - budget).max(0.0));
+        BudgetStatus {
+            within: overage_usd == 0.0,
+            overage_usd,
+        }
+    }
+
     /// Record a cost breakdown.
-    pub fn record_cost(&mut self, breakdown: CostBreakdown) {
+    ///
+    /// Returns the budget status including this cost, so callers can stop
+    /// before spending further once `within` is false.
+    pub fn record_cost(&mut self, breakdown: CostBreakdown) -> BudgetStatus {
         self.cost_records.push(breakdown);
+        self.budget_status()
     }
 
     /// Record cost from a span.
-    pub fn record_span_cost(&mut self, span: &LlmSpan) -> Result<()> {
-        let breakdown = self.calculate_cost(span)?;
-        self.record_cost(breakdown);
-        Ok(())
+    ///
+    /// The cost is attributed to the project found in the span's metadata
+    /// attributes under [`PROJECT_ATTRIBUTE_KEY`], if any.
+    pub fn record_span_cost(&mut self, span: &LlmSpan) -> Result<BudgetStatus> {
+        self.record_span_cost_for(span, None)
+    }
+
+    /// Record cost from a span, attributing it to a project.
+    ///
+    /// When `project` is `None`, the project is taken from the span's metadata
+    /// attributes under [`PROJECT_ATTRIBUTE_KEY`].
+    pub fn record_span_cost_for(
+        &mut self,
+        span: &LlmSpan,
+        project: Option<&str>,
+    ) -> Result<BudgetStatus> {
+        let mut breakdown = self.calculate_cost(span)?;
+        breakdown.project = project
+            .map(str::to_string)
+            .or_else(|| span.metadata.attributes.get(PROJECT_ATTRIBUTE_KEY).cloned());
+        Ok(self.record_cost(breakdown))
     }
 
     /// Get total cost from recorded breakdowns.
@@ -409,6 +767,28 @@ impl CostAdapter {
         by_model
     }
 
+    /// Get cost by project.
+    ///
+    /// Records without a project are not included.
+    pub fn cost_by_project(&self) -> HashMap<String, f64> {
+        let mut by_project = HashMap::new();
+        for record in &self.cost_records {
+            if let Some(project) = &record.project {
+                *by_project.entry(project.clone()).or_insert(0.0) += record.total_usd;
+            }
+        }
+        by_project
+    }
+
+    /// Total cost of recorded breakdowns whose token counts were estimated.
+    pub fn estimated_cost(&self) -> f64 {
+        self.cost_records
+            .iter()
+            .filter(|r| r.estimated)
+            .map(|r| r.total_usd)
+            .sum()
+    }
+
     /// Generate a cost report.
     pub fn generate_report(
         &self,
@@ -428,12 +808,88 @@ impl CostAdapter {
             },
             by_provider: self.cost_by_provider(),
             by_model: self.cost_by_model(),
-            by_project: HashMap::new(),
+            by_project: self.cost_by_project(),
+            estimated_cost: self.estimated_cost(),
+            currency: default_currency(),
             period_start,
             period_end,
         }
     }
 
+    /// Generate a cost report denominated in the given currency.
+    ///
+    /// `rates` are USD-relative: each entry is the number of units of that
+    /// currency per 1 USD. Converting to USD needs no rate.
+    pub fn generate_report_in(
+        &self,
+        currency: Currency,
+        rates: &HashMap<Currency, f64>,
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+    ) -> Result<CostReport> {
+        let rate = Self::exchange_rate(&currency, rates)?;
+        let mut report = self.generate_report(period_start, period_end);
+
+        report.total_cost *= rate;
+        report.avg_cost_per_request *= rate;
+        report.estimated_cost *= rate;
+        for cost in report
+            .by_provider
+            .values_mut()
+            .chain(report.by_model.values_mut())
+            .chain(report.by_project.values_mut())
+        {
+            *cost *= rate;
+        }
+        report.currency = currency_code(&currency)?.to_string();
+
+        Ok(report)
+    }
+
+    /// Convert a USD cost breakdown into another currency.
+    ///
+    /// `rates` are USD-relative: each entry is the number of units of that
+    /// currency per 1 USD. The monetary fields (`total_usd`, `input_cost`,
+    /// `output_cost`, `image_cost`) are multiplied by the target rate and
+    /// `currency` is updated to the target. Returns an error if the target
+    /// rate is missing or the breakdown is not denominated in USD.
+    pub fn convert(
+        breakdown: &CostBreakdown,
+        target: Currency,
+        rates: &HashMap<Currency, f64>,
+    ) -> Result<CostBreakdown> {
+        if breakdown.currency != "USD" {
+            return Err(CostAdapterError::CalculationError(format!(
+                "Cannot convert from non-USD currency: {}",
+                breakdown.currency
+            )));
+        }
+
+        let rate = Self::exchange_rate(&target, rates)?;
+        let mut converted = breakdown.clone();
+        converted.total_usd *= rate;
+        converted.input_cost *= rate;
+        converted.output_cost *= rate;
+        converted.image_cost *= rate;
+        converted.currency = currency_code(&target)?.to_string();
+
+        Ok(converted)
+    }
+
+    /// Look up the USD-relative rate for a currency.
+    fn exchange_rate(currency: &Currency, rates: &HashMap<Currency, f64>) -> Result<f64> {
+        let code = currency_code(currency)?;
+        match rates.get(currency) {
+            Some(rate) if rate.is_finite() && *rate > 0.0 => Ok(*rate),
+            Some(rate) => Err(CostAdapterError::CalculationError(format!(
+                "Invalid exchange rate for {}: {}",
+                code, rate
+            ))),
+            None if code == "USD" => Ok(1.0),
+            None => Err(CostAdapterError::MissingExchangeRate(code.to_string())),
+        }
+    }
+
     /// Clear recorded costs.
     pub fn clear(&mut self) {
         self.cost_records.clear();
@@ -469,19 +925,64 @@ impl CostAdapter {
 
     /// Get supported currencies.
     pub fn supported_currencies() -> Vec<Currency> {
-        vec![
-            Currency::USD,
-            Currency::EUR,
-            Currency::GBP,
-            Currency::JPY,
-        ]
+        vec![Currency::USD, Currency::EUR, Currency::GBP, Currency::JPY]
+    }
+}
+
+/// Concatenated text content of a span's input, for token estimation.
+fn input_text(input: &LlmInput) -> String {
+    match input {
+        LlmInput::Text { prompt } => prompt.clone(),
+        LlmInput::Chat { messages } => messages
+            .iter()
+            .map(|m| m.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        LlmInput::Multimodal { parts } => parts
+            .iter()
+            .filter_map(|p| match p {
+                ContentPart::Text { text } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Number of image parts in a span's input.
+fn image_count(input: &LlmInput) -> u32 {
+    match input {
+        LlmInput::Multimodal { parts } => parts
+            .iter()
+            .filter(|p| matches!(p, ContentPart::Image { .. }))
+            .count() as u32,
+        _ => 0,
+    }
+}
+
+/// ISO 4217 code for a CostOps currency.
+///
+/// Only [`CostAdapter::supported_currencies`] have a code; other variants
+/// are rejected rather than named after their `Debug` output.
+#[allow(unreachable_patterns)]
+fn currency_code(currency: &Currency) -> Result<&'static str> {
+    match currency {
+        Currency::USD => Ok("USD"),
+        Currency::EUR => Ok("EUR"),
+        Currency::GBP => Ok("GBP"),
+        Currency::JPY => Ok("JPY"),
+        // CostOps may define more currencies than are supported here
+        other => Err(CostAdapterError::CalculationError(format!(
+            "Unsupported currency: {:?}",
+            other
+        ))),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use llm_observatory_core::span::{LlmInput, SpanStatus};
+    use llm_observatory_core::span::SpanStatus;
     use llm_observatory_core::types::Latency;
 
     fn create_test_span() -> LlmSpan {
@@ -518,6 +1019,216 @@ mod tests {
         assert_eq!(breakdown.model, "gpt-4o");
     }
 
+    #[test]
+    fn test_annotate_span_cost() {
+        let mut adapter = CostAdapter::new();
+        let mut span = create_test_span();
+        assert!(span.cost.is_none());
+
+        adapter.annotate_span_cost(&mut span).unwrap();
+        let cost = span.cost.as_ref().unwrap();
+        let expected = adapter.calculate_cost(&span).unwrap();
+        assert_eq!(cost.amount_usd, expected.total_usd);
+        assert_eq!(cost.prompt_cost, Some(expected.input_cost));
+        assert_eq!(cost.completion_cost, Some(expected.output_cost));
+
+        let mut unpriced = create_test_span();
+        unpriced.model = "unknown-model".to_string();
+        adapter.annotate_span_cost(&mut unpriced).unwrap();
+        assert!(unpriced.cost.is_none());
+
+        adapter.set_require_pricing(true);
+        let err = adapter.annotate_span_cost(&mut unpriced).unwrap_err();
+        assert!(matches!(err, CostAdapterError::PricingNotFound(_)));
+    }
+
+    #[test]
+    fn test_annotate_span_cost_keeps_reported_cost() {
+        let mut adapter = CostAdapter::new();
+        let mut span = create_test_span();
+        span.cost = Some(Cost::new(1.23));
+
+        adapter.annotate_span_cost(&mut span).unwrap();
+        assert_eq!(span.cost.as_ref().unwrap().amount_usd, 1.23);
+
+        adapter.set_overwrite_cost(true);
+        adapter.annotate_span_cost(&mut span).unwrap();
+        let expected = adapter.calculate_cost(&span).unwrap();
+        assert_eq!(span.cost.as_ref().unwrap().amount_usd, expected.total_usd);
+    }
+
+    #[test]
+    fn test_cost_breakdown_display() {
+        let mut breakdown = DefaultPricing {
+            input_price_per_million: 2.5,
+            output_price_per_million: 10.0,
+            image_price_usd: None,
+        }
+        .calculate(1600, 830);
+        breakdown.provider = "openai".to_string();
+        breakdown.model = "gpt-4o".to_string();
+
+        assert_eq!(
+            breakdown.to_string(),
+            "$0.0123 (in: $0.004 / out: $0.0083) gpt-4o [1600→830 tok]"
+        );
+
+        breakdown.project = Some("acme".to_string());
+        breakdown.estimated = true;
+        let line = breakdown.to_summary_line();
+        assert!(line.starts_with("openai $0.0123 "));
+        assert!(line.contains("gpt-4o"));
+        assert!(line.ends_with(" project=acme (estimated)"));
+
+        assert_eq!(format_amount(0.00001, "USD"), "<$0.0001");
+        assert_eq!(format_amount(0.0, "USD"), "$0");
+        assert_eq!(format_amount(1.5, "CHF"), "CHF 1.5");
+    }
+
+    #[test]
+    fn test_calculate_costs_mixed_model_batch() {
+        let adapter = CostAdapter::new();
+        let span_for = |model: &str| {
+            let mut span = create_test_span();
+            span.model = model.to_string();
+            span
+        };
+        let spans = vec![
+            span_for("gpt-4o"),
+            span_for("unknown-model"),
+            span_for("gpt-4o-mini"),
+            span_for("gpt-4o"),
+        ];
+
+        let costs = adapter.calculate_costs(&spans);
+        assert_eq!(costs.len(), 4);
+        assert!(matches!(
+            costs[1],
+            Err(CostAdapterError::PricingNotFound(ref key)) if key == "openai:unknown-model"
+        ));
+        for (span, cost) in spans.iter().zip(&costs) {
+            if let Ok(cost) = cost {
+                let single = adapter.calculate_cost(span).unwrap();
+                assert_eq!(cost.model, span.model);
+                assert_eq!(cost.total_usd, single.total_usd);
+            }
+        }
+
+        let expected: f64 = [0, 2, 3]
+            .iter()
+            .map(|&i| costs[i].as_ref().unwrap().total_usd)
+            .sum();
+        assert!((adapter.calculate_total(&spans) - expected).abs() < 1e-12);
+        assert_eq!(adapter.calculate_total(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_calculate_cost_estimates_missing_token_usage() {
+        let adapter = CostAdapter::new();
+        let mut span = create_test_span();
+        span.token_usage = None;
+        span.input = LlmInput::Text {
+            prompt: "Summarize the quarterly report in three bullet points.".to_string(),
+        };
+
+        let breakdown = adapter.calculate_cost(&span).unwrap();
+        assert!(breakdown.estimated);
+        assert!(breakdown.tokens.input_tokens > 0);
+        assert!(breakdown.total_usd > 0.0);
+
+        // Reported usage is not marked as estimated
+        let reported = adapter.calculate_cost(&create_test_span()).unwrap();
+        assert!(!reported.estimated);
+
+        // Reports surface how much of the total was estimated
+        let mut recorder = CostAdapter::new();
+        recorder.record_cost(breakdown.clone());
+        recorder.record_cost(reported.clone());
+        let report = recorder.generate_report(Utc::now(), Utc::now());
+        assert_eq!(report.estimated_cost, breakdown.total_usd);
+        assert!(report.estimated_cost < report.total_cost);
+
+        // Nothing to estimate from
+        span.input = LlmInput::Text {
+            prompt: String::new(),
+        };
+        assert!(matches!(
+            adapter.calculate_cost(&span),
+            Err(CostAdapterError::MissingTokenUsage)
+        ));
+    }
+
+    #[test]
+    fn test_normalize_tokens() {
+        let gpt4 = |text: &str| CostAdapter::normalize_tokens(&ObsProvider::OpenAI, "gpt-4", text);
+        assert_eq!(gpt4(""), 0);
+        assert!(gpt4("hi") >= 1);
+
+        // Longer text never estimates fewer tokens
+        let short = "The quick brown fox jumps over the lazy dog. ".repeat(2);
+        let long = short.repeat(20);
+        assert!(gpt4(&long) > gpt4(&short));
+
+        for provider in [
+            ObsProvider::Anthropic,
+            ObsProvider::Mistral,
+            ObsProvider::SelfHosted,
+        ] {
+            assert!(CostAdapter::normalize_tokens(&provider, "any-model", &long) > 0);
+        }
+    }
+
+    #[test]
+    fn test_calculate_cost_multimodal_image_surcharge() {
+        let adapter = CostAdapter::new();
+        let text_only = adapter.calculate_cost(&create_test_span()).unwrap();
+        assert_eq!(text_only.image_count, 0);
+        assert_eq!(text_only.image_cost, 0.0);
+
+        let mut span = create_test_span();
+        span.input = LlmInput::Multimodal {
+            parts: vec![
+                ContentPart::Text {
+                    text: "What is in this picture?".to_string(),
+                },
+                ContentPart::Image {
+                    source: "https://example.com/cat.png".to_string(),
+                },
+            ],
+        };
+
+        let breakdown = adapter.calculate_cost(&span).unwrap();
+        let image_price = DefaultPricing::for_model(&ObsProvider::OpenAI, "gpt-4o")
+            .unwrap()
+            .image_price_usd
+            .unwrap();
+        assert_eq!(breakdown.image_count, 1);
+        assert_eq!(breakdown.image_cost, image_price);
+        assert!((breakdown.total_usd - (text_only.total_usd + image_price)).abs() < 1e-12);
+        // Images are input, so the input and output parts still add up
+        assert!((breakdown.input_cost - (text_only.input_cost + image_price)).abs() < 1e-12);
+        assert!((breakdown.input_cost + breakdown.output_cost - breakdown.total_usd).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_budget_overage() {
+        let mut adapter = CostAdapter::new();
+        let span = create_test_span();
+        let per_span = adapter.calculate_cost(&span).unwrap().total_usd;
+        assert_eq!(adapter.remaining_budget(), None);
+
+        // Budget covers two spans and a half
+        adapter.set_budget(per_span * 2.5);
+        assert!(adapter.record_span_cost(&span).unwrap().within);
+        assert!(adapter.record_span_cost(&span).unwrap().within);
+        assert!((adapter.remaining_budget().unwrap() - per_span * 0.5).abs() < 1e-12);
+
+        let status = adapter.record_span_cost(&span).unwrap();
+        assert!(!status.within);
+        assert!((status.overage_usd - per_span * 0.5).abs() < 1e-12);
+        assert_eq!(adapter.remaining_budget(), Some(0.0));
+    }
+
     #[test]
     fn test_default_pricing() {
         // GPT-4o pricing
@@ -525,6 +1236,11 @@ mod tests {
         let breakdown = pricing.calculate(1_000_000, 1_000_000);
         assert_eq!(breakdown.input_cost, 2.50);
         assert_eq!(breakdown.output_cost, 10.00);
+        assert_eq!(
+            breakdown.pricing_source.as_deref(),
+            Some(DEFAULT_PRICING_SOURCE)
+        );
+        assert!(!breakdown.estimated);
 
         // Claude 3.5 Sonnet pricing
         let pricing =
@@ -534,6 +1250,55 @@ mod tests {
         assert_eq!(breakdown.output_cost, 15.00);
     }
 
+    #[test]
+    fn test_normalize_model() {
+        assert_eq!(normalize_model("gpt-4o-2024-08-06"), "gpt-4o");
+        assert_eq!(normalize_model("GPT-3.5-Turbo-0125"), "gpt-3.5-turbo");
+        assert_eq!(normalize_model("openai/chatgpt-4o-latest"), "gpt-4o");
+        assert_eq!(normalize_model("gpt-4-1106-preview"), "gpt-4-turbo");
+        assert_eq!(
+            normalize_model("claude-3-5-sonnet@20240620"),
+            "claude-3-5-sonnet"
+        );
+        assert_eq!(normalize_model("claude-3.5-haiku"), "claude-3-5-haiku");
+        assert_eq!(normalize_model("gemini-1.5-pro-002"), "gemini-1.5-pro");
+        assert_eq!(normalize_model("mistral-large-latest"), "mistral-large");
+    }
+
+    #[test]
+    fn test_dated_models_resolve_to_family_pricing() {
+        let price = |provider: &ObsProvider, model: &str| {
+            let pricing = DefaultPricing::for_model(provider, model).unwrap();
+            (
+                pricing.input_price_per_million,
+                pricing.output_price_per_million,
+            )
+        };
+
+        assert_eq!(
+            price(&ObsProvider::OpenAI, "gpt-4o-2024-08-06"),
+            (2.50, 10.00)
+        );
+        assert_eq!(
+            price(&ObsProvider::OpenAI, "gpt-4o-mini-2024-07-18"),
+            (0.15, 0.60)
+        );
+        assert_eq!(
+            price(&ObsProvider::OpenAI, "gpt-4-0125-preview"),
+            (10.00, 30.00)
+        );
+        assert_eq!(price(&ObsProvider::OpenAI, "gpt-4-0613"), (30.00, 60.00));
+        assert_eq!(
+            price(&ObsProvider::Anthropic, "claude-3-opus-20240229"),
+            (15.00, 75.00)
+        );
+        assert_eq!(
+            price(&ObsProvider::Anthropic, "claude-3.5-sonnet"),
+            (3.00, 15.00)
+        );
+        assert!(DefaultPricing::for_model(&ObsProvider::OpenAI, "gpt-5-2030-01-01").is_none());
+    }
+
     #[test]
     fn test_record_and_aggregate() {
         let mut adapter = CostAdapter::new();
@@ -549,6 +1314,80 @@ mod tests {
         assert!(by_provider.contains_key("openai"));
     }
 
+    #[test]
+    fn test_cost_by_project() {
+        let mut adapter = CostAdapter::new();
+        let span = create_test_span();
+
+        for project in ["project-a", "project-a", "project-b"] {
+            adapter.record_span_cost_for(&span, Some(project)).unwrap();
+        }
+
+        let mut tagged = create_test_span();
+        tagged
+            .metadata
+            .attributes
+            .insert(PROJECT_ATTRIBUTE_KEY.to_string(), "project-b".to_string());
+        adapter.record_span_cost(&tagged).unwrap();
+
+        // Unattributed costs are excluded from the project split
+        adapter.record_cost(adapter.calculate_cost(&span).unwrap());
+
+        let per_span = adapter.calculate_cost(&span).unwrap().total_usd;
+        let report = adapter.generate_report(Utc::now(), Utc::now());
+        assert_eq!(report.by_project.len(), 2);
+        assert!((report.by_project["project-a"] - 2.0 * per_span).abs() < 1e-12);
+        assert!((report.by_project["project-b"] - 2.0 * per_span).abs() < 1e-12);
+        assert_eq!(report.total_requests, 5);
+    }
+
+    #[test]
+    fn test_currency_conversion() {
+        let adapter = CostAdapter::new();
+        let breakdown = adapter.calculate_cost(&create_test_span()).unwrap();
+
+        let mut rates = HashMap::new();
+        rates.insert(Currency::EUR, 0.5);
+
+        let converted = CostAdapter::convert(&breakdown, Currency::EUR, &rates).unwrap();
+        assert_eq!(converted.currency, "EUR");
+        assert!((converted.total_usd - breakdown.total_usd * 0.5).abs() < 1e-12);
+        assert!((converted.input_cost - breakdown.input_cost * 0.5).abs() < 1e-12);
+        assert!((converted.output_cost - breakdown.output_cost * 0.5).abs() < 1e-12);
+
+        assert!(matches!(
+            CostAdapter::convert(&breakdown, Currency::GBP, &rates),
+            Err(CostAdapterError::MissingExchangeRate(_))
+        ));
+    }
+
+    #[test]
+    fn test_currency_codes() {
+        let codes: Vec<&str> = CostAdapter::supported_currencies()
+            .iter()
+            .map(|currency| currency_code(currency).unwrap())
+            .collect();
+        assert_eq!(codes, ["USD", "EUR", "GBP", "JPY"]);
+    }
+
+    #[test]
+    fn test_generate_report_in_currency() {
+        let mut adapter = CostAdapter::new();
+        adapter.record_span_cost(&create_test_span()).unwrap();
+
+        let mut rates = HashMap::new();
+        rates.insert(Currency::EUR, 2.0);
+
+        let usd = adapter.generate_report(Utc::now(), Utc::now());
+        let eur = adapter
+            .generate_report_in(Currency::EUR, &rates, Utc::now(), Utc::now())
+            .unwrap();
+        assert_eq!(usd.currency, "USD");
+        assert_eq!(eur.currency, "EUR");
+        assert!((eur.total_cost - usd.total_cost * 2.0).abs() < 1e-12);
+        assert!((eur.by_provider["openai"] - usd.by_provider["openai"] * 2.0).abs() < 1e-12);
+    }
+
     #[test]
     fn test_provider_mapping() {
         assert!(matches!(
@@ -13,6 +13,7 @@
 //! - Gateway trace processing
 //! - Edge metrics aggregation
 //! - Request routing metadata extraction
+//! - Deterministic rate-based sampling of metric and log events
 //!
 //! # Architecture
 //!
@@ -34,9 +35,15 @@
 //! let traces = adapter.extract_gateway_traces(&telemetry)?;
 //! ```
 
+use super::clock::{Clock, SystemClock};
+use super::ids::{IdGenerator, RandomIdGenerator};
+use super::parse::impl_variant_str;
+use super::sampling::{Sampler, SamplingContext, TailSampler};
+use super::stats::AdapterStats;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -124,6 +131,16 @@ pub enum IngressEventType {
     Custom(String),
 }
 
+impl_variant_str!(
+    IngressEventType {
+        Span => "span",
+        Metric => "metric",
+        Log => "log",
+        Resource => "resource",
+    },
+    custom = Custom
+);
+
 /// Status of ingress processing.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -140,6 +157,16 @@ pub enum IngressStatus {
     Dropped,
 }
 
+impl_variant_str!(
+    IngressStatus {
+        Received => "received",
+        Validated => "validated",
+        Processed => "processed",
+        Failed => "failed",
+        Dropped => "dropped",
+    }
+);
+
 /// Gateway trace from edge agent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GatewayTrace {
@@ -264,6 +291,18 @@ pub enum ErrorCategory {
     Unknown,
 }
 
+impl_variant_str!(
+    ErrorCategory {
+        Client => "client",
+        Server => "server",
+        Network => "network",
+        Timeout => "timeout",
+        RateLimit => "rate_limit",
+        Auth => "auth",
+        Unknown => "unknown",
+    }
+);
+
 /// Edge metrics snapshot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EdgeMetrics {
@@ -317,21 +356,45 @@ pub struct EdgeAgentAdapter {
     ingress_events: Vec<TelemetryIngressEvent>,
     /// Collected gateway traces
     gateway_traces: Vec<GatewayTrace>,
+    /// Metrics snapshots collected for session rollups
+    metrics_snapshots: Vec<EdgeMetrics>,
+    /// Fraction (0.0-1.0) of metric/log events to sample
+    sample_rate: f64,
     /// Statistics
     stats: EdgeStats,
+    /// Source of event timestamps and trace start times
+    clock: Arc<dyn Clock>,
+    /// Source of ids the adapter assigns itself
+    ids: Arc<dyn IdGenerator>,
 }
 
 impl EdgeAgentAdapter {
     /// Create a new EdgeAgentAdapter.
     pub fn new(edge_node_id: impl Into<String>) -> Self {
+        Self::with_clock(edge_node_id, Arc::new(SystemClock))
+    }
+
+    /// Create a new EdgeAgentAdapter that reads the time from `clock`.
+    pub fn with_clock(edge_node_id: impl Into<String>, clock: Arc<dyn Clock>) -> Self {
         Self {
             edge_node_id: EdgeNodeId::new(edge_node_id),
             ingress_events: Vec::new(),
             gateway_traces: Vec::new(),
+            metrics_snapshots: Vec::new(),
+            sample_rate: 0.0,
             stats: EdgeStats::default(),
+            clock,
+            ids: Arc::new(RandomIdGenerator),
         }
     }
 
+    /// Replace the generator of ids the adapter assigns itself, e.g. with a
+    /// [`SequentialIdGenerator`](super::ids::SequentialIdGenerator) for
+    /// reproducible output.
+    pub fn set_id_generator(&mut self, ids: Arc<dyn IdGenerator>) {
+        self.ids = ids;
+    }
+
     /// Get the edge node ID.
     pub fn edge_node_id(&self) -> &EdgeNodeId {
         &self.edge_node_id
@@ -345,13 +408,7 @@ impl EdgeAgentAdapter {
         let event_type = json_data
             .get("event_type")
             .and_then(|v| v.as_str())
-            .map(|s| match s {
-                "span" => IngressEventType::Span,
-                "metric" => IngressEventType::Metric,
-                "log" => IngressEventType::Log,
-                "resource" => IngressEventType::Resource,
-                other => IngressEventType::Custom(other.to_string()),
-            })
+            .and_then(|s| s.parse::<IngressEventType>().ok())
             .ok_or_else(|| EdgeAgentAdapterError::MissingField("event_type".to_string()))?;
 
         let payload = json_data
@@ -365,9 +422,9 @@ impl EdgeAgentAdapter {
             .unwrap_or_default();
 
         let event = TelemetryIngressEvent {
-            event_id: Uuid::new_v4(),
+            event_id: self.ids.next_id(),
             edge_node_id: self.edge_node_id.clone(),
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
             event_type,
             payload,
             metadata,
@@ -426,8 +483,7 @@ impl EdgeAgentAdapter {
         let span_id = payload
             .get("span_id")
             .and_then(|v| v.as_str())
-            .unwrap_or(&Uuid::new_v4().to_string())
-            .to_string();
+            .map_or_else(|| self.ids.next_id().to_string(), String::from);
 
         let operation = payload
             .get("operation")
@@ -493,7 +549,7 @@ impl EdgeAgentAdapter {
                 .map(String::from),
             operation,
             edge_node_id: self.edge_node_id.clone(),
-            start_time: Utc::now(),
+            start_time: self.clock.now(),
             end_time: None,
             duration_ms: payload.get("duration_ms").and_then(|v| v.as_u64()),
             routing,
@@ -549,9 +605,64 @@ impl EdgeAgentAdapter {
     pub fn clear(&mut self) {
         self.ingress_events.clear();
         self.gateway_traces.clear();
+        self.metrics_snapshots.clear();
         self.stats = EdgeStats::default();
     }
 
+    /// Add a metrics snapshot to the session rollup.
+    pub fn push_snapshot(&mut self, snapshot: EdgeMetrics) {
+        self.metrics_snapshots.push(snapshot);
+    }
+
+    /// Get all collected metrics snapshots.
+    pub fn metrics_snapshots(&self) -> &[EdgeMetrics] {
+        &self.metrics_snapshots
+    }
+
+    /// Roll up all collected metrics snapshots into one.
+    ///
+    /// Rates, latencies and error rates are averaged, byte counters are
+    /// summed, and `p99_latency_ms` is the highest p99 observed. Gauges
+    /// (`active_connections`, `queue_depth`) and the timestamp come from the
+    /// most recent snapshot. With no snapshots, every value is zero and the
+    /// timestamp is the adapter clock's current time.
+    pub fn aggregate_edge_metrics(&self) -> EdgeMetrics {
+        let mut aggregate = EdgeMetrics {
+            edge_node_id: self.edge_node_id.clone(),
+            timestamp: self.clock.now(),
+            requests_per_second: 0.0,
+            avg_latency_ms: 0.0,
+            p99_latency_ms: 0.0,
+            error_rate: 0.0,
+            active_connections: 0,
+            bytes_received: 0,
+            bytes_sent: 0,
+            queue_depth: 0,
+        };
+
+        let Some(latest) = self.metrics_snapshots.iter().max_by_key(|m| m.timestamp) else {
+            return aggregate;
+        };
+        aggregate.timestamp = latest.timestamp;
+        aggregate.active_connections = latest.active_connections;
+        aggregate.queue_depth = latest.queue_depth;
+
+        for snapshot in &self.metrics_snapshots {
+            aggregate.requests_per_second += snapshot.requests_per_second;
+            aggregate.avg_latency_ms += snapshot.avg_latency_ms;
+            aggregate.error_rate += snapshot.error_rate;
+            aggregate.p99_latency_ms = aggregate.p99_latency_ms.max(snapshot.p99_latency_ms);
+            aggregate.bytes_received += snapshot.bytes_received;
+            aggregate.bytes_sent += snapshot.bytes_sent;
+        }
+
+        let count = self.metrics_snapshots.len() as f64;
+        aggregate.requests_per_second /= count;
+        aggregate.avg_latency_ms /= count;
+        aggregate.error_rate /= count;
+        aggregate
+    }
+
     /// Create edge metrics from current state.
     pub fn create_metrics_snapshot(&self) -> EdgeMetrics {
         let processed = self.stats.total_events_processed as f64;
@@ -560,7 +671,7 @@ impl EdgeAgentAdapter {
 
         EdgeMetrics {
             edge_node_id: self.edge_node_id.clone(),
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
             requests_per_second: 0.0, // Would need time tracking for real value
             avg_latency_ms: self.stats.avg_ingress_latency_ms,
             p99_latency_ms: 0.0, // Would need latency tracking
@@ -572,7 +683,25 @@ impl EdgeAgentAdapter {
         }
     }
 
+    /// Fraction (0.0-1.0) of metric/log events that are sampled.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// Set the fraction of metric/log events to sample, clamped to [0, 1].
+    pub fn set_sample_rate(&mut self, rate: f64) {
+        self.sample_rate = if rate.is_nan() {
+            0.0
+        } else {
+            rate.clamp(0.0, 1.0)
+        };
+    }
+
     /// Check if an event should be sampled (for tail-based sampling).
+    ///
+    /// Failed events, spans and custom events are always sampled. Other
+    /// events are sampled at [`sample_rate`](Self::sample_rate), selected
+    /// deterministically by hashing the event ID.
     pub fn should_sample_event(&self, event: &TelemetryIngressEvent) -> bool {
         // Always sample failed events
         if event.status == IngressStatus::Failed {
@@ -589,7 +718,12 @@ impl EdgeAgentAdapter {
             return true;
         }
 
-        false
+        TailSampler::new()
+            .with_normal_sample_rate(self.sample_rate)
+            .should_sample(&SamplingContext {
+                key: Some(event.event_id.to_string()),
+                ..Default::default()
+            })
     }
 
     /// Convert a gateway trace to an Observatory-compatible span format.
@@ -616,9 +750,20 @@ impl EdgeAgentAdapter {
     }
 }
 
+impl AdapterStats for EdgeAgentAdapter {
+    fn adapter_name(&self) -> String {
+        format!("edge_agent/{}", self.edge_node_id.as_str())
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(&self.stats).unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::upstream::clock::MockClock;
 
     #[test]
     fn test_edge_agent_adapter_creation() {
@@ -651,6 +796,22 @@ mod tests {
         assert_eq!(event.status, IngressStatus::Received);
     }
 
+    #[test]
+    fn test_ingress_timestamp_comes_from_clock() {
+        let now = Utc::now() - chrono::Duration::minutes(10);
+        let mut adapter =
+            EdgeAgentAdapter::with_clock("edge-node-1", Arc::new(MockClock::new(now)));
+
+        let event = adapter
+            .parse_telemetry_ingress(&serde_json::json!({
+                "event_type": "metric",
+                "payload": {"name": "requests", "value": 1}
+            }))
+            .unwrap();
+        assert_eq!(event.timestamp, now);
+        assert_eq!(adapter.create_metrics_snapshot().timestamp, now);
+    }
+
     #[test]
     fn test_process_ingress_event() {
         let mut adapter = EdgeAgentAdapter::new("edge-node-1");
@@ -736,6 +897,56 @@ mod tests {
         assert!(!adapter.should_sample_event(&metric_event));
     }
 
+    #[test]
+    fn test_should_sample_event_rate() {
+        let mut adapter = EdgeAgentAdapter::new("edge-node-1");
+        let event = |event_type: IngressEventType| TelemetryIngressEvent {
+            event_id: Uuid::new_v4(),
+            edge_node_id: EdgeNodeId::new("node1"),
+            timestamp: Utc::now(),
+            event_type,
+            payload: serde_json::Value::Null,
+            metadata: HashMap::new(),
+            status: IngressStatus::Processed,
+        };
+        let events: Vec<TelemetryIngressEvent> = (0..50)
+            .flat_map(|_| {
+                [
+                    event(IngressEventType::Span),
+                    event(IngressEventType::Custom("audit".to_string())),
+                    event(IngressEventType::Metric),
+                    event(IngressEventType::Log),
+                ]
+            })
+            .collect();
+        let always_on = |e: &TelemetryIngressEvent| {
+            matches!(
+                e.event_type,
+                IngressEventType::Span | IngressEventType::Custom(_)
+            )
+        };
+
+        adapter.set_sample_rate(1.0);
+        assert!(events.iter().all(|e| adapter.should_sample_event(e)));
+
+        adapter.set_sample_rate(0.0);
+        assert!(events
+            .iter()
+            .all(|e| adapter.should_sample_event(e) == always_on(e)));
+
+        adapter.set_sample_rate(0.5);
+        let sampled = events
+            .iter()
+            .filter(|e| !always_on(e) && adapter.should_sample_event(e))
+            .count();
+        assert!(sampled > 0 && sampled < 100, "sampled {}", sampled);
+
+        adapter.set_sample_rate(7.5);
+        assert_eq!(adapter.sample_rate(), 1.0);
+        adapter.set_sample_rate(-1.0);
+        assert_eq!(adapter.sample_rate(), 0.0);
+    }
+
     #[test]
     fn test_trace_to_span_json() {
         let adapter = EdgeAgentAdapter::new("edge-node-1");
@@ -803,4 +1014,84 @@ mod tests {
         assert!(adapter.gateway_traces().is_empty());
         assert_eq!(adapter.stats().total_events_received, 0);
     }
+
+    #[test]
+    fn test_aggregate_edge_metrics() {
+        let now = Utc::now() - chrono::Duration::minutes(10);
+        let mut adapter =
+            EdgeAgentAdapter::with_clock("edge-node-1", Arc::new(MockClock::new(now)));
+
+        let empty = adapter.aggregate_edge_metrics();
+        assert_eq!(empty.requests_per_second, 0.0);
+        assert_eq!(empty.bytes_received, 0);
+        assert_eq!(empty.timestamp, now);
+
+        let snapshot = |rps: f64, latency: f64, p99: f64, bytes: u64| EdgeMetrics {
+            edge_node_id: EdgeNodeId::new("edge-node-1"),
+            timestamp: Utc::now(),
+            requests_per_second: rps,
+            avg_latency_ms: latency,
+            p99_latency_ms: p99,
+            error_rate: 0.0,
+            active_connections: 4,
+            bytes_received: bytes,
+            bytes_sent: bytes * 2,
+            queue_depth: 1,
+        };
+        adapter.push_snapshot(snapshot(10.0, 100.0, 400.0, 1000));
+        adapter.push_snapshot(snapshot(30.0, 200.0, 250.0, 500));
+
+        let aggregate = adapter.aggregate_edge_metrics();
+        assert_eq!(aggregate.requests_per_second, 20.0);
+        assert_eq!(aggregate.avg_latency_ms, 150.0);
+        assert_eq!(aggregate.p99_latency_ms, 400.0);
+        assert_eq!(aggregate.bytes_received, 1500);
+        assert_eq!(aggregate.bytes_sent, 3000);
+        assert_eq!(aggregate.active_connections, 4);
+    }
+
+    #[test]
+    fn test_enums_round_trip_through_strings() {
+        for value in [
+            IngressEventType::Span,
+            IngressEventType::Metric,
+            IngressEventType::Log,
+            IngressEventType::Resource,
+        ] {
+            let parsed: IngressEventType = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        let custom = IngressEventType::Custom("my_custom".to_string());
+        let parsed: IngressEventType = custom.to_string().parse().unwrap();
+        assert_eq!(parsed, custom);
+
+        for value in [
+            IngressStatus::Received,
+            IngressStatus::Validated,
+            IngressStatus::Processed,
+            IngressStatus::Failed,
+            IngressStatus::Dropped,
+        ] {
+            let parsed: IngressStatus = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<IngressStatus>().is_err());
+
+        for value in [
+            ErrorCategory::Client,
+            ErrorCategory::Server,
+            ErrorCategory::Network,
+            ErrorCategory::Timeout,
+            ErrorCategory::RateLimit,
+            ErrorCategory::Auth,
+            ErrorCategory::Unknown,
+        ] {
+            let parsed: ErrorCategory = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<ErrorCategory>().is_err());
+    }
 }
@@ -0,0 +1,91 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pluggable generation of ids that adapters assign themselves.
+//!
+//! Adapters generate event, telemetry, span and step ids through an
+//! [`IdGenerator`] instead of calling `Uuid::new_v4()` directly, so their
+//! output can be made reproducible in tests.
+//!
+//! # Features
+//!
+//! - [`RandomIdGenerator`]: random v4 UUIDs, used by default
+//! - [`SequentialIdGenerator`]: UUIDs counting up from 1, for tests
+//!
+//! # Example
+//!
+//! ```ignore
+//! use llm_observatory_adapters::upstream::prelude::*;
+//! use std::sync::Arc;
+//!
+//! let mut adapter = OrchestratorAdapter::new("orch-1");
+//! adapter.set_id_generator(Arc::new(SequentialIdGenerator::new()));
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Source of ids for adapter-generated records.
+pub trait IdGenerator: Send + Sync {
+    /// Next id.
+    fn next_id(&self) -> Uuid;
+}
+
+/// Generator of random (v4) UUIDs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Deterministic generator yielding `00000000-0000-0000-0000-000000000001`,
+/// then `...0002`, and so on.
+#[derive(Debug)]
+pub struct SequentialIdGenerator {
+    next: AtomicU64,
+}
+
+impl SequentialIdGenerator {
+    /// Create a generator starting at 1.
+    pub fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// Create a generator whose first id is `first`.
+    pub fn starting_at(first: u64) -> Self {
+        Self {
+            next: AtomicU64::new(first),
+        }
+    }
+}
+
+impl Default for SequentialIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Uuid::from_u128(u128::from(self.next.fetch_add(1, Ordering::Relaxed)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_ids_count_up() {
+        let ids = SequentialIdGenerator::new();
+        assert_eq!(
+            ids.next_id().to_string(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+        assert_eq!(ids.next_id(), Uuid::from_u128(2));
+        assert_ne!(RandomIdGenerator.next_id(), RandomIdGenerator.next_id());
+    }
+}
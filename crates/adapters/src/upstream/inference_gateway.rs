@@ -13,6 +13,12 @@
 //! - Inference telemetry processing
 //! - Model routing decisions tracking
 //! - Load balancing metrics aggregation
+//! - Backend health transition tracking
+//! - Per-backend circuit breakers fed by inference telemetry and edge gateway
+//!   routing
+//! - Configurable tail-based sampling
+//! - Per-request cost from backend pricing, falling back to default model pricing
+//! - Retry totals and per-backend retry counts from routing logs
 //!
 //! # Architecture
 //!
@@ -34,9 +40,19 @@
 //! let telemetry = adapter.extract_inference_telemetry(&routing_log)?;
 //! ```
 
+use super::clock::{Clock, SystemClock};
+use super::cost::DefaultPricing;
+use super::edge_agent::GatewayRouting;
+use super::ids::{IdGenerator, RandomIdGenerator};
+use super::parse::impl_variant_str;
+use super::sampling::{Sampler, SamplingContext, TailSampler};
+use super::stats::AdapterStats;
 use chrono::{DateTime, Utc};
+use llm_observatory_core::types::{Provider as ObsProvider, TokenUsage};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -126,6 +142,9 @@ pub struct RoutingLog {
     pub available_backends: Vec<BackendInfo>,
     /// Routing strategy used
     pub strategy: RoutingStrategy,
+    /// Retries made before this routing decision
+    #[serde(default)]
+    pub retry_count: u32,
     /// Additional context
     pub context: HashMap<String, serde_json::Value>,
 }
@@ -146,6 +165,16 @@ pub enum RoutingDecision {
     NoBackend,
 }
 
+impl_variant_str!(
+    RoutingDecision {
+        Routed => "routed",
+        Queued => "queued",
+        Rejected => "rejected",
+        Fallback => "fallback",
+        NoBackend => "no_backend",
+    }
+);
+
 /// Routing strategy used for decision.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -166,6 +195,18 @@ pub enum RoutingStrategy {
     Custom(String),
 }
 
+impl_variant_str!(
+    RoutingStrategy {
+        RoundRobin => "round_robin",
+        LeastConnections => "least_connections",
+        WeightedRandom => "weighted_random",
+        LatencyBased => "latency_based",
+        CostBased => "cost_based",
+        ModelSpecific => "model_specific",
+    },
+    custom = Custom
+);
+
 /// Backend information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInfo {
@@ -199,6 +240,46 @@ pub enum BackendHealth {
     Unknown,
 }
 
+impl_variant_str!(
+    BackendHealth {
+        Healthy => "healthy",
+        Degraded => "degraded",
+        Unhealthy => "unhealthy",
+        Unknown => "unknown",
+    }
+);
+
+impl BackendHealth {
+    /// Rank used to pick the worst status when combining reports.
+    ///
+    /// `Unknown` ranks above `Healthy` so that a backend is never reported
+    /// healthy unless every source agrees.
+    fn severity(&self) -> u8 {
+        match self {
+            Self::Healthy => 0,
+            Self::Unknown => 1,
+            Self::Degraded => 2,
+            Self::Unhealthy => 3,
+        }
+    }
+}
+
+/// Most recent backend health transitions kept by the adapter.
+pub const HEALTH_TRANSITION_CAPACITY: usize = 1_000;
+
+/// A change in a backend's health status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthTransition {
+    /// Backend whose health changed
+    pub backend_id: BackendId,
+    /// Health before the change
+    pub from: BackendHealth,
+    /// Health after the change
+    pub to: BackendHealth,
+    /// When the change was observed
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Inference telemetry from a routed request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InferenceTelemetry {
@@ -208,6 +289,9 @@ pub struct InferenceTelemetry {
     pub request_id: String,
     /// Trace ID (for distributed tracing)
     pub trace_id: Option<String>,
+    /// Span of the caller that made the request, e.g. an orchestrator step
+    #[serde(default)]
+    pub parent_span_id: Option<String>,
     /// Gateway that processed this request
     pub gateway_id: GatewayId,
     /// Backend that served the request
@@ -265,6 +349,16 @@ pub enum InferenceStatus {
     Cancelled,
 }
 
+impl_variant_str!(
+    InferenceStatus {
+        Success => "success",
+        Partial => "partial",
+        Failed => "failed",
+        Timeout => "timeout",
+        Cancelled => "cancelled",
+    }
+);
+
 /// Inference error details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InferenceError {
@@ -294,6 +388,16 @@ pub enum ErrorSource {
     Unknown,
 }
 
+impl_variant_str!(
+    ErrorSource {
+        Backend => "backend",
+        Gateway => "gateway",
+        Client => "client",
+        Network => "network",
+        Unknown => "unknown",
+    }
+);
+
 /// Gateway statistics.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GatewayStats {
@@ -315,6 +419,223 @@ pub struct GatewayStats {
     pub avg_routing_latency_us: f64,
     /// Average inference latency (ms)
     pub avg_inference_latency_ms: f64,
+    /// Total cost of priced inference requests (USD)
+    #[serde(default)]
+    pub total_cost_usd: f64,
+    /// Total retries reported by routing logs
+    #[serde(default)]
+    pub total_retries: u64,
+    /// Retries per selected backend
+    #[serde(default)]
+    pub retries_by_backend: HashMap<String, u64>,
+}
+
+impl GatewayStats {
+    /// Average retries per routing decision (0.0 with no decisions).
+    pub fn retry_rate(&self) -> f64 {
+        if self.total_routing_decisions == 0 {
+            return 0.0;
+        }
+        self.total_retries as f64 / self.total_routing_decisions as f64
+    }
+
+    /// The backend with the most retries and its retry count, if any
+    /// backend was retried. Ties go to the alphabetically first backend.
+    pub fn most_retried_backend(&self) -> Option<(&str, u64)> {
+        self.retries_by_backend
+            .iter()
+            .filter(|(_, &retries)| retries > 0)
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(backend, &retries)| (backend.as_str(), retries))
+    }
+}
+
+/// Tail-based sampling thresholds for inference requests.
+///
+/// Failed requests are always sampled. Successful requests are sampled when
+/// they exceed either threshold, or otherwise for a deterministic fraction
+/// (`sample_rate_for_normal`) selected by hashing the `request_id`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SamplingConfig {
+    /// Sample requests slower than this (milliseconds)
+    pub latency_ms: u64,
+    /// Sample requests using more tokens than this
+    pub token_count: u32,
+    /// Fraction (0.0-1.0) of otherwise-normal requests to sample
+    pub sample_rate_for_normal: f64,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            latency_ms: 5000,
+            token_count: 10000,
+            sample_rate_for_normal: 0.0,
+        }
+    }
+}
+
+impl From<&SamplingConfig> for TailSampler {
+    fn from(config: &SamplingConfig) -> Self {
+        TailSampler::new()
+            .with_latency_ms(config.latency_ms)
+            .with_token_count(u64::from(config.token_count))
+            .with_normal_sample_rate(config.sample_rate_for_normal)
+    }
+}
+
+/// Circuit breaker state for a backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Too many consecutive failures; the backend is skipped
+    Open,
+    /// Cooldown elapsed; the backend may take a single trial request
+    HalfOpen,
+}
+
+impl_variant_str!(
+    CircuitState {
+        Closed => "closed",
+        Open => "open",
+        HalfOpen => "half_open",
+    }
+);
+
+/// Circuit breaker thresholds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// How long the breaker stays open before half-opening
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Consecutive-failure circuit breaker for one backend.
+///
+/// Opens after `failure_threshold` consecutive failures and half-opens once
+/// `cooldown` has passed. While half-open, [`CircuitBreaker::try_acquire`]
+/// lets exactly one trial request through; its success closes the breaker
+/// and its failure re-opens it immediately.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    /// Thresholds
+    config: CircuitBreakerConfig,
+    /// Failures since the last success
+    consecutive_failures: u32,
+    /// When the breaker last opened
+    opened_at: Option<DateTime<Utc>>,
+    /// Whether the half-open trial request has been handed out
+    trial_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            consecutive_failures: 0,
+            opened_at: None,
+            trial_in_flight: false,
+        }
+    }
+
+    /// Current state.
+    pub fn state(&self) -> CircuitState {
+        self.state_at(Utc::now())
+    }
+
+    /// Failures since the last success.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Whether a request may be sent now, claiming the trial slot when
+    /// half-open.
+    pub fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Utc::now())
+    }
+
+    /// Record a successful request, closing the breaker.
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+        self.trial_in_flight = false;
+    }
+
+    /// Record a failed request, opening the breaker if the threshold is hit.
+    pub fn record_failure(&mut self) {
+        self.record_failure_at(Utc::now());
+    }
+
+    fn state_at(&self, now: DateTime<Utc>) -> CircuitState {
+        let Some(opened_at) = self.opened_at else {
+            return CircuitState::Closed;
+        };
+        match (now - opened_at).to_std() {
+            Ok(elapsed) if elapsed >= self.config.cooldown => CircuitState::HalfOpen,
+            _ => CircuitState::Open,
+        }
+    }
+
+    fn allows_request_at(&self, now: DateTime<Utc>) -> bool {
+        match self.state_at(now) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => !self.trial_in_flight,
+        }
+    }
+
+    fn try_acquire_at(&mut self, now: DateTime<Utc>) -> bool {
+        if !self.allows_request_at(now) {
+            return false;
+        }
+        if self.state_at(now) == CircuitState::HalfOpen {
+            self.trial_in_flight = true;
+        }
+        true
+    }
+
+    fn record_failure_at(&mut self, now: DateTime<Utc>) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        let trial_failed = self.state_at(now) == CircuitState::HalfOpen;
+        if trial_failed || self.consecutive_failures >= self.config.failure_threshold {
+            self.opened_at = Some(now);
+            self.trial_in_flight = false;
+        }
+    }
+
+    /// Adopt a state reported by an upstream gateway's own breaker.
+    fn apply_reported_at(&mut self, state: CircuitState, now: DateTime<Utc>) {
+        match state {
+            CircuitState::Closed => self.record_success(),
+            CircuitState::Open => {
+                if self.state_at(now) != CircuitState::Open {
+                    self.opened_at = Some(now);
+                }
+                self.trial_in_flight = false;
+            }
+            CircuitState::HalfOpen => {
+                if self.state_at(now) != CircuitState::HalfOpen {
+                    let cooldown = chrono::Duration::from_std(self.config.cooldown)
+                        .unwrap_or_else(|_| chrono::Duration::zero());
+                    self.opened_at = Some(now - cooldown);
+                    self.trial_in_flight = false;
+                }
+            }
+        }
+    }
 }
 
 /// Load balancing metrics.
@@ -332,6 +653,70 @@ pub struct LoadBalancingMetrics {
     pub backend_health: HashMap<String, BackendHealth>,
 }
 
+impl LoadBalancingMetrics {
+    /// Gateway ID used when merging snapshots from different gateways.
+    pub const AGGREGATE_GATEWAY_ID: &'static str = "aggregate";
+
+    /// Combine snapshots from several gateways into one.
+    ///
+    /// Request counts are summed, load is averaged over the snapshots that
+    /// report each backend, and the worst health seen for a backend wins.
+    /// The result keeps the shared `gateway_id` if all inputs agree and uses
+    /// [`Self::AGGREGATE_GATEWAY_ID`] otherwise; its timestamp is the latest
+    /// input timestamp.
+    pub fn merge(metrics: &[LoadBalancingMetrics]) -> LoadBalancingMetrics {
+        let gateway_id = match metrics.split_first() {
+            Some((first, rest)) if rest.iter().all(|m| m.gateway_id == first.gateway_id) => {
+                first.gateway_id.clone()
+            }
+            _ => GatewayId::new(Self::AGGREGATE_GATEWAY_ID),
+        };
+        let timestamp = metrics
+            .iter()
+            .map(|m| m.timestamp)
+            .max()
+            .unwrap_or_else(Utc::now);
+
+        let mut requests_per_backend: HashMap<String, u64> = HashMap::new();
+        let mut load_totals: HashMap<String, (f64, u32)> = HashMap::new();
+        let mut backend_health: HashMap<String, BackendHealth> = HashMap::new();
+
+        for snapshot in metrics {
+            for (backend, requests) in &snapshot.requests_per_backend {
+                *requests_per_backend.entry(backend.clone()).or_insert(0) += requests;
+            }
+            for (backend, load) in &snapshot.load_per_backend {
+                let total = load_totals.entry(backend.clone()).or_insert((0.0, 0));
+                total.0 += load;
+                total.1 += 1;
+            }
+            for (backend, health) in &snapshot.backend_health {
+                backend_health
+                    .entry(backend.clone())
+                    .and_modify(|worst| {
+                        if health.severity() > worst.severity() {
+                            *worst = health.clone();
+                        }
+                    })
+                    .or_insert_with(|| health.clone());
+            }
+        }
+
+        let load_per_backend = load_totals
+            .into_iter()
+            .map(|(backend, (sum, count))| (backend, sum / f64::from(count)))
+            .collect();
+
+        LoadBalancingMetrics {
+            gateway_id,
+            timestamp,
+            requests_per_backend,
+            load_per_backend,
+            backend_health,
+        }
+    }
+}
+
 /// Adapter for consuming LLM-Inference-Gateway telemetry.
 ///
 /// Provides runtime integration for Observatory to ingest routing logs
@@ -345,22 +730,55 @@ pub struct InferenceGatewayAdapter {
     inference_telemetry: Vec<InferenceTelemetry>,
     /// Backend registry
     backends: HashMap<String, BackendInfo>,
+    /// Most recent backend health changes, oldest first
+    health_transitions: VecDeque<HealthTransition>,
+    /// Circuit breakers keyed by backend ID
+    breakers: HashMap<String, CircuitBreaker>,
+    /// Thresholds for new and existing breakers
+    breaker_config: CircuitBreakerConfig,
     /// Statistics
     stats: GatewayStats,
+    /// Tail-based sampling thresholds
+    sampling: SamplingConfig,
+    /// Sampler built from `sampling`
+    sampler: TailSampler,
+    /// Source of timestamps and circuit breaker time
+    clock: Arc<dyn Clock>,
+    /// Source of ids the adapter assigns itself
+    ids: Arc<dyn IdGenerator>,
 }
 
 impl InferenceGatewayAdapter {
     /// Create a new InferenceGatewayAdapter.
     pub fn new(gateway_id: impl Into<String>) -> Self {
+        Self::with_clock(gateway_id, Arc::new(SystemClock))
+    }
+
+    /// Create a new InferenceGatewayAdapter that reads the time from `clock`.
+    pub fn with_clock(gateway_id: impl Into<String>, clock: Arc<dyn Clock>) -> Self {
         Self {
             gateway_id: GatewayId::new(gateway_id),
             routing_logs: Vec::new(),
             inference_telemetry: Vec::new(),
             backends: HashMap::new(),
+            health_transitions: VecDeque::new(),
+            breakers: HashMap::new(),
+            breaker_config: CircuitBreakerConfig::default(),
             stats: GatewayStats::default(),
+            sampling: SamplingConfig::default(),
+            sampler: TailSampler::from(&SamplingConfig::default()),
+            clock,
+            ids: Arc::new(RandomIdGenerator),
         }
     }
 
+    /// Replace the generator of ids the adapter assigns itself, e.g. with a
+    /// [`SequentialIdGenerator`](super::ids::SequentialIdGenerator) for
+    /// reproducible output.
+    pub fn set_id_generator(&mut self, ids: Arc<dyn IdGenerator>) {
+        self.ids = ids;
+    }
+
     /// Get the gateway ID.
     pub fn gateway_id(&self) -> &GatewayId {
         &self.gateway_id
@@ -377,6 +795,81 @@ impl InferenceGatewayAdapter {
         &self.backends
     }
 
+    /// Update a registered backend's health.
+    ///
+    /// Returns the recorded transition if the health changed, or `None` if it
+    /// is unchanged or the backend is not registered.
+    pub fn update_backend_health(
+        &mut self,
+        backend_id: &str,
+        new_health: BackendHealth,
+    ) -> Option<HealthTransition> {
+        let backend = self.backends.get_mut(backend_id)?;
+        if backend.health == new_health {
+            return None;
+        }
+
+        let transition = HealthTransition {
+            backend_id: backend.backend_id.clone(),
+            from: std::mem::replace(&mut backend.health, new_health.clone()),
+            to: new_health,
+            timestamp: self.clock.now(),
+        };
+        if self.health_transitions.len() == HEALTH_TRANSITION_CAPACITY {
+            self.health_transitions.pop_front();
+        }
+        self.health_transitions.push_back(transition.clone());
+        Some(transition)
+    }
+
+    /// Get the last [`HEALTH_TRANSITION_CAPACITY`] backend health
+    /// transitions, oldest first.
+    pub fn health_transitions(&self) -> &VecDeque<HealthTransition> {
+        &self.health_transitions
+    }
+
+    /// Get the circuit breaker state for a backend.
+    ///
+    /// Backends with no inference telemetry yet are `Closed`.
+    pub fn breaker_state(&self, backend_id: &str) -> CircuitState {
+        self.breakers
+            .get(backend_id)
+            .map_or(CircuitState::Closed, |breaker| {
+                breaker.state_at(self.clock.now())
+            })
+    }
+
+    /// Feed the breaker state an edge gateway reported for a routed request
+    /// into that backend's breaker.
+    ///
+    /// Routing without a backend or with an unrecognized state is ignored.
+    /// Returns the state applied, if any.
+    pub fn observe_gateway_routing(&mut self, routing: &GatewayRouting) -> Option<CircuitState> {
+        let backend_id = routing.backend.as_deref()?;
+        let state: CircuitState = routing.circuit_breaker_state.as_deref()?.parse().ok()?;
+
+        let now = self.clock.now();
+        let breaker_config = &self.breaker_config;
+        self.breakers
+            .entry(backend_id.to_string())
+            .or_insert_with(|| CircuitBreaker::new(breaker_config.clone()))
+            .apply_reported_at(state, now);
+        Some(state)
+    }
+
+    /// Get the circuit breaker configuration.
+    pub fn circuit_breaker_config(&self) -> &CircuitBreakerConfig {
+        &self.breaker_config
+    }
+
+    /// Replace the circuit breaker configuration, applying it to all breakers.
+    pub fn set_circuit_breaker_config(&mut self, config: CircuitBreakerConfig) {
+        for breaker in self.breakers.values_mut() {
+            breaker.config = config.clone();
+        }
+        self.breaker_config = config;
+    }
+
     /// Parse a routing log from JSON.
     pub fn parse_routing_log(&mut self, json_data: &serde_json::Value) -> Result<RoutingLog> {
         let request_id = json_data
@@ -388,27 +881,13 @@ impl InferenceGatewayAdapter {
         let decision = json_data
             .get("decision")
             .and_then(|v| v.as_str())
-            .map(|s| match s {
-                "routed" => RoutingDecision::Routed,
-                "queued" => RoutingDecision::Queued,
-                "rejected" => RoutingDecision::Rejected,
-                "fallback" => RoutingDecision::Fallback,
-                _ => RoutingDecision::NoBackend,
-            })
+            .map(|s| s.parse().unwrap_or(RoutingDecision::NoBackend))
             .unwrap_or(RoutingDecision::Routed);
 
         let strategy = json_data
             .get("strategy")
             .and_then(|v| v.as_str())
-            .map(|s| match s {
-                "round_robin" => RoutingStrategy::RoundRobin,
-                "least_connections" => RoutingStrategy::LeastConnections,
-                "weighted_random" => RoutingStrategy::WeightedRandom,
-                "latency_based" => RoutingStrategy::LatencyBased,
-                "cost_based" => RoutingStrategy::CostBased,
-                "model_specific" => RoutingStrategy::ModelSpecific,
-                other => RoutingStrategy::Custom(other.to_string()),
-            })
+            .and_then(|s| s.parse::<RoutingStrategy>().ok())
             .unwrap_or(RoutingStrategy::RoundRobin);
 
         let selected_backend = json_data
@@ -417,9 +896,9 @@ impl InferenceGatewayAdapter {
             .map(|s| BackendId::new(s));
 
         let log = RoutingLog {
-            log_id: Uuid::new_v4(),
+            log_id: self.ids.next_id(),
             gateway_id: self.gateway_id.clone(),
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
             request_id,
             decision: decision.clone(),
             selected_backend,
@@ -429,12 +908,28 @@ impl InferenceGatewayAdapter {
                 .unwrap_or(0),
             available_backends: Vec::new(),
             strategy,
+            retry_count: json_data
+                .get("retry_count")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as u32,
             context: HashMap::new(),
         };
 
         self.routing_logs.push(log.clone());
         self.stats.total_routing_decisions += 1;
 
+        if log.retry_count > 0 {
+            let retries = u64::from(log.retry_count);
+            self.stats.total_retries += retries;
+            if let Some(backend) = &log.selected_backend {
+                *self
+                    .stats
+                    .retries_by_backend
+                    .entry(backend.as_str().to_string())
+                    .or_default() += retries;
+            }
+        }
+
         match decision {
             RoutingDecision::Routed => self.stats.successful_routes += 1,
             RoutingDecision::Fallback => self.stats.fallback_routes += 1,
@@ -476,21 +971,21 @@ impl InferenceGatewayAdapter {
         let status = json_data
             .get("status")
             .and_then(|v| v.as_str())
-            .map(|s| match s {
-                "success" => InferenceStatus::Success,
-                "partial" => InferenceStatus::Partial,
-                "failed" => InferenceStatus::Failed,
-                "timeout" => InferenceStatus::Timeout,
-                "cancelled" => InferenceStatus::Cancelled,
-                _ => InferenceStatus::Failed,
-            })
+            .map(|s| s.parse().unwrap_or(InferenceStatus::Failed))
             .unwrap_or(InferenceStatus::Success);
 
+        // The reported total is recomputed from its parts so it is always consistent
         let token_usage = json_data.get("token_usage").and_then(|v| {
-            Some(InferenceTokenUsage {
+            let mut usage = TokenUsage {
                 prompt_tokens: v.get("prompt_tokens")?.as_u64()? as u32,
                 completion_tokens: v.get("completion_tokens")?.as_u64()? as u32,
-                total_tokens: v.get("total_tokens")?.as_u64()? as u32,
+                total_tokens: v.get("total_tokens").and_then(|t| t.as_u64()).unwrap_or(0) as u32,
+            };
+            usage.reconcile();
+            Some(InferenceTokenUsage {
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+                total_tokens: usage.total_tokens,
                 cached_tokens: v
                     .get("cached_tokens")
                     .and_then(|c| c.as_u64())
@@ -499,17 +994,21 @@ impl InferenceGatewayAdapter {
         });
 
         let telemetry = InferenceTelemetry {
-            telemetry_id: Uuid::new_v4(),
+            telemetry_id: self.ids.next_id(),
             request_id,
             trace_id: json_data
                 .get("trace_id")
                 .and_then(|v| v.as_str())
                 .map(String::from),
+            parent_span_id: json_data
+                .get("parent_span_id")
+                .and_then(|v| v.as_str())
+                .map(String::from),
             gateway_id: self.gateway_id.clone(),
             backend_id: BackendId::new(backend_id),
             model,
             provider,
-            request_time: Utc::now(),
+            request_time: self.clock.now(),
             response_time: None,
             total_latency_ms: json_data.get("total_latency_ms").and_then(|v| v.as_u64()),
             ttft_ms: json_data.get("ttft_ms").and_then(|v| v.as_u64()),
@@ -531,6 +1030,23 @@ impl InferenceGatewayAdapter {
             _ => self.stats.failed_inferences += 1,
         }
 
+        // Client cancellations and interrupted streams say nothing about backend health
+        let now = self.clock.now();
+        let breaker_config = &self.breaker_config;
+        let breaker = self
+            .breakers
+            .entry(backend_id.to_string())
+            .or_insert_with(|| CircuitBreaker::new(breaker_config.clone()));
+        match status {
+            InferenceStatus::Success => breaker.record_success(),
+            InferenceStatus::Failed | InferenceStatus::Timeout => breaker.record_failure_at(now),
+            InferenceStatus::Partial | InferenceStatus::Cancelled => {}
+        }
+
+        if let Some(cost) = self.request_cost_usd(&telemetry) {
+            self.stats.total_cost_usd += cost;
+        }
+
         if let Some(latency) = telemetry.total_latency_ms {
             let n = self.stats.total_inference_requests as f64;
             self.stats.avg_inference_latency_ms =
@@ -559,6 +1075,7 @@ impl InferenceGatewayAdapter {
     pub fn clear(&mut self) {
         self.routing_logs.clear();
         self.inference_telemetry.clear();
+        self.health_transitions.clear();
         self.stats = GatewayStats::default();
     }
 
@@ -582,35 +1099,38 @@ impl InferenceGatewayAdapter {
 
         LoadBalancingMetrics {
             gateway_id: self.gateway_id.clone(),
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
             requests_per_backend,
             load_per_backend,
             backend_health,
         }
     }
 
-    /// Check if inference should be sampled (for tail-based sampling).
-    pub fn should_sample_inference(&self, telemetry: &InferenceTelemetry) -> bool {
-        // Always sample failures
-        if telemetry.status != InferenceStatus::Success {
-            return true;
-        }
-
-        // Always sample slow requests (> 5 seconds)
-        if let Some(latency) = telemetry.total_latency_ms {
-            if latency > 5000 {
-                return true;
-            }
-        }
+    /// Get the sampling configuration.
+    pub fn sampling_config(&self) -> &SamplingConfig {
+        &self.sampling
+    }
 
-        // Always sample high token usage (> 10K tokens)
-        if let Some(usage) = &telemetry.token_usage {
-            if usage.total_tokens > 10000 {
-                return true;
-            }
-        }
+    /// Replace the sampling configuration.
+    pub fn set_sampling_config(&mut self, config: SamplingConfig) {
+        self.sampler = TailSampler::from(&config);
+        self.sampling = config;
+    }
 
-        false
+    /// Check if inference should be sampled (for tail-based sampling).
+    ///
+    /// Thresholds come from the adapter's [`SamplingConfig`].
+    pub fn should_sample_inference(&self, telemetry: &InferenceTelemetry) -> bool {
+        self.sampler.should_sample(&SamplingContext {
+            latency_ms: telemetry.total_latency_ms,
+            cost_usd: None,
+            token_count: telemetry
+                .token_usage
+                .as_ref()
+                .map(|u| u64::from(u.total_tokens)),
+            error: telemetry.status != InferenceStatus::Success,
+            key: Some(telemetry.request_id.clone()),
+        })
     }
 
     /// Convert inference telemetry to Observatory span format.
@@ -618,6 +1138,7 @@ impl InferenceGatewayAdapter {
         serde_json::json!({
             "trace_id": telemetry.trace_id,
             "span_id": telemetry.telemetry_id.to_string(),
+            "parent_span_id": telemetry.parent_span_id,
             "name": format!("inference.{}", telemetry.provider),
             "model": telemetry.model,
             "provider": telemetry.provider,
@@ -630,6 +1151,7 @@ impl InferenceGatewayAdapter {
                 "completion_tokens": u.completion_tokens,
                 "total_tokens": u.total_tokens
             })),
+            "cost_usd": self.request_cost_usd(telemetry),
             "status": match telemetry.status {
                 InferenceStatus::Success => "ok",
                 _ => "error"
@@ -642,17 +1164,74 @@ impl InferenceGatewayAdapter {
         })
     }
 
+    /// Cost of an inference request in USD.
+    ///
+    /// Uses the serving backend's `cost_per_1k_tokens` when it is registered
+    /// with a price, and otherwise the Cost adapter's [`DefaultPricing`] for
+    /// the request's provider and model. Returns `None` without token usage
+    /// or any known price.
+    pub fn request_cost_usd(&self, telemetry: &InferenceTelemetry) -> Option<f64> {
+        let usage = telemetry.token_usage.as_ref()?;
+        let backend_price = self
+            .backends
+            .get(telemetry.backend_id.as_str())
+            .and_then(|b| b.cost_per_1k_tokens);
+        if let Some(price) = backend_price {
+            return Some(f64::from(usage.total_tokens) / 1000.0 * price);
+        }
+
+        let provider: ObsProvider = telemetry.provider.parse().ok()?;
+        let pricing = DefaultPricing::for_model(&provider, &telemetry.model)?;
+        Some(
+            f64::from(usage.prompt_tokens) / 1_000_000.0 * pricing.input_price_per_million
+                + f64::from(usage.completion_tokens) / 1_000_000.0
+                    * pricing.output_price_per_million,
+        )
+    }
+
     /// Get routing decision for a model.
-    pub fn select_backend_for_model(&self, model: &str) -> Option<&BackendInfo> {
-        self.backends
+    ///
+    /// Backends whose circuit breaker is open are skipped. A half-open
+    /// backend is chosen for at most one trial request until telemetry for
+    /// that request closes or re-opens its breaker.
+    pub fn select_backend_for_model(&mut self, model: &str) -> Option<&BackendInfo> {
+        let now = self.clock.now();
+        let breakers = &self.breakers;
+        let backend_id = self
+            .backends
             .values()
-            .find(|b| b.health == BackendHealth::Healthy && b.models.contains(&model.to_string()))
+            .find(|b| {
+                b.health == BackendHealth::Healthy
+                    && b.models.iter().any(|m| m == model)
+                    && breakers
+                        .get(b.backend_id.as_str())
+                        .map_or(true, |breaker| breaker.allows_request_at(now))
+            })?
+            .backend_id
+            .as_str()
+            .to_string();
+
+        if let Some(breaker) = self.breakers.get_mut(&backend_id) {
+            breaker.try_acquire_at(now);
+        }
+        self.backends.get(backend_id.as_str())
+    }
+}
+
+impl AdapterStats for InferenceGatewayAdapter {
+    fn adapter_name(&self) -> String {
+        format!("inference_gateway/{}", self.gateway_id.as_str())
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(&self.stats).unwrap_or_default()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::upstream::clock::MockClock;
 
     #[test]
     fn test_inference_gateway_adapter_creation() {
@@ -713,6 +1292,36 @@ mod tests {
         assert!(telemetry.token_usage.is_some());
     }
 
+    #[test]
+    fn test_parse_inference_telemetry_reconciles_total_tokens() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+
+        let json_data = serde_json::json!({
+            "request_id": "req-123",
+            "backend_id": "backend-openai",
+            "model": "gpt-4",
+            "provider": "openai",
+            "token_usage": {
+                "prompt_tokens": 100,
+                "completion_tokens": 500,
+                "total_tokens": 42
+            }
+        });
+        let telemetry = adapter.parse_inference_telemetry(&json_data).unwrap();
+        assert_eq!(telemetry.token_usage.unwrap().total_tokens, 600);
+
+        // A missing total is filled in
+        let json_data = serde_json::json!({
+            "request_id": "req-124",
+            "backend_id": "backend-openai",
+            "model": "gpt-4",
+            "provider": "openai",
+            "token_usage": {"prompt_tokens": 10, "completion_tokens": 5}
+        });
+        let telemetry = adapter.parse_inference_telemetry(&json_data).unwrap();
+        assert_eq!(telemetry.token_usage.unwrap().total_tokens, 15);
+    }
+
     #[test]
     fn test_register_backend() {
         let mut adapter = InferenceGatewayAdapter::new("gateway-1");
@@ -740,6 +1349,7 @@ mod tests {
             telemetry_id: Uuid::new_v4(),
             request_id: "req-1".to_string(),
             trace_id: None,
+            parent_span_id: None,
             gateway_id: GatewayId::new("gateway-1"),
             backend_id: BackendId::new("backend-1"),
             model: "gpt-4".to_string(),
@@ -793,6 +1403,61 @@ mod tests {
         assert!(!adapter.should_sample_inference(&normal));
     }
 
+    #[test]
+    fn test_sampling_config_normal_sample_rate() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        let normal = |request_id: String| InferenceTelemetry {
+            telemetry_id: Uuid::new_v4(),
+            request_id,
+            trace_id: None,
+            parent_span_id: None,
+            gateway_id: GatewayId::new("gateway-1"),
+            backend_id: BackendId::new("backend-1"),
+            model: "gpt-4".to_string(),
+            provider: "openai".to_string(),
+            request_time: Utc::now(),
+            response_time: None,
+            total_latency_ms: Some(100),
+            ttft_ms: None,
+            token_usage: None,
+            status: InferenceStatus::Success,
+            error: None,
+            streaming: false,
+            metadata: HashMap::new(),
+        };
+        let requests: Vec<_> = (0..100).map(|i| normal(format!("req-{}", i))).collect();
+        assert!(!requests.iter().any(|t| adapter.should_sample_inference(t)));
+
+        adapter.set_sampling_config(SamplingConfig {
+            sample_rate_for_normal: 1.0,
+            ..SamplingConfig::default()
+        });
+        assert!(requests.iter().all(|t| adapter.should_sample_inference(t)));
+
+        // Partial rates are reproducible per request ID
+        adapter.set_sampling_config(SamplingConfig {
+            sample_rate_for_normal: 0.5,
+            ..SamplingConfig::default()
+        });
+        let first: Vec<bool> = requests
+            .iter()
+            .map(|t| adapter.should_sample_inference(t))
+            .collect();
+        let second: Vec<bool> = requests
+            .iter()
+            .map(|t| adapter.should_sample_inference(t))
+            .collect();
+        assert_eq!(first, second);
+        assert!(first.iter().any(|&s| s) && first.iter().any(|&s| !s));
+
+        // Lower thresholds pull in otherwise-normal requests
+        adapter.set_sampling_config(SamplingConfig {
+            latency_ms: 50,
+            ..SamplingConfig::default()
+        });
+        assert!(adapter.should_sample_inference(&requests[0]));
+    }
+
     #[test]
     fn test_stats_tracking() {
         let mut adapter = InferenceGatewayAdapter::new("gateway-1");
@@ -827,6 +1492,39 @@ mod tests {
         assert_eq!(stats.successful_inferences, 3);
     }
 
+    #[test]
+    fn test_retry_stats() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        assert_eq!(adapter.stats().retry_rate(), 0.0);
+
+        let logs = [
+            ("backend-1", 0),
+            ("backend-2", 3),
+            ("backend-1", 1),
+            ("backend-2", 2),
+        ];
+        for (i, (backend, retries)) in logs.iter().enumerate() {
+            let json_data = serde_json::json!({
+                "request_id": format!("req-{}", i),
+                "decision": "routed",
+                "selected_backend": backend,
+                "retry_count": retries
+            });
+            adapter.parse_routing_log(&json_data).unwrap();
+        }
+        assert_eq!(adapter.routing_logs()[1].retry_count, 3);
+
+        let stats = adapter.stats();
+        assert_eq!(stats.total_retries, 6);
+        assert_eq!(stats.retries_by_backend["backend-1"], 1);
+        assert_eq!(stats.retries_by_backend["backend-2"], 5);
+        assert_eq!(stats.retry_rate(), 1.5);
+        assert_eq!(stats.most_retried_backend(), Some(("backend-2", 5)));
+
+        adapter.clear();
+        assert_eq!(adapter.stats().most_retried_backend(), None);
+    }
+
     #[test]
     fn test_create_lb_metrics() {
         let mut adapter = InferenceGatewayAdapter::new("gateway-1");
@@ -857,6 +1555,285 @@ mod tests {
         assert_eq!(metrics.load_per_backend.len(), 2);
     }
 
+    #[test]
+    fn test_backend_health_transitions() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        adapter.register_backend(BackendInfo {
+            backend_id: BackendId::new("backend-1"),
+            provider: "OpenAI".to_string(),
+            models: vec!["gpt-4".to_string()],
+            health: BackendHealth::Healthy,
+            load: 0.3,
+            avg_latency_ms: 150.0,
+            cost_per_1k_tokens: Some(0.03),
+        });
+
+        let down = adapter
+            .update_backend_health("backend-1", BackendHealth::Unhealthy)
+            .unwrap();
+        assert_eq!(down.from, BackendHealth::Healthy);
+        assert_eq!(down.to, BackendHealth::Unhealthy);
+        assert!(adapter
+            .update_backend_health("backend-1", BackendHealth::Unhealthy)
+            .is_none());
+        assert!(adapter
+            .update_backend_health("missing", BackendHealth::Degraded)
+            .is_none());
+        adapter.update_backend_health("backend-1", BackendHealth::Healthy);
+
+        let transitions = adapter.health_transitions();
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[1].from, BackendHealth::Unhealthy);
+        assert_eq!(transitions[1].to, BackendHealth::Healthy);
+        assert_eq!(
+            adapter.backends()["backend-1"].health,
+            BackendHealth::Healthy
+        );
+    }
+
+    #[test]
+    fn test_health_transitions_are_capped() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        adapter.register_backend(BackendInfo {
+            backend_id: BackendId::new("backend-1"),
+            provider: "OpenAI".to_string(),
+            models: vec!["gpt-4".to_string()],
+            health: BackendHealth::Healthy,
+            load: 0.3,
+            avg_latency_ms: 150.0,
+            cost_per_1k_tokens: None,
+        });
+
+        for i in 0..=HEALTH_TRANSITION_CAPACITY {
+            let health = if i % 2 == 0 {
+                BackendHealth::Unhealthy
+            } else {
+                BackendHealth::Healthy
+            };
+            adapter.update_backend_health("backend-1", health);
+        }
+
+        // The first Healthy -> Unhealthy transition was evicted
+        let transitions = adapter.health_transitions();
+        assert_eq!(transitions.len(), HEALTH_TRANSITION_CAPACITY);
+        assert_eq!(transitions[0].from, BackendHealth::Unhealthy);
+        assert_eq!(transitions[0].to, BackendHealth::Healthy);
+    }
+
+    #[test]
+    fn test_circuit_breaker_skips_failing_backend() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        adapter.set_circuit_breaker_config(CircuitBreakerConfig {
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(300),
+        });
+        for id in ["backend-a", "backend-b"] {
+            adapter.register_backend(BackendInfo {
+                backend_id: BackendId::new(id),
+                provider: "OpenAI".to_string(),
+                models: vec!["gpt-4".to_string()],
+                health: BackendHealth::Healthy,
+                load: 0.5,
+                avg_latency_ms: 150.0,
+                cost_per_1k_tokens: None,
+            });
+        }
+
+        let failure = serde_json::json!({
+            "request_id": "req-1",
+            "backend_id": "backend-a",
+            "status": "failed"
+        });
+        for _ in 0..2 {
+            adapter.parse_inference_telemetry(&failure).unwrap();
+        }
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Closed);
+
+        adapter.parse_inference_telemetry(&failure).unwrap();
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Open);
+        assert_eq!(adapter.breaker_state("backend-b"), CircuitState::Closed);
+        for _ in 0..10 {
+            let selected = adapter.select_backend_for_model("gpt-4").unwrap();
+            assert_eq!(selected.backend_id.as_str(), "backend-b");
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker_half_opens_after_cooldown() {
+        let mut breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            cooldown: Duration::from_secs(30),
+        });
+        let start = Utc::now();
+        breaker.record_failure_at(start);
+        breaker.record_failure_at(start);
+        assert_eq!(breaker.state_at(start), CircuitState::Open);
+
+        let later = start + chrono::Duration::seconds(31);
+        assert_eq!(breaker.state_at(later), CircuitState::HalfOpen);
+
+        // A failed trial re-opens; a success closes
+        breaker.record_failure_at(later);
+        assert_eq!(breaker.state_at(later), CircuitState::Open);
+        breaker.record_success();
+        assert_eq!(breaker.state_at(later), CircuitState::Closed);
+        assert_eq!(breaker.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn test_half_open_breaker_allows_one_trial() {
+        let start = Utc::now();
+        let clock = Arc::new(MockClock::new(start));
+        let mut adapter = InferenceGatewayAdapter::with_clock("gateway-1", clock.clone());
+        adapter.set_circuit_breaker_config(CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: Duration::from_secs(30),
+        });
+        adapter.register_backend(BackendInfo {
+            backend_id: BackendId::new("backend-a"),
+            provider: "OpenAI".to_string(),
+            models: vec!["gpt-4".to_string()],
+            health: BackendHealth::Healthy,
+            load: 0.5,
+            avg_latency_ms: 150.0,
+            cost_per_1k_tokens: None,
+        });
+        let telemetry = |status: &str| {
+            serde_json::json!({
+                "request_id": "req-1",
+                "backend_id": "backend-a",
+                "status": status
+            })
+        };
+
+        adapter
+            .parse_inference_telemetry(&telemetry("failed"))
+            .unwrap();
+        assert!(adapter.select_backend_for_model("gpt-4").is_none());
+
+        // Half-open: one trial, then nothing until it resolves
+        clock.advance(chrono::Duration::seconds(31));
+        assert!(adapter.select_backend_for_model("gpt-4").is_some());
+        assert!(adapter.select_backend_for_model("gpt-4").is_none());
+
+        // A failed trial re-opens the breaker for a fresh cooldown
+        adapter
+            .parse_inference_telemetry(&telemetry("failed"))
+            .unwrap();
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Open);
+        clock.advance(chrono::Duration::seconds(31));
+        assert!(adapter.select_backend_for_model("gpt-4").is_some());
+
+        // A successful trial closes it
+        adapter
+            .parse_inference_telemetry(&telemetry("success"))
+            .unwrap();
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Closed);
+        assert!(adapter.select_backend_for_model("gpt-4").is_some());
+        assert!(adapter.select_backend_for_model("gpt-4").is_some());
+    }
+
+    #[test]
+    fn test_gateway_routing_feeds_breaker() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        let routing = |state: &str| GatewayRouting {
+            backend: Some("backend-a".to_string()),
+            circuit_breaker_state: Some(state.to_string()),
+            ..GatewayRouting::default()
+        };
+
+        assert_eq!(
+            adapter.observe_gateway_routing(&routing("open")),
+            Some(CircuitState::Open)
+        );
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Open);
+
+        adapter.observe_gateway_routing(&routing("half_open"));
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::HalfOpen);
+
+        adapter.observe_gateway_routing(&routing("closed"));
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Closed);
+
+        assert_eq!(adapter.observe_gateway_routing(&routing("tripped")), None);
+        assert_eq!(
+            adapter.observe_gateway_routing(&GatewayRouting::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_breaker_state_follows_adapter_clock() {
+        let start = Utc::now();
+        let clock = Arc::new(MockClock::new(start));
+        let mut adapter = InferenceGatewayAdapter::with_clock("gateway-1", clock.clone());
+        adapter.set_circuit_breaker_config(CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: Duration::from_secs(30),
+        });
+
+        let telemetry = adapter
+            .parse_inference_telemetry(&serde_json::json!({
+                "request_id": "req-1",
+                "backend_id": "backend-a",
+                "status": "failed"
+            }))
+            .unwrap();
+        assert_eq!(telemetry.request_time, start);
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::Open);
+
+        clock.advance(chrono::Duration::seconds(31));
+        assert_eq!(adapter.breaker_state("backend-a"), CircuitState::HalfOpen);
+    }
+
+    #[test]
+    fn test_merge_lb_metrics() {
+        let snapshot =
+            |gateway: &str, backends: &[(&str, u64, f64, BackendHealth)]| LoadBalancingMetrics {
+                gateway_id: GatewayId::new(gateway),
+                timestamp: Utc::now(),
+                requests_per_backend: backends
+                    .iter()
+                    .map(|(id, requests, _, _)| (id.to_string(), *requests))
+                    .collect(),
+                load_per_backend: backends
+                    .iter()
+                    .map(|(id, _, load, _)| (id.to_string(), *load))
+                    .collect(),
+                backend_health: backends
+                    .iter()
+                    .map(|(id, _, _, health)| (id.to_string(), health.clone()))
+                    .collect(),
+            };
+
+        let a = snapshot(
+            "gateway-1",
+            &[
+                ("shared", 10, 0.2, BackendHealth::Healthy),
+                ("only-a", 3, 0.5, BackendHealth::Healthy),
+            ],
+        );
+        let b = snapshot(
+            "gateway-2",
+            &[
+                ("shared", 5, 0.6, BackendHealth::Degraded),
+                ("only-b", 7, 0.9, BackendHealth::Unhealthy),
+            ],
+        );
+
+        let merged = LoadBalancingMetrics::merge(&[a.clone(), b]);
+        assert_eq!(merged.gateway_id.as_str(), "aggregate");
+        assert_eq!(merged.requests_per_backend["shared"], 15);
+        assert_eq!(merged.requests_per_backend["only-a"], 3);
+        assert!((merged.load_per_backend["shared"] - 0.4).abs() < 1e-9);
+        assert!((merged.load_per_backend["only-b"] - 0.9).abs() < 1e-9);
+        assert_eq!(merged.backend_health["shared"], BackendHealth::Degraded);
+        assert_eq!(merged.backend_health["only-b"], BackendHealth::Unhealthy);
+
+        let same = LoadBalancingMetrics::merge(&[a.clone(), a]);
+        assert_eq!(same.gateway_id.as_str(), "gateway-1");
+        assert_eq!(same.requests_per_backend["shared"], 20);
+    }
+
     #[test]
     fn test_telemetry_to_span_json() {
         let adapter = InferenceGatewayAdapter::new("gateway-1");
@@ -865,6 +1842,7 @@ mod tests {
             telemetry_id: Uuid::new_v4(),
             request_id: "req-123".to_string(),
             trace_id: Some("trace-abc".to_string()),
+            parent_span_id: Some("step-7".to_string()),
             gateway_id: GatewayId::new("gateway-1"),
             backend_id: BackendId::new("backend-openai"),
             model: "gpt-4".to_string(),
@@ -888,7 +1866,43 @@ mod tests {
         let json = adapter.telemetry_to_span_json(&telemetry);
         assert_eq!(json["model"], "gpt-4");
         assert_eq!(json["provider"], "openai");
+        assert_eq!(json["parent_span_id"], "step-7");
         assert_eq!(json["duration_ms"], 1500);
+        // Unregistered backend: priced from the default gpt-4 rates
+        let expected = 100.0 * 30.0 / 1_000_000.0 + 500.0 * 60.0 / 1_000_000.0;
+        assert!((json["cost_usd"].as_f64().unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_priced_backend_reports_request_cost() {
+        let mut adapter = InferenceGatewayAdapter::new("gateway-1");
+        adapter.register_backend(BackendInfo {
+            backend_id: BackendId::new("backend-self-hosted"),
+            provider: "self-hosted".to_string(),
+            models: vec!["llama-3".to_string()],
+            health: BackendHealth::Healthy,
+            load: 0.2,
+            avg_latency_ms: 80.0,
+            cost_per_1k_tokens: Some(0.02),
+        });
+
+        let telemetry = adapter
+            .parse_inference_telemetry(&serde_json::json!({
+                "request_id": "req-1",
+                "backend_id": "backend-self-hosted",
+                "model": "llama-3",
+                "provider": "self-hosted",
+                "status": "success",
+                "token_usage": {"prompt_tokens": 1000, "completion_tokens": 500}
+            }))
+            .unwrap();
+
+        let cost = adapter.telemetry_to_span_json(&telemetry)["cost_usd"]
+            .as_f64()
+            .unwrap();
+        assert!(cost > 0.0);
+        assert!((cost - 0.03).abs() < 1e-12);
+        assert!((adapter.stats().total_cost_usd - 0.03).abs() < 1e-12);
     }
 
     #[test]
@@ -916,4 +1930,74 @@ mod tests {
         assert!(adapter.inference_telemetry().is_empty());
         assert_eq!(adapter.stats().total_routing_decisions, 0);
     }
+
+    #[test]
+    fn test_enums_round_trip_through_strings() {
+        for value in [
+            RoutingDecision::Routed,
+            RoutingDecision::Queued,
+            RoutingDecision::Rejected,
+            RoutingDecision::Fallback,
+            RoutingDecision::NoBackend,
+        ] {
+            let parsed: RoutingDecision = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<RoutingDecision>().is_err());
+
+        for value in [
+            RoutingStrategy::RoundRobin,
+            RoutingStrategy::LeastConnections,
+            RoutingStrategy::WeightedRandom,
+            RoutingStrategy::LatencyBased,
+            RoutingStrategy::CostBased,
+            RoutingStrategy::ModelSpecific,
+        ] {
+            let parsed: RoutingStrategy = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        let custom = RoutingStrategy::Custom("my_custom".to_string());
+        let parsed: RoutingStrategy = custom.to_string().parse().unwrap();
+        assert_eq!(parsed, custom);
+
+        for value in [
+            BackendHealth::Healthy,
+            BackendHealth::Degraded,
+            BackendHealth::Unhealthy,
+            BackendHealth::Unknown,
+        ] {
+            let parsed: BackendHealth = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<BackendHealth>().is_err());
+
+        for value in [
+            InferenceStatus::Success,
+            InferenceStatus::Partial,
+            InferenceStatus::Failed,
+            InferenceStatus::Timeout,
+            InferenceStatus::Cancelled,
+        ] {
+            let parsed: InferenceStatus = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<InferenceStatus>().is_err());
+
+        for value in [
+            ErrorSource::Backend,
+            ErrorSource::Gateway,
+            ErrorSource::Client,
+            ErrorSource::Network,
+            ErrorSource::Unknown,
+        ] {
+            let parsed: ErrorSource = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<ErrorSource>().is_err());
+    }
 }
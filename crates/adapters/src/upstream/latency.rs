@@ -35,12 +35,12 @@
 //! ```
 
 use llm_latency_lens_core::{
-    Clock, RequestId, RequestMetadata, SessionId, Timestamp, TimingEngine, TimingMeasurement,
+    RequestId, RequestMetadata, SessionId, Timestamp, TimingEngine, TimingMeasurement,
     TimingResult, TokenEvent,
 };
 use llm_observatory_core::types::Latency;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
@@ -140,6 +140,217 @@ impl LatencyDistribution {
             sample_count: n,
         }
     }
+
+    /// Combine already-computed distributions into an estimated distribution.
+    ///
+    /// Min, max, mean, standard deviation and sample count are combined
+    /// exactly. Percentiles cannot be recovered from summaries, so they are
+    /// estimated as the sample-weighted average of the input percentiles.
+    /// For exact (or histogram-accurate) percentiles, merge the underlying
+    /// samples instead, e.g. with [`LatencyAdapter::merge`].
+    pub fn merge(distributions: &[LatencyDistribution]) -> Self {
+        let parts: Vec<&LatencyDistribution> = distributions
+            .iter()
+            .filter(|d| d.sample_count > 0)
+            .collect();
+        let total: usize = parts.iter().map(|d| d.sample_count).sum();
+        if total == 0 {
+            return Self::default();
+        }
+
+        let weighted = |field: fn(&LatencyDistribution) -> Duration| {
+            let sum: f64 = parts
+                .iter()
+                .map(|d| field(d).as_nanos() as f64 * d.sample_count as f64)
+                .sum();
+            sum / total as f64
+        };
+
+        let mean = weighted(|d| d.mean);
+        // Pooled variance: E[x^2] - E[x]^2, with E[x^2] = sd^2 + mean^2 per part
+        let second_moment: f64 = parts
+            .iter()
+            .map(|d| {
+                let m = d.mean.as_nanos() as f64;
+                let sd = d.std_dev.as_nanos() as f64;
+                (sd * sd + m * m) * d.sample_count as f64
+            })
+            .sum::<f64>()
+            / total as f64;
+        let variance = (second_moment - mean * mean).max(0.0);
+
+        Self {
+            min: parts.iter().map(|d| d.min).min().unwrap_or_default(),
+            max: parts.iter().map(|d| d.max).max().unwrap_or_default(),
+            mean: Duration::from_nanos(mean as u64),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            p50: Duration::from_nanos(weighted(|d| d.p50) as u64),
+            p90: Duration::from_nanos(weighted(|d| d.p90) as u64),
+            p95: Duration::from_nanos(weighted(|d| d.p95) as u64),
+            p99: Duration::from_nanos(weighted(|d| d.p99) as u64),
+            sample_count: total,
+        }
+    }
+}
+
+/// Bucketed latency histogram with HDR-style log-linear buckets.
+///
+/// Values are tracked in microseconds, from 1µs up to [`Self::MAX_VALUE`]
+/// (one hour); larger values are clamped. Values below 256µs are counted
+/// exactly, and larger values fall into buckets with 128 sub-buckets per
+/// power of two, so percentile estimates are within ~0.4% of the exact
+/// value. Min, max, mean and standard deviation are tracked exactly.
+///
+/// Recording is O(1) and percentile queries are O(buckets).
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    /// Count per bucket, grown on demand
+    counts: Vec<u64>,
+    /// Total number of recorded values
+    total: u64,
+    /// Smallest recorded value
+    min: Option<Duration>,
+    /// Largest recorded value
+    max: Duration,
+    /// Sum of recorded values in nanoseconds
+    sum_nanos: f64,
+    /// Sum of squared recorded values in nanoseconds
+    sum_sq_nanos: f64,
+}
+
+impl LatencyHistogram {
+    /// Largest trackable value (one hour).
+    pub const MAX_VALUE: Duration = Duration::from_secs(3600);
+
+    /// Number of exactly-counted values, in microseconds.
+    const LINEAR_BUCKETS: u64 = 256;
+    /// Sub-buckets per power of two above the linear range.
+    const SUB_BUCKETS: u64 = 128;
+
+    /// Create an empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a value.
+    pub fn record(&mut self, duration: Duration) {
+        let duration = duration.min(Self::MAX_VALUE);
+        let index = Self::bucket_index(duration.as_micros() as u64);
+        if index >= self.counts.len() {
+            self.counts.resize(index + 1, 0);
+        }
+        self.counts[index] += 1;
+
+        let nanos = duration.as_nanos() as f64;
+        self.total += 1;
+        self.sum_nanos += nanos;
+        self.sum_sq_nanos += nanos * nanos;
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = self.max.max(duration);
+    }
+
+    /// Get the number of recorded values.
+    pub fn len(&self) -> usize {
+        self.total as usize
+    }
+
+    /// Check whether no values have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// Get the value at a percentile (0.0 - 100.0).
+    pub fn percentile(&self, percentile: f64) -> Duration {
+        if self.total == 0 {
+            return Duration::ZERO;
+        }
+
+        let rank = ((self.total as f64 * percentile / 100.0) as u64).min(self.total - 1);
+        let mut seen = 0;
+        for (index, count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen > rank {
+                let value = Duration::from_micros(Self::bucket_midpoint(index));
+                return value.clamp(self.min.unwrap_or_default(), self.max);
+            }
+        }
+        self.max
+    }
+
+    /// Compute a distribution from the recorded values.
+    pub fn distribution(&self) -> LatencyDistribution {
+        if self.total == 0 {
+            return LatencyDistribution::default();
+        }
+
+        let n = self.total as f64;
+        let mean = self.sum_nanos / n;
+        let variance = (self.sum_sq_nanos / n - mean * mean).max(0.0);
+
+        LatencyDistribution {
+            min: self.min.unwrap_or_default(),
+            max: self.max,
+            mean: Duration::from_nanos(mean as u64),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            p50: self.percentile(50.0),
+            p90: self.percentile(90.0),
+            p95: self.percentile(95.0),
+            p99: self.percentile(99.0),
+            sample_count: self.len(),
+        }
+    }
+
+    /// Add all values recorded in another histogram to this one.
+    ///
+    /// Bucket counts add exactly, so the result is identical to having
+    /// recorded every value into a single histogram.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        if other.counts.len() > self.counts.len() {
+            self.counts.resize(other.counts.len(), 0);
+        }
+        for (count, other_count) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other_count;
+        }
+
+        self.total += other.total;
+        self.sum_nanos += other.sum_nanos;
+        self.sum_sq_nanos += other.sum_sq_nanos;
+        self.min = match (self.min, other.min) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.max = self.max.max(other.max);
+    }
+
+    /// Clear all recorded values.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Bucket index for a value in microseconds.
+    fn bucket_index(micros: u64) -> usize {
+        if micros < Self::LINEAR_BUCKETS {
+            return micros as usize;
+        }
+        // Position of the highest set bit; at least 8 here
+        let magnitude = 63 - micros.leading_zeros() as u64;
+        let shift = magnitude - 7;
+        let sub_bucket = (micros >> shift) - Self::SUB_BUCKETS;
+        (Self::LINEAR_BUCKETS + (magnitude - 8) * Self::SUB_BUCKETS + sub_bucket) as usize
+    }
+
+    /// Representative value (bucket midpoint) in microseconds for a bucket.
+    fn bucket_midpoint(index: usize) -> u64 {
+        let index = index as u64;
+        if index < Self::LINEAR_BUCKETS {
+            return index;
+        }
+        let offset = index - Self::LINEAR_BUCKETS;
+        let magnitude = 8 + offset / Self::SUB_BUCKETS;
+        let shift = magnitude - 7;
+        let lower = (Self::SUB_BUCKETS + offset % Self::SUB_BUCKETS) << shift;
+        lower + (1 << shift) / 2
+    }
 }
 
 /// Throughput statistics.
@@ -178,6 +389,8 @@ pub struct ObservatoryMeasurement {
     checkpoints: Vec<(String, std::time::Instant)>,
     /// Time to first token (if recorded)
     ttft: Option<Duration>,
+    /// Arrival time of each token recorded via `record_token`
+    token_times: Vec<std::time::Instant>,
 }
 
 impl ObservatoryMeasurement {
@@ -189,12 +402,14 @@ impl ObservatoryMeasurement {
             start_time: std::time::Instant::now(),
             checkpoints: Vec::new(),
             ttft: None,
+            token_times: Vec::new(),
         }
     }
 
     /// Add a checkpoint.
     pub fn checkpoint(&mut self, label: impl Into<String>) {
-        self.checkpoints.push((label.into(), std::time::Instant::now()));
+        self.checkpoints
+            .push((label.into(), std::time::Instant::now()));
     }
 
     /// Record first token arrival.
@@ -205,6 +420,20 @@ impl ObservatoryMeasurement {
         }
     }
 
+    /// Record the arrival of a streamed token.
+    ///
+    /// The first token also records TTFT. Gaps between consecutive tokens are
+    /// reported as inter-token latencies when the measurement finishes.
+    pub fn record_token(&mut self) {
+        self.record_first_token();
+        self.token_times.push(std::time::Instant::now());
+    }
+
+    /// Get the number of tokens recorded.
+    pub fn token_count(&self) -> usize {
+        self.token_times.len()
+    }
+
     /// Get the session ID.
     pub fn session_id(&self) -> &SessionId {
         &self.session_id
@@ -240,10 +469,34 @@ impl ObservatoryMeasurement {
             total_duration,
             ttft: self.ttft,
             checkpoints: checkpoint_durations,
+            inter_token_latencies: inter_token_gaps(&self.token_times),
         }
     }
 }
 
+/// Gaps between consecutive token arrivals.
+fn inter_token_gaps(token_times: &[std::time::Instant]) -> Vec<Duration> {
+    token_times
+        .windows(2)
+        .map(|pair| pair[1].saturating_duration_since(pair[0]))
+        .collect()
+}
+
+/// Checkpoint marking the end of queueing, when the request is picked up.
+pub const DEQUEUED_CHECKPOINT: &str = "dequeued";
+
+/// Checkpoint marking when the request was sent to the provider.
+pub const REQUEST_SENT_CHECKPOINT: &str = "request_sent";
+
+/// Checkpoint marking when the provider's response started arriving.
+pub const RESPONSE_RECEIVED_CHECKPOINT: &str = "response_received";
+
+/// Most recent token samples kept for [`LatencyAdapter::throughput_stats`].
+pub const TOKEN_SAMPLE_CAPACITY: usize = 10_000;
+
+/// How far back [`LatencyAdapter::windowed`] can look by default.
+pub const DEFAULT_WINDOW_RETENTION: Duration = Duration::from_secs(3600);
+
 /// Result of an Observatory timing measurement.
 #[derive(Debug, Clone)]
 pub struct ObservatoryTimingResult {
@@ -257,20 +510,49 @@ pub struct ObservatoryTimingResult {
     pub ttft: Option<Duration>,
     /// Checkpoint durations
     pub checkpoints: Vec<(String, Duration)>,
+    /// Gaps between consecutive tokens (one fewer than the number of tokens)
+    pub inter_token_latencies: Vec<Duration>,
 }
 
 impl ObservatoryTimingResult {
     /// Convert to Observatory Latency type.
+    ///
+    /// Phases are filled from the [`DEQUEUED_CHECKPOINT`],
+    /// [`REQUEST_SENT_CHECKPOINT`] and [`RESPONSE_RECEIVED_CHECKPOINT`]
+    /// checkpoints when all three were recorded. Queue time runs up to
+    /// dequeueing and inference from sending the request until the response
+    /// arrives; network time covers the rest after dequeueing (sending the
+    /// request and streaming the response back).
     pub fn to_latency(&self, start_time: chrono::DateTime<chrono::Utc>) -> Latency {
-        let end_time = start_time + chrono::Duration::from_std(self.total_duration).unwrap_or_default();
+        let end_time =
+            start_time + chrono::Duration::from_std(self.total_duration).unwrap_or_default();
 
         let mut latency = Latency::new(start_time, end_time);
         if let Some(ttft) = self.ttft {
             latency = latency.with_ttft(ttft.as_millis() as u64);
         }
+        if let Some((queue, network, inference)) = self.phases() {
+            latency = latency.with_phases(
+                queue.as_millis() as u64,
+                network.as_millis() as u64,
+                inference.as_millis() as u64,
+            );
+        }
         latency
     }
 
+    /// Queue, network and inference durations derived from checkpoints.
+    fn phases(&self) -> Option<(Duration, Duration, Duration)> {
+        let dequeued = self.get_checkpoint(DEQUEUED_CHECKPOINT)?;
+        let sent = self.get_checkpoint(REQUEST_SENT_CHECKPOINT)?;
+        let received = self.get_checkpoint(RESPONSE_RECEIVED_CHECKPOINT)?;
+
+        let inference = received.saturating_sub(sent);
+        let network =
+            sent.saturating_sub(dequeued) + self.total_duration.saturating_sub(received.max(sent));
+        Some((dequeued, network, inference))
+    }
+
     /// Get a specific checkpoint duration.
     pub fn get_checkpoint(&self, label: &str) -> Option<Duration> {
         self.checkpoints
@@ -303,11 +585,17 @@ pub struct LatencyAdapter {
     /// Current session ID
     session_id: SessionId,
     /// Collected latency samples
-    samples: Vec<Duration>,
+    samples: LatencyHistogram,
     /// TTFT samples
-    ttft_samples: Vec<Duration>,
+    ttft_samples: LatencyHistogram,
     /// Inter-token latency samples
-    inter_token_samples: Vec<Duration>,
+    inter_token_samples: LatencyHistogram,
+    /// Most recent token counts paired with request durations, for throughput
+    token_samples: VecDeque<(u32, Duration)>,
+    /// Latency samples with the time they were observed, for windowing
+    timed_samples: VecDeque<(chrono::DateTime<chrono::Utc>, Duration)>,
+    /// Age, relative to the newest timed sample, beyond which samples are evicted
+    window_retention: Duration,
 }
 
 impl Default for LatencyAdapter {
@@ -319,24 +607,31 @@ impl Default for LatencyAdapter {
 impl LatencyAdapter {
     /// Create a new LatencyAdapter.
     pub fn new() -> Self {
-        Self {
-            session_id: SessionId::new(),
-            samples: Vec::new(),
-            ttft_samples: Vec::new(),
-            inter_token_samples: Vec::new(),
-        }
+        Self::with_session(SessionId::new())
     }
 
     /// Create a new LatencyAdapter with a specific session ID.
     pub fn with_session(session_id: SessionId) -> Self {
         Self {
             session_id,
-            samples: Vec::new(),
-            ttft_samples: Vec::new(),
-            inter_token_samples: Vec::new(),
+            samples: LatencyHistogram::new(),
+            ttft_samples: LatencyHistogram::new(),
+            inter_token_samples: LatencyHistogram::new(),
+            token_samples: VecDeque::new(),
+            timed_samples: VecDeque::new(),
+            window_retention: DEFAULT_WINDOW_RETENTION,
         }
     }
 
+    /// Set how long timestamped samples are kept for [`Self::windowed`].
+    ///
+    /// This should cover the largest window queried; older samples are
+    /// evicted as newer ones are recorded.
+    pub fn with_window_retention(mut self, retention: Duration) -> Self {
+        self.window_retention = retention;
+        self
+    }
+
     /// Get the current session ID.
     pub fn session_id(&self) -> &SessionId {
         &self.session_id
@@ -349,17 +644,66 @@ impl LatencyAdapter {
 
     /// Record a latency sample.
     pub fn record_sample(&mut self, duration: Duration) {
-        self.samples.push(duration);
+        self.samples.record(duration);
+    }
+
+    /// Record a latency sample observed at a specific time.
+    ///
+    /// Timestamped samples feed [`Self::windowed`]. Samples more than the
+    /// window retention older than `at` are evicted; samples are expected to
+    /// be recorded in roughly chronological order.
+    pub fn record_sample_at(&mut self, duration: Duration, at: chrono::DateTime<chrono::Utc>) {
+        self.record_sample(duration);
+        self.timed_samples.push_back((at, duration));
+        self.evict_timed_samples(at);
+    }
+
+    /// Drop timed samples older than the retention, measured back from `newest`.
+    fn evict_timed_samples(&mut self, newest: chrono::DateTime<chrono::Utc>) {
+        let Ok(retention) = chrono::Duration::from_std(self.window_retention) else {
+            return;
+        };
+        let Some(cutoff) = newest.checked_sub_signed(retention) else {
+            return;
+        };
+        while self
+            .timed_samples
+            .front()
+            .is_some_and(|(at, _)| *at < cutoff)
+        {
+            self.timed_samples.pop_front();
+        }
+    }
+
+    /// Record a latency sample along with the number of tokens produced.
+    ///
+    /// The pair feeds [`Self::throughput_stats`]; only the most recent
+    /// [`TOKEN_SAMPLE_CAPACITY`] pairs are kept.
+    pub fn record_sample_with_tokens(&mut self, duration: Duration, tokens: u32) {
+        self.record_sample(duration);
+        if self.token_samples.len() == TOKEN_SAMPLE_CAPACITY {
+            self.token_samples.pop_front();
+        }
+        self.token_samples.push_back((tokens, duration));
     }
 
     /// Record a TTFT sample.
     pub fn record_ttft(&mut self, duration: Duration) {
-        self.ttft_samples.push(duration);
+        self.ttft_samples.record(duration);
     }
 
     /// Record an inter-token latency sample.
     pub fn record_inter_token(&mut self, duration: Duration) {
-        self.inter_token_samples.push(duration);
+        self.inter_token_samples.record(duration);
+    }
+
+    /// Record inter-token latencies from a sequence of token arrival times.
+    ///
+    /// Records one sample per gap between consecutive tokens.
+    pub fn record_token_events(&mut self, token_times: &[std::time::Instant]) {
+        for gap in inter_token_gaps(token_times) {
+            self.record_inter_token(gap);
+        }
     }
 
     /// Record samples from a timing result.
@@ -368,21 +712,111 @@ impl LatencyAdapter {
         if let Some(ttft) = result.ttft {
             self.record_ttft(ttft);
         }
+        for gap in &result.inter_token_latencies {
+            self.record_inter_token(*gap);
+        }
+    }
+
+    /// Get the total latency distribution from the sample histogram.
+    ///
+    /// Percentiles are estimated from [`LatencyHistogram`] buckets.
+    pub fn distribution(&self) -> LatencyDistribution {
+        self.samples.distribution()
     }
 
     /// Get total latency distribution.
     pub fn latency_distribution(&self) -> LatencyDistribution {
-        LatencyDistribution::from_samples(&self.samples)
+        self.distribution()
     }
 
     /// Get TTFT distribution.
     pub fn ttft_distribution(&self) -> LatencyDistribution {
-        LatencyDistribution::from_samples(&self.ttft_samples)
+        self.ttft_samples.distribution()
     }
 
     /// Get inter-token latency distribution.
     pub fn inter_token_distribution(&self) -> LatencyDistribution {
-        LatencyDistribution::from_samples(&self.inter_token_samples)
+        self.inter_token_samples.distribution()
+    }
+
+    /// Get latency distributions bucketed into fixed time windows.
+    ///
+    /// Only samples recorded with [`Self::record_sample_at`] and still within
+    /// the window retention (see [`Self::with_window_retention`]) are considered.
+    /// Windows are aligned to the Unix epoch and returned in chronological
+    /// order, keyed by window start; empty windows are omitted.
+    pub fn windowed(
+        &self,
+        window: Duration,
+    ) -> Vec<(chrono::DateTime<chrono::Utc>, LatencyDistribution)> {
+        let window_ms = (window.as_millis() as i64).max(1);
+
+        let mut buckets: std::collections::BTreeMap<i64, LatencyHistogram> =
+            std::collections::BTreeMap::new();
+        for (at, duration) in &self.timed_samples {
+            let start = at.timestamp_millis().div_euclid(window_ms) * window_ms;
+            buckets.entry(start).or_default().record(*duration);
+        }
+
+        buckets
+            .into_iter()
+            .filter_map(|(start, histogram)| {
+                chrono::DateTime::from_timestamp_millis(start)
+                    .map(|start| (start, histogram.distribution()))
+            })
+            .collect()
+    }
+
+    /// Merge samples collected by another adapter into this one.
+    ///
+    /// Useful when collection is sharded across threads. Histogram buckets
+    /// and token samples are combined, so the merged distribution is as
+    /// accurate as if every sample had been recorded here.
+    pub fn merge(&mut self, other: &LatencyAdapter) {
+        self.samples.merge(&other.samples);
+        self.ttft_samples.merge(&other.ttft_samples);
+        self.inter_token_samples.merge(&other.inter_token_samples);
+        self.token_samples
+            .extend(other.token_samples.iter().copied());
+        let excess = self
+            .token_samples
+            .len()
+            .saturating_sub(TOKEN_SAMPLE_CAPACITY);
+        self.token_samples.drain(..excess);
+        self.timed_samples
+            .extend(other.timed_samples.iter().copied());
+        self.timed_samples
+            .make_contiguous()
+            .sort_by_key(|(at, _)| *at);
+        if let Some((newest, _)) = self.timed_samples.back().copied() {
+            self.evict_timed_samples(newest);
+        }
+    }
+
+    /// Get tokens-per-second statistics over samples recorded with
+    /// [`Self::record_sample_with_tokens`].
+    ///
+    /// Zero-duration samples are skipped.
+    pub fn throughput_stats(&self) -> ThroughputStats {
+        let mut rates: Vec<f64> = self
+            .token_samples
+            .iter()
+            .filter(|(_, duration)| !duration.is_zero())
+            .map(|(tokens, duration)| Self::calculate_throughput(*tokens, *duration))
+            .collect();
+
+        if rates.is_empty() {
+            return ThroughputStats::default();
+        }
+        rates.sort_by(|a, b| a.total_cmp(b));
+
+        let n = rates.len();
+        ThroughputStats {
+            mean_tokens_per_second: rates.iter().sum::<f64>() / n as f64,
+            min_tokens_per_second: rates[0],
+            max_tokens_per_second: rates[n - 1],
+            p95_tokens_per_second: rates[(n * 95 / 100).min(n - 1)],
+        }
     }
 
     /// Get the number of samples collected.
@@ -395,6 +829,8 @@ impl LatencyAdapter {
         self.samples.clear();
         self.ttft_samples.clear();
         self.inter_token_samples.clear();
+        self.token_samples.clear();
+        self.timed_samples.clear();
     }
 
     /// Calculate throughput from token count and duration.
@@ -474,6 +910,61 @@ mod tests {
         assert!(result.ttft.is_some());
     }
 
+    #[test]
+    fn test_checkpoints_map_to_latency_phases() {
+        let at = |label: &str, ms: u64| (label.to_string(), Duration::from_millis(ms));
+        let result = ObservatoryTimingResult {
+            session_id: SessionId::new(),
+            request_id: RequestId::new(),
+            total_duration: Duration::from_millis(1000),
+            ttft: None,
+            checkpoints: vec![
+                at(DEQUEUED_CHECKPOINT, 100),
+                at(REQUEST_SENT_CHECKPOINT, 150),
+                at(RESPONSE_RECEIVED_CHECKPOINT, 850),
+            ],
+            inter_token_latencies: Vec::new(),
+        };
+
+        let latency = result.to_latency(chrono::Utc::now());
+        assert_eq!(latency.queue_ms, Some(100));
+        assert_eq!(latency.network_ms, Some(200));
+        assert_eq!(latency.inference_ms, Some(700));
+        assert!(latency.validate());
+
+        let unphased = ObservatoryTimingResult {
+            checkpoints: Vec::new(),
+            ..result
+        };
+        assert_eq!(unphased.to_latency(chrono::Utc::now()).queue_ms, None);
+    }
+
+    #[test]
+    fn test_token_events() {
+        let mut adapter = LatencyAdapter::new();
+        let mut measurement = adapter.start_measurement();
+
+        for _ in 0..5 {
+            std::thread::sleep(Duration::from_millis(2));
+            measurement.record_token();
+        }
+        assert_eq!(measurement.token_count(), 5);
+
+        let result = measurement.finish();
+        assert!(result.ttft.is_some());
+        assert_eq!(result.inter_token_latencies.len(), 4);
+
+        adapter.record_from_result(&result);
+        assert_eq!(adapter.inter_token_distribution().sample_count, 4);
+
+        let start = std::time::Instant::now();
+        let times: Vec<_> = (0..3)
+            .map(|i| start + Duration::from_millis(10 * i))
+            .collect();
+        adapter.record_token_events(&times);
+        assert_eq!(adapter.inter_token_distribution().sample_count, 6);
+    }
+
     #[test]
     fn test_latency_distribution() {
         let samples = vec![
@@ -490,6 +981,128 @@ mod tests {
         assert_eq!(dist.sample_count, 5);
     }
 
+    #[test]
+    fn test_histogram_matches_exact_percentiles() {
+        let samples: Vec<Duration> = (1..=10_000).map(Duration::from_millis).collect();
+
+        let mut histogram = LatencyHistogram::new();
+        for sample in &samples {
+            histogram.record(*sample);
+        }
+
+        let exact = LatencyDistribution::from_samples(&samples);
+        let approx = histogram.distribution();
+
+        assert_eq!(approx.sample_count, exact.sample_count);
+        assert_eq!(approx.min, exact.min);
+        assert_eq!(approx.max, exact.max);
+        for (a, e) in [
+            (approx.p50, exact.p50),
+            (approx.p90, exact.p90),
+            (approx.p95, exact.p95),
+            (approx.p99, exact.p99),
+            (approx.mean, exact.mean),
+        ] {
+            let error = (a.as_secs_f64() - e.as_secs_f64()).abs() / e.as_secs_f64();
+            assert!(error < 0.01, "{:?} vs {:?}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_histogram_small_values_exact() {
+        let mut histogram = LatencyHistogram::new();
+        histogram.record(Duration::from_micros(100));
+        histogram.record(Duration::from_micros(200));
+
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram.percentile(0.0), Duration::from_micros(100));
+        assert_eq!(histogram.percentile(100.0), Duration::from_micros(200));
+        assert_eq!(LatencyHistogram::new().distribution().sample_count, 0);
+    }
+
+    #[test]
+    fn test_merge_adapters() {
+        let mut a = LatencyAdapter::new();
+        let mut b = LatencyAdapter::new();
+        a.record_sample(Duration::from_millis(100));
+        a.record_sample(Duration::from_millis(200));
+        b.record_sample(Duration::from_millis(300));
+        b.record_ttft(Duration::from_millis(50));
+
+        a.merge(&b);
+        assert_eq!(a.sample_count(), 3);
+        assert_eq!(a.distribution().min, Duration::from_millis(100));
+        assert_eq!(a.distribution().max, Duration::from_millis(300));
+        assert_eq!(a.ttft_distribution().sample_count, 1);
+    }
+
+    #[test]
+    fn test_merge_distributions() {
+        let first = LatencyDistribution::from_samples(&[
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+        ]);
+        let second = LatencyDistribution::from_samples(&[Duration::from_millis(300)]);
+
+        let merged = LatencyDistribution::merge(&[first, second, LatencyDistribution::default()]);
+        let exact = LatencyDistribution::from_samples(&[
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(300),
+        ]);
+
+        assert_eq!(merged.sample_count, 3);
+        assert_eq!(merged.min, exact.min);
+        assert_eq!(merged.max, exact.max);
+        assert_eq!(merged.mean, exact.mean);
+        let std_dev_error = merged.std_dev.as_nanos() as f64 - exact.std_dev.as_nanos() as f64;
+        assert!(std_dev_error.abs() < 1_000.0);
+        assert_eq!(LatencyDistribution::merge(&[]).sample_count, 0);
+    }
+
+    #[test]
+    fn test_windowed_distributions() {
+        let mut adapter = LatencyAdapter::new();
+        let base = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let at = |secs: i64| base + chrono::Duration::seconds(secs);
+
+        adapter.record_sample_at(Duration::from_millis(100), at(0));
+        adapter.record_sample_at(Duration::from_millis(200), at(30));
+        // Nothing recorded in the [60, 120) window
+        adapter.record_sample_at(Duration::from_millis(900), at(150));
+
+        let windows = adapter.windowed(Duration::from_secs(60));
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].0, base - chrono::Duration::seconds(20));
+        assert_eq!(windows[0].1.sample_count, 2);
+        assert_eq!(windows[1].0, at(100));
+        assert_eq!(windows[1].1.p99, Duration::from_millis(900));
+        assert_eq!(adapter.sample_count(), 3);
+    }
+
+    #[test]
+    fn test_windowed_samples_are_evicted_past_retention() {
+        let mut adapter = LatencyAdapter::new().with_window_retention(Duration::from_secs(60));
+        let base = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let at = |secs: i64| base + chrono::Duration::seconds(secs);
+
+        adapter.record_sample_at(Duration::from_millis(100), at(0));
+        adapter.record_sample_at(Duration::from_millis(200), at(30));
+        adapter.record_sample_at(Duration::from_millis(300), at(90));
+        assert_eq!(adapter.timed_samples.len(), 2);
+
+        let mut other = LatencyAdapter::new();
+        other.record_sample_at(Duration::from_millis(400), at(200));
+        adapter.merge(&other);
+
+        // Only the merged sample is within 60s of the newest one
+        let windows = adapter.windowed(Duration::from_secs(3600));
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].1.sample_count, 1);
+        // The overall distribution is unaffected by eviction
+        assert_eq!(adapter.sample_count(), 4);
+    }
+
     #[test]
     fn test_record_samples() {
         let mut adapter = LatencyAdapter::new();
@@ -500,6 +1113,45 @@ mod tests {
 
         assert_eq!(adapter.sample_count(), 2);
         assert_eq!(adapter.ttft_samples.len(), 1);
+        assert_eq!(adapter.distribution().max, Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_throughput_stats() {
+        let mut adapter = LatencyAdapter::new();
+        assert_eq!(adapter.throughput_stats().mean_tokens_per_second, 0.0);
+
+        adapter.record_sample_with_tokens(Duration::from_secs(1), 100);
+        adapter.record_sample_with_tokens(Duration::from_secs(1), 300);
+        adapter.record_sample_with_tokens(Duration::from_millis(500), 100);
+        // Skipped rather than producing infinity
+        adapter.record_sample_with_tokens(Duration::ZERO, 50);
+
+        let stats = adapter.throughput_stats();
+        assert_eq!(stats.min_tokens_per_second, 100.0);
+        assert_eq!(stats.max_tokens_per_second, 300.0);
+        assert_eq!(stats.mean_tokens_per_second, 200.0);
+        assert_eq!(stats.p95_tokens_per_second, 300.0);
+        assert_eq!(adapter.sample_count(), 4);
+    }
+
+    #[test]
+    fn test_token_samples_are_capped() {
+        let mut adapter = LatencyAdapter::new();
+        adapter.record_sample_with_tokens(Duration::from_secs(1), 1);
+        for _ in 0..TOKEN_SAMPLE_CAPACITY {
+            adapter.record_sample_with_tokens(Duration::from_secs(1), 100);
+        }
+
+        // The oldest sample was evicted
+        assert_eq!(adapter.token_samples.len(), TOKEN_SAMPLE_CAPACITY);
+        assert_eq!(adapter.throughput_stats().min_tokens_per_second, 100.0);
+
+        let mut other = LatencyAdapter::new();
+        other.record_sample_with_tokens(Duration::from_secs(1), 500);
+        adapter.merge(&other);
+        assert_eq!(adapter.token_samples.len(), TOKEN_SAMPLE_CAPACITY);
+        assert_eq!(adapter.throughput_stats().max_tokens_per_second, 500.0);
     }
 
     #[test]
@@ -0,0 +1,272 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Markdown reports for upstream adapter output.
+//!
+//! [`generate_anomaly_report`] renders anomalies detected by the
+//! [`SentinelAdapter`](super::sentinel::SentinelAdapter) as a human-readable
+//! digest, suitable for attaching to CI artifacts next to the benchmark
+//! summary from `llm_observatory_benchmarks::markdown`.
+//!
+//! [`cost_report_to_markdown`] renders a [`CostReport`] from the
+//! [`CostAdapter`](super::cost::CostAdapter) for nightly cost summaries.
+
+use super::cost::CostReport;
+use super::sentinel::DetectedAnomaly;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// Sort key for a severity label: most severe first, unknown labels last.
+fn severity_rank(severity: &str) -> usize {
+    match severity {
+        "Critical" => 0,
+        "High" => 1,
+        "Medium" => 2,
+        "Low" => 3,
+        _ => 4,
+    }
+}
+
+/// Generate a markdown digest of detected anomalies.
+///
+/// Anomalies are grouped by `anomaly_type`, then by `severity` (most severe
+/// first), with one table row per anomaly. The report ends with a count per
+/// severity. An empty slice produces a "No anomalies detected" line instead
+/// of tables.
+pub fn generate_anomaly_report(anomalies: &[DetectedAnomaly]) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "# Anomaly Report").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "Generated: {}", chrono::Utc::now().to_rfc3339()).unwrap();
+    writeln!(output).unwrap();
+
+    if anomalies.is_empty() {
+        writeln!(output, "No anomalies detected.").unwrap();
+        return output;
+    }
+
+    let mut groups: BTreeMap<(&str, usize, &str), Vec<&DetectedAnomaly>> = BTreeMap::new();
+    let mut counts: BTreeMap<(usize, &str), usize> = BTreeMap::new();
+    for anomaly in anomalies {
+        let rank = severity_rank(&anomaly.severity);
+        groups
+            .entry((
+                anomaly.anomaly_type.as_str(),
+                rank,
+                anomaly.severity.as_str(),
+            ))
+            .or_default()
+            .push(anomaly);
+        *counts.entry((rank, anomaly.severity.as_str())).or_default() += 1;
+    }
+
+    let mut current_type = None;
+    for ((anomaly_type, _, severity), group) in &groups {
+        if current_type != Some(*anomaly_type) {
+            writeln!(output, "## {}", anomaly_type).unwrap();
+            writeln!(output).unwrap();
+            current_type = Some(*anomaly_type);
+        }
+        writeln!(output, "### {} ({})", severity, group.len()).unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Metric | Value | Threshold | Confidence | Timestamp |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|--------|-------|-----------|------------|-----------|"
+        )
+        .unwrap();
+        for anomaly in group {
+            writeln!(
+                output,
+                "| {} | {:.2} | {:.2} | {:.0}% | {} |",
+                anomaly.metric,
+                anomaly.value,
+                anomaly.threshold,
+                anomaly.confidence * 100.0,
+                anomaly.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+            )
+            .unwrap();
+        }
+        writeln!(output).unwrap();
+    }
+
+    writeln!(output, "## Summary").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "| Severity | Count |").unwrap();
+    writeln!(output, "|----------|-------|").unwrap();
+    for ((_, severity), count) in &counts {
+        writeln!(output, "| {} | {} |", severity, count).unwrap();
+    }
+    writeln!(output).unwrap();
+    writeln!(output, "---").unwrap();
+    writeln!(output, "Total anomalies: {}", anomalies.len()).unwrap();
+
+    output
+}
+
+/// Generate a markdown summary of a cost report.
+///
+/// Starts with the reporting period and a totals table, followed by
+/// breakdown tables by provider, model and project, each sorted by
+/// descending cost. Empty breakdowns (e.g. no spans carried a project) are
+/// noted rather than rendered as empty tables.
+pub fn cost_report_to_markdown(report: &CostReport) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "# Cost Report").unwrap();
+    writeln!(output).unwrap();
+    writeln!(
+        output,
+        "Period: {} to {}",
+        report.period_start.format("%Y-%m-%d %H:%M:%S UTC"),
+        report.period_end.format("%Y-%m-%d %H:%M:%S UTC")
+    )
+    .unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "## Totals").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "| Metric | Value |").unwrap();
+    writeln!(output, "|--------|-------|").unwrap();
+    writeln!(
+        output,
+        "| Total cost | {:.4} {} |",
+        report.total_cost, report.currency
+    )
+    .unwrap();
+    writeln!(output, "| Total requests | {} |", report.total_requests).unwrap();
+    writeln!(
+        output,
+        "| Average cost per request | {:.4} {} |",
+        report.avg_cost_per_request, report.currency
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "| Estimated cost | {:.4} {} |",
+        report.estimated_cost, report.currency
+    )
+    .unwrap();
+
+    for (title, label, breakdown) in [
+        ("By Provider", "Provider", &report.by_provider),
+        ("By Model", "Model", &report.by_model),
+        ("By Project", "Project", &report.by_project),
+    ] {
+        writeln!(output).unwrap();
+        writeln!(output, "## {}", title).unwrap();
+        writeln!(output).unwrap();
+        write_cost_table(&mut output, label, breakdown, report);
+    }
+
+    output
+}
+
+/// Write one cost breakdown table, most expensive entry first.
+fn write_cost_table(
+    output: &mut String,
+    label: &str,
+    breakdown: &HashMap<String, f64>,
+    report: &CostReport,
+) {
+    if breakdown.is_empty() {
+        writeln!(output, "No {} data.", label.to_lowercase()).unwrap();
+        return;
+    }
+
+    let mut entries: Vec<(&String, &f64)> = breakdown.iter().collect();
+    entries.sort_by(|a, b| b.1.total_cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    writeln!(output, "| {} | Cost ({}) | Share |", label, report.currency).unwrap();
+    writeln!(output, "|------|------|-------|").unwrap();
+    for (name, cost) in entries {
+        let share = if report.total_cost > 0.0 {
+            cost / report.total_cost * 100.0
+        } else {
+            0.0
+        };
+        writeln!(output, "| {} | {:.4} | {:.1}% |", name, cost, share).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn anomaly(anomaly_type: &str, severity: &str, value: f64) -> DetectedAnomaly {
+        DetectedAnomaly {
+            id: Uuid::new_v4(),
+            anomaly_type: anomaly_type.to_string(),
+            severity: severity.to_string(),
+            detection_method: "threshold".to_string(),
+            confidence: 0.9,
+            metric: "latency_ms".to_string(),
+            value,
+            threshold: 5000.0,
+            baseline: None,
+            deviation_sigma: None,
+            timestamp: Utc::now(),
+            span_id: None,
+            trace_id: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_anomaly_report() {
+        let report = generate_anomaly_report(&[
+            anomaly("LatencySpike", "Low", 6000.0),
+            anomaly("LatencySpike", "Critical", 30000.0),
+            anomaly("CostAnomaly", "Low", 1.5),
+        ]);
+
+        assert!(report.contains("## CostAnomaly"));
+        assert!(report.contains("| latency_ms | 30000.00 | 5000.00 | 90% |"));
+        let critical = report.find("### Critical (1)").unwrap();
+        let low = report.rfind("### Low (1)").unwrap();
+        assert!(report.find("## LatencySpike").unwrap() < critical && critical < low);
+        assert!(report.contains("| Critical | 1 |\n| Low | 2 |"));
+        assert!(report.contains("Total anomalies: 3"));
+
+        let empty = generate_anomaly_report(&[]);
+        assert!(empty.contains("No anomalies detected."));
+        assert!(!empty.contains("| Severity |"));
+    }
+
+    #[test]
+    fn test_cost_report_to_markdown() {
+        let report = CostReport {
+            total_cost: 4.0,
+            total_requests: 8,
+            avg_cost_per_request: 0.5,
+            by_provider: HashMap::from([
+                ("openai".to_string(), 1.0),
+                ("anthropic".to_string(), 3.0),
+            ]),
+            by_model: HashMap::from([
+                ("gpt-4o".to_string(), 1.0),
+                ("claude-3-opus".to_string(), 3.0),
+            ]),
+            by_project: HashMap::new(),
+            estimated_cost: 0.0,
+            currency: "USD".to_string(),
+            period_start: Utc::now(),
+            period_end: Utc::now(),
+        };
+
+        let markdown = cost_report_to_markdown(&report);
+        assert!(markdown.contains("Period: "));
+        assert!(markdown.contains("| Average cost per request | 0.5000 USD |"));
+        assert!(markdown.contains(
+            "| Provider | Cost (USD) | Share |\n|------|------|-------|\n\
+             | anthropic | 3.0000 | 75.0% |\n| openai | 1.0000 | 25.0% |"
+        ));
+        assert!(markdown.contains("## By Model"));
+        assert!(markdown.contains("No project data."));
+    }
+}
@@ -19,6 +19,7 @@
 //! - **Edge Agent**: Telemetry ingress and gateway traces
 //! - **Inference Gateway**: Backend routing logs and inference telemetry
 //! - **Orchestrator**: Workflow telemetry and pipeline execution traces
+//! - **Timeline**: Merged per-trace span trees across the adapters above
 //!
 //! # Architecture
 //!
@@ -59,23 +60,44 @@ pub mod sentinel;
 pub mod edge_agent;
 pub mod inference_gateway;
 pub mod orchestrator;
+pub mod timeline;
 
 // Phase 2B - Infra integration (foundational utilities)
 pub mod infra;
 
+// Shared helpers used across adapters
+pub mod clock;
+pub mod ids;
+pub mod markdown;
+pub mod parse;
+pub mod redact;
+pub mod retry;
+pub mod sampling;
+pub mod stats;
+
 /// Prelude module for convenient imports.
 pub mod prelude {
     // Phase 2A adapters
     pub use super::config::{ConfigAdapter, ConfigAdapterError};
     pub use super::cost::{CostAdapter, CostAdapterError};
     pub use super::latency::{LatencyAdapter, LatencyAdapterError};
-    pub use super::schema::{SchemaAdapter, SchemaAdapterError};
-    pub use super::sentinel::{SentinelAdapter, SentinelAdapterError};
+    pub use super::schema::{
+        InMemorySchemaRegistry, SchemaAdapter, SchemaAdapterError, SchemaRegistryClient,
+    };
+    pub use super::sentinel::{
+        CusumConfig, CusumMetric, DriftKind, SentinelAdapter, SentinelAdapterError,
+    };
 
     // Phase 2B adapters
     pub use super::edge_agent::{EdgeAgentAdapter, EdgeAgentAdapterError};
-    pub use super::inference_gateway::{InferenceGatewayAdapter, InferenceGatewayAdapterError};
-    pub use super::orchestrator::{OrchestratorAdapter, OrchestratorAdapterError};
+    pub use super::inference_gateway::{
+        CircuitBreakerConfig, CircuitState, HealthTransition, InferenceGatewayAdapter,
+        InferenceGatewayAdapterError, SamplingConfig,
+    };
+    pub use super::orchestrator::{
+        OrchestratorAdapter, OrchestratorAdapterError, SpanExportClient,
+    };
+    pub use super::timeline::{Timeline, TimelineBuilder, TimelineError};
 
     // Phase 2B Infra adapters
     pub use super::infra::{
@@ -83,6 +105,18 @@ pub mod prelude {
         MetricsAdapter, ObservatoryCacheConfig, ObservatoryLogLevel, ObservatoryMetric,
         ObservatoryRateLimitConfig, ObservatoryRetryConfig, RateLimitAdapter, RetryAdapter,
     };
+
+    // Shared helpers
+    pub use super::clock::{Clock, MockClock, SystemClock};
+    pub use super::ids::{IdGenerator, RandomIdGenerator, SequentialIdGenerator};
+    pub use super::markdown::{cost_report_to_markdown, generate_anomaly_report};
+    pub use super::parse::ParseVariantError;
+    pub use super::redact::{redact_text, AttributeRedactionPolicy, UnlistedAttributeAction};
+    pub use super::retry::{retry_with_backoff, RetryPolicy, Retryable};
+    pub use super::sampling::{
+        LatencyPercentile, LatencyPercentiles, Sampler, SamplingContext, TailSampler,
+    };
+    pub use super::stats::{collect_stats, AdapterStats, AdapterStatsReport};
 }
 
 // Re-export Phase 2A adapters at module level
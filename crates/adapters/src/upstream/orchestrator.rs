@@ -13,6 +13,9 @@
 //! - Pipeline execution trace processing
 //! - Step-by-step execution tracking
 //! - Orchestration metrics aggregation
+//! - Export of sampled workflow spans to an Observatory trace endpoint
+//!   (HTTP client behind the `http-export` feature), retrying transient
+//!   failures with backoff
 //!
 //! # Architecture
 //!
@@ -34,11 +37,21 @@
 //! let traces = adapter.extract_pipeline_traces(&workflow)?;
 //! ```
 
+use super::clock::{Clock, SystemClock};
+use super::ids::{IdGenerator, RandomIdGenerator};
+use super::latency::LatencyDistribution;
+use super::parse::impl_variant_str;
+use super::retry::{retry_with_backoff, RetryPolicy, Retryable};
+use super::sampling::{SamplingContext, TailSampler};
+use super::stats::AdapterStats;
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use llm_observatory_core::types::TokenUsage;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use uuid::Uuid;
 
 /// Errors that can occur during orchestrator operations.
 #[derive(Debug, Error)]
@@ -62,6 +75,20 @@ pub enum OrchestratorAdapterError {
     /// Step execution error
     #[error("Step execution error: {0}")]
     StepError(String),
+
+    /// Span export error
+    #[error("Span export error: {0}")]
+    ExportError(String),
+
+    /// Transient span export failure (network error or 5xx), safe to retry
+    #[error("Transient span export error: {0}")]
+    TransientExportError(String),
+}
+
+impl Retryable for OrchestratorAdapterError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::TransientExportError(_))
+    }
 }
 
 /// Result type for orchestrator operations.
@@ -176,6 +203,18 @@ pub enum WorkflowStatus {
     Paused,
 }
 
+impl_variant_str!(
+    WorkflowStatus {
+        Pending => "pending",
+        Running => "running",
+        Completed => "completed",
+        Failed => "failed",
+        Cancelled => "cancelled",
+        Timeout => "timeout",
+        Paused => "paused",
+    }
+);
+
 /// Token usage aggregated at workflow level.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorkflowTokenUsage {
@@ -240,6 +279,17 @@ pub enum PipelineStatus {
     Retried,
 }
 
+impl_variant_str!(
+    PipelineStatus {
+        Pending => "pending",
+        Running => "running",
+        Completed => "completed",
+        Failed => "failed",
+        Skipped => "skipped",
+        Retried => "retried",
+    }
+);
+
 /// Token usage for a pipeline.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PipelineTokenUsage {
@@ -327,6 +377,22 @@ pub enum StepType {
     Custom(String),
 }
 
+impl_variant_str!(
+    StepType {
+        LlmCompletion => "llm_completion",
+        LlmChat => "llm_chat",
+        LlmEmbedding => "llm_embedding",
+        Transform => "transform",
+        ApiCall => "api_call",
+        Database => "database",
+        Cache => "cache",
+        Condition => "condition",
+        Parallel => "parallel",
+        Loop => "loop",
+    },
+    custom = Custom
+);
+
 /// Step execution status.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -345,6 +411,17 @@ pub enum StepStatus {
     Waiting,
 }
 
+impl_variant_str!(
+    StepStatus {
+        Pending => "pending",
+        Running => "running",
+        Completed => "completed",
+        Failed => "failed",
+        Skipped => "skipped",
+        Waiting => "waiting",
+    }
+);
+
 /// Token usage for a step.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StepTokenUsage {
@@ -356,6 +433,9 @@ pub struct StepTokenUsage {
     pub total_tokens: u32,
 }
 
+/// Most recent workflow and pipeline durations kept for percentiles.
+pub const DURATION_SAMPLE_CAPACITY: usize = 10_000;
+
 /// Orchestrator statistics.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OrchestratorStats {
@@ -381,6 +461,67 @@ pub struct OrchestratorStats {
     pub total_cost_usd: f64,
 }
 
+/// Client used to ship span JSON to an Observatory trace endpoint.
+///
+/// Implement this to plug in a custom transport or a mock in tests.
+#[async_trait]
+pub trait SpanExportClient: Send + Sync {
+    /// POST a batch of spans to `endpoint`, returning how many were accepted.
+    async fn post_spans(&self, endpoint: &str, spans: &[serde_json::Value]) -> Result<usize>;
+}
+
+/// [`SpanExportClient`] backed by `reqwest`.
+///
+/// Expects the endpoint to answer with a body carrying an `accepted` count,
+/// as `POST /api/v1/traces` does.
+#[cfg(feature = "http-export")]
+#[derive(Debug, Clone, Default)]
+pub struct HttpSpanExportClient {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "http-export")]
+impl HttpSpanExportClient {
+    /// Create a client wrapping an existing `reqwest::Client`.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "http-export")]
+#[async_trait]
+impl SpanExportClient for HttpSpanExportClient {
+    async fn post_spans(&self, endpoint: &str, spans: &[serde_json::Value]) -> Result<usize> {
+        let response = self
+            .client
+            .post(endpoint)
+            .json(spans)
+            .send()
+            .await
+            .map_err(|e| OrchestratorAdapterError::TransientExportError(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(OrchestratorAdapterError::TransientExportError(format!(
+                "Endpoint returned {}",
+                status
+            )));
+        }
+        if !status.is_success() {
+            return Err(OrchestratorAdapterError::ExportError(format!(
+                "Endpoint returned {}",
+                status
+            )));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| OrchestratorAdapterError::ExportError(e.to_string()))?;
+        Ok(body.get("accepted").and_then(|v| v.as_u64()).unwrap_or(0) as usize)
+    }
+}
+
 /// Adapter for consuming LLM-Orchestrator telemetry.
 ///
 /// Provides runtime integration for Observatory to ingest workflow telemetry
@@ -392,18 +533,74 @@ pub struct OrchestratorAdapter {
     workflows: Vec<WorkflowTelemetry>,
     /// Statistics
     stats: OrchestratorStats,
+    /// Recent workflow durations (ms), for percentiles
+    workflow_durations: VecDeque<u64>,
+    /// Recent pipeline durations (ms), for percentiles
+    pipeline_durations: VecDeque<u64>,
+    /// Tail-based sampler for workflows
+    sampler: TailSampler,
+    /// Retry policy for span export calls
+    retry_policy: RetryPolicy,
+    /// Source of workflow, pipeline and step start times
+    clock: Arc<dyn Clock>,
+    /// Source of ids the adapter assigns itself
+    ids: Arc<dyn IdGenerator>,
 }
 
 impl OrchestratorAdapter {
     /// Create a new OrchestratorAdapter.
+    ///
+    /// Workflows are sampled when slower than 30s, costlier than $1 or
+    /// using more than 50K tokens; see [`Self::set_sampler`].
     pub fn new(orchestrator_id: impl Into<String>) -> Self {
+        Self::with_clock(orchestrator_id, Arc::new(SystemClock))
+    }
+
+    /// Create a new OrchestratorAdapter that reads the time from `clock`.
+    pub fn with_clock(orchestrator_id: impl Into<String>, clock: Arc<dyn Clock>) -> Self {
         Self {
             orchestrator_id: OrchestratorId::new(orchestrator_id),
             workflows: Vec::new(),
             stats: OrchestratorStats::default(),
+            workflow_durations: VecDeque::new(),
+            pipeline_durations: VecDeque::new(),
+            sampler: TailSampler::new()
+                .with_latency_ms(30000)
+                .with_cost_usd(1.0)
+                .with_token_count(50000),
+            retry_policy: RetryPolicy::default(),
+            clock,
+            ids: Arc::new(RandomIdGenerator),
         }
     }
 
+    /// Replace the generator of ids the adapter assigns itself, e.g. with a
+    /// [`SequentialIdGenerator`](super::ids::SequentialIdGenerator) for
+    /// reproducible output.
+    pub fn set_id_generator(&mut self, ids: Arc<dyn IdGenerator>) {
+        self.ids = ids;
+    }
+
+    /// Get the workflow sampler.
+    pub fn sampler(&self) -> &TailSampler {
+        &self.sampler
+    }
+
+    /// Replace the workflow sampler.
+    pub fn set_sampler(&mut self, sampler: TailSampler) {
+        self.sampler = sampler;
+    }
+
+    /// Get the retry policy used for span export.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Replace the retry policy used for span export.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
     /// Get the orchestrator ID.
     pub fn orchestrator_id(&self) -> &OrchestratorId {
         &self.orchestrator_id
@@ -428,16 +625,7 @@ impl OrchestratorAdapter {
         let status = json_data
             .get("status")
             .and_then(|v| v.as_str())
-            .map(|s| match s {
-                "pending" => WorkflowStatus::Pending,
-                "running" => WorkflowStatus::Running,
-                "completed" => WorkflowStatus::Completed,
-                "failed" => WorkflowStatus::Failed,
-                "cancelled" => WorkflowStatus::Cancelled,
-                "timeout" => WorkflowStatus::Timeout,
-                "paused" => WorkflowStatus::Paused,
-                _ => WorkflowStatus::Pending,
-            })
+            .map(|s| s.parse().unwrap_or(WorkflowStatus::Pending))
             .unwrap_or(WorkflowStatus::Pending);
 
         let pipelines = self.parse_pipelines(json_data, &WorkflowId::new(workflow_id))?;
@@ -457,7 +645,7 @@ impl OrchestratorAdapter {
                 .get("version")
                 .and_then(|v| v.as_str())
                 .map(String::from),
-            start_time: Utc::now(),
+            start_time: self.parse_start_time(json_data)?,
             end_time: None,
             duration_ms: json_data.get("duration_ms").and_then(|v| v.as_u64()),
             status: status.clone(),
@@ -490,6 +678,8 @@ impl OrchestratorAdapter {
             let n = self.stats.total_workflows as f64;
             self.stats.avg_workflow_duration_ms =
                 (self.stats.avg_workflow_duration_ms * (n - 1.0) + duration as f64) / n;
+            record_duration(&mut self.workflow_durations, duration);
+            self.sampler.record_latency(duration);
         }
 
         self.workflows.push(workflow.clone());
@@ -497,6 +687,19 @@ impl OrchestratorAdapter {
         Ok(workflow)
     }
 
+    /// `start_time` of a workflow, pipeline or step, read from its JSON when
+    /// present (RFC 3339) and taken from the adapter's clock otherwise.
+    fn parse_start_time(&self, json_data: &serde_json::Value) -> Result<DateTime<Utc>> {
+        let Some(raw) = json_data.get("start_time").and_then(|v| v.as_str()) else {
+            return Ok(self.clock.now());
+        };
+        DateTime::parse_from_rfc3339(raw)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| {
+                OrchestratorAdapterError::ParseError(format!("start_time '{}': {}", raw, e))
+            })
+    }
+
     /// Parse pipelines from workflow JSON.
     fn parse_pipelines(
         &mut self,
@@ -514,8 +717,7 @@ impl OrchestratorAdapter {
             let pipeline_id = pipeline_json
                 .get("pipeline_id")
                 .and_then(|v| v.as_str())
-                .unwrap_or(&Uuid::new_v4().to_string())
-                .to_string();
+                .map_or_else(|| self.ids.next_id().to_string(), String::from);
 
             let name = pipeline_json
                 .get("name")
@@ -526,15 +728,7 @@ impl OrchestratorAdapter {
             let status = pipeline_json
                 .get("status")
                 .and_then(|v| v.as_str())
-                .map(|s| match s {
-                    "pending" => PipelineStatus::Pending,
-                    "running" => PipelineStatus::Running,
-                    "completed" => PipelineStatus::Completed,
-                    "failed" => PipelineStatus::Failed,
-                    "skipped" => PipelineStatus::Skipped,
-                    "retried" => PipelineStatus::Retried,
-                    _ => PipelineStatus::Pending,
-                })
+                .map(|s| s.parse().unwrap_or(PipelineStatus::Pending))
                 .unwrap_or(PipelineStatus::Pending);
 
             let steps = self.parse_steps(pipeline_json)?;
@@ -548,13 +742,12 @@ impl OrchestratorAdapter {
                 span_id: pipeline_json
                     .get("span_id")
                     .and_then(|v| v.as_str())
-                    .unwrap_or(&Uuid::new_v4().to_string())
-                    .to_string(),
+                    .map_or_else(|| self.ids.next_id().to_string(), String::from),
                 parent_span_id: pipeline_json
                     .get("parent_span_id")
                     .and_then(|v| v.as_str())
                     .map(String::from),
-                start_time: Utc::now(),
+                start_time: self.parse_start_time(pipeline_json)?,
                 end_time: None,
                 duration_ms: pipeline_json.get("duration_ms").and_then(|v| v.as_u64()),
                 status,
@@ -565,6 +758,9 @@ impl OrchestratorAdapter {
             };
 
             self.stats.total_pipelines += 1;
+            if let Some(duration) = pipeline.duration_ms {
+                record_duration(&mut self.pipeline_durations, duration);
+            }
             pipelines.push(pipeline);
         }
 
@@ -584,40 +780,28 @@ impl OrchestratorAdapter {
             let step_type = step_json
                 .get("step_type")
                 .and_then(|v| v.as_str())
-                .map(|s| match s {
-                    "llm_completion" => StepType::LlmCompletion,
-                    "llm_chat" => StepType::LlmChat,
-                    "llm_embedding" => StepType::LlmEmbedding,
-                    "transform" => StepType::Transform,
-                    "api_call" => StepType::ApiCall,
-                    "database" => StepType::Database,
-                    "cache" => StepType::Cache,
-                    "condition" => StepType::Condition,
-                    "parallel" => StepType::Parallel,
-                    "loop" => StepType::Loop,
-                    other => StepType::Custom(other.to_string()),
-                })
+                .and_then(|s| s.parse::<StepType>().ok())
                 .unwrap_or(StepType::Custom("unknown".to_string()));
 
             let status = step_json
                 .get("status")
                 .and_then(|v| v.as_str())
-                .map(|s| match s {
-                    "pending" => StepStatus::Pending,
-                    "running" => StepStatus::Running,
-                    "completed" => StepStatus::Completed,
-                    "failed" => StepStatus::Failed,
-                    "skipped" => StepStatus::Skipped,
-                    "waiting" => StepStatus::Waiting,
-                    _ => StepStatus::Pending,
-                })
+                .map(|s| s.parse().unwrap_or(StepStatus::Pending))
                 .unwrap_or(StepStatus::Pending);
 
+            // The reported total is recomputed from its parts so it is always consistent
             let token_usage = step_json.get("token_usage").and_then(|v| {
-                Some(StepTokenUsage {
+                let mut usage = TokenUsage {
                     prompt_tokens: v.get("prompt_tokens")?.as_u64()? as u32,
                     completion_tokens: v.get("completion_tokens")?.as_u64()? as u32,
-                    total_tokens: v.get("total_tokens")?.as_u64()? as u32,
+                    total_tokens: v.get("total_tokens").and_then(|t| t.as_u64()).unwrap_or(0)
+                        as u32,
+                };
+                usage.reconcile();
+                Some(StepTokenUsage {
+                    prompt_tokens: usage.prompt_tokens,
+                    completion_tokens: usage.completion_tokens,
+                    total_tokens: usage.total_tokens,
                 })
             });
 
@@ -625,8 +809,7 @@ impl OrchestratorAdapter {
                 step_id: step_json
                     .get("step_id")
                     .and_then(|v| v.as_str())
-                    .unwrap_or(&Uuid::new_v4().to_string())
-                    .to_string(),
+                    .map_or_else(|| self.ids.next_id().to_string(), String::from),
                 name: step_json
                     .get("name")
                     .and_then(|v| v.as_str())
@@ -636,13 +819,12 @@ impl OrchestratorAdapter {
                 span_id: step_json
                     .get("span_id")
                     .and_then(|v| v.as_str())
-                    .unwrap_or(&Uuid::new_v4().to_string())
-                    .to_string(),
+                    .map_or_else(|| self.ids.next_id().to_string(), String::from),
                 parent_span_id: step_json
                     .get("parent_span_id")
                     .and_then(|v| v.as_str())
                     .map(String::from),
-                start_time: Utc::now(),
+                start_time: self.parse_start_time(step_json)?,
                 end_time: None,
                 duration_ms: step_json.get("duration_ms").and_then(|v| v.as_u64()),
                 status,
@@ -726,6 +908,26 @@ impl OrchestratorAdapter {
         &self.workflows
     }
 
+    /// Get workflows with the given status.
+    pub fn workflows_with_status(&self, status: WorkflowStatus) -> Vec<&WorkflowTelemetry> {
+        self.workflows
+            .iter()
+            .filter(|w| w.status == status)
+            .collect()
+    }
+
+    /// Get workflows whose `start_time` falls in `[start, end)`.
+    pub fn workflows_in_range(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<&WorkflowTelemetry> {
+        self.workflows
+            .iter()
+            .filter(|w| w.start_time >= start && w.start_time < end)
+            .collect()
+    }
+
     /// Get statistics.
     pub fn stats(&self) -> &OrchestratorStats {
         &self.stats
@@ -735,23 +937,51 @@ impl OrchestratorAdapter {
     pub fn clear(&mut self) {
         self.workflows.clear();
         self.stats = OrchestratorStats::default();
+        self.workflow_durations.clear();
+        self.pipeline_durations.clear();
+    }
+
+    /// (p50, p90, p99) of recent workflow durations in milliseconds, over
+    /// the last [`DURATION_SAMPLE_CAPACITY`] workflows that reported one.
+    /// All zero when none have.
+    pub fn workflow_duration_percentiles(&self) -> (u64, u64, u64) {
+        duration_percentiles(&self.workflow_durations)
+    }
+
+    /// (p50, p90, p99) of recent pipeline durations in milliseconds, over
+    /// the last [`DURATION_SAMPLE_CAPACITY`] pipelines that reported one.
+    /// All zero when none have.
+    pub fn pipeline_duration_percentiles(&self) -> (u64, u64, u64) {
+        duration_percentiles(&self.pipeline_durations)
     }
 
     /// Extract all pipeline executions across workflows.
+    ///
+    /// Pipelines are ordered by `start_time`, then `span_id`, regardless of
+    /// the order in which the telemetry listed them.
     pub fn all_pipelines(&self) -> Vec<&PipelineExecution> {
-        self.workflows
+        let mut pipelines: Vec<&PipelineExecution> = self
+            .workflows
             .iter()
             .flat_map(|w| w.pipelines.iter())
-            .collect()
+            .collect();
+        pipelines.sort_by(|a, b| (a.start_time, &a.span_id).cmp(&(b.start_time, &b.span_id)));
+        pipelines
     }
 
     /// Extract all steps across all pipelines.
+    ///
+    /// Steps are ordered by `start_time`, then `span_id`, regardless of the
+    /// order in which the telemetry listed them.
     pub fn all_steps(&self) -> Vec<&PipelineStep> {
-        self.workflows
+        let mut steps: Vec<&PipelineStep> = self
+            .workflows
             .iter()
             .flat_map(|w| w.pipelines.iter())
             .flat_map(|p| p.steps.iter())
-            .collect()
+            .collect();
+        steps.sort_by(|a, b| (a.start_time, &a.span_id).cmp(&(b.start_time, &b.span_id)));
+        steps
     }
 
     /// Get all LLM steps.
@@ -767,49 +997,117 @@ impl OrchestratorAdapter {
             .collect()
     }
 
-    /// Check if workflow should be sampled (for tail-based sampling).
-    pub fn should_sample_workflow(&self, workflow: &WorkflowTelemetry) -> bool {
-        // Always sample failed workflows
-        if workflow.status == WorkflowStatus::Failed {
-            return true;
-        }
+    /// Reconstruct the step execution DAG of a pipeline.
+    ///
+    /// Maps every step ID to the IDs of the steps whose `parent_span_id` is
+    /// that step's span, in pipeline order. Steps parented to the pipeline
+    /// itself (or to an unknown span) are roots and appear only as keys.
+    pub fn step_dependency_graph(
+        &self,
+        pipeline: &PipelineExecution,
+    ) -> HashMap<String, Vec<String>> {
+        let step_by_span: HashMap<&str, &str> = pipeline
+            .steps
+            .iter()
+            .map(|s| (s.span_id.as_str(), s.step_id.as_str()))
+            .collect();
 
-        // Always sample timed out workflows
-        if workflow.status == WorkflowStatus::Timeout {
-            return true;
-        }
+        let mut graph: HashMap<String, Vec<String>> = pipeline
+            .steps
+            .iter()
+            .map(|s| (s.step_id.clone(), Vec::new()))
+            .collect();
 
-        // Sample slow workflows (> 30 seconds)
-        if let Some(duration) = workflow.duration_ms {
-            if duration > 30000 {
-                return true;
+        for step in &pipeline.steps {
+            let parent = step
+                .parent_span_id
+                .as_deref()
+                .and_then(|span| step_by_span.get(span));
+            if let Some(parent) = parent {
+                if let Some(children) = graph.get_mut(*parent) {
+                    children.push(step.step_id.clone());
+                }
             }
         }
 
-        // Sample high-cost workflows (> $1)
-        if let Some(cost) = workflow.total_cost_usd {
-            if cost > 1.0 {
-                return true;
-            }
-        }
+        graph
+    }
 
-        // Sample high-token workflows (> 50K tokens)
-        if let Some(usage) = &workflow.total_token_usage {
-            if usage.total_tokens > 50000 {
-                return true;
-            }
-        }
+    /// Group steps of a pipeline that ran in parallel.
+    ///
+    /// Each group holds the children of one fan-out point: a
+    /// [`StepType::Parallel`] step, or any step with more than one child.
+    /// Groups are returned in the pipeline order of their fan-out step.
+    pub fn parallel_groups(&self, pipeline: &PipelineExecution) -> Vec<Vec<String>> {
+        let mut graph = self.step_dependency_graph(pipeline);
 
-        // Sample workflows with failed pipelines
-        if workflow
+        pipeline
+            .steps
+            .iter()
+            .filter_map(|step| {
+                let children = graph.remove(&step.step_id)?;
+                let fan_out = step.step_type == StepType::Parallel || children.len() > 1;
+                (fan_out && !children.is_empty()).then_some(children)
+            })
+            .collect()
+    }
+
+    /// Check if workflow should be sampled (for tail-based sampling).
+    ///
+    /// Failed or timed-out workflows, and workflows with a failed pipeline,
+    /// always count as errors. Durations are recorded in the sampler's
+    /// latency window once, when the workflow is parsed, so deciding again
+    /// (e.g. on every export) does not skew the rolling percentile.
+    pub fn should_sample_workflow(&self, workflow: &WorkflowTelemetry) -> bool {
+        let error = matches!(
+            workflow.status,
+            WorkflowStatus::Failed | WorkflowStatus::Timeout
+        ) || workflow
             .pipelines
             .iter()
-            .any(|p| p.status == PipelineStatus::Failed)
+            .any(|p| p.status == PipelineStatus::Failed);
+
+        self.sampler.matches(&SamplingContext {
+            latency_ms: workflow.duration_ms,
+            cost_usd: workflow.total_cost_usd,
+            token_count: workflow.total_token_usage.as_ref().map(|u| u.total_tokens),
+            error,
+            key: Some(workflow.workflow_id.as_str().to_string()),
+        })
+    }
+
+    /// Export every sampled workflow to an Observatory trace endpoint.
+    ///
+    /// Only workflows passing [`Self::should_sample_workflow`] are sent, one
+    /// POST per workflow. Transient failures are retried according to
+    /// [`Self::retry_policy`]. Returns the total number of spans accepted.
+    #[cfg(feature = "http-export")]
+    pub async fn export_spans(&self, endpoint: &str) -> Result<usize> {
+        self.export_spans_with(&HttpSpanExportClient::default(), endpoint)
+            .await
+    }
+
+    /// Export every sampled workflow using the given client.
+    ///
+    /// See [`Self::export_spans`].
+    pub async fn export_spans_with(
+        &self,
+        client: &dyn SpanExportClient,
+        endpoint: &str,
+    ) -> Result<usize> {
+        let mut accepted = 0;
+        for workflow in self
+            .workflows
+            .iter()
+            .filter(|w| self.should_sample_workflow(w))
         {
-            return true;
+            let span = self.workflow_to_span_json(workflow);
+            let spans = std::slice::from_ref(&span);
+            accepted +=
+                retry_with_backoff(&self.retry_policy, || client.post_spans(endpoint, spans))
+                    .await?;
         }
-
-        false
+        Ok(accepted)
     }
 
     /// Convert workflow to Observatory span format.
@@ -894,9 +1192,45 @@ impl OrchestratorAdapter {
     }
 }
 
+impl AdapterStats for OrchestratorAdapter {
+    fn adapter_name(&self) -> String {
+        format!("orchestrator/{}", self.orchestrator_id.as_str())
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(&self.stats).unwrap_or_default()
+    }
+}
+
+/// Append a duration sample, dropping the oldest beyond capacity.
+fn record_duration(samples: &mut VecDeque<u64>, duration_ms: u64) {
+    if samples.len() == DURATION_SAMPLE_CAPACITY {
+        samples.pop_front();
+    }
+    samples.push_back(duration_ms);
+}
+
+/// (p50, p90, p99) in milliseconds, using [`LatencyDistribution`]'s math.
+fn duration_percentiles(samples: &VecDeque<u64>) -> (u64, u64, u64) {
+    let durations: Vec<Duration> = samples
+        .iter()
+        .map(|&ms| Duration::from_millis(ms))
+        .collect();
+    let distribution = LatencyDistribution::from_samples(&durations);
+    let ms = |d: Duration| d.as_millis() as u64;
+    (
+        ms(distribution.p50),
+        ms(distribution.p90),
+        ms(distribution.p99),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::upstream::clock::MockClock;
+    use crate::upstream::ids::SequentialIdGenerator;
+    use crate::upstream::sampling::LatencyPercentile;
 
     #[test]
     fn test_orchestrator_adapter_creation() {
@@ -950,6 +1284,159 @@ mod tests {
         assert_eq!(workflow.pipelines[0].steps.len(), 1);
     }
 
+    #[test]
+    fn test_start_times_come_from_clock() {
+        let start = Utc::now() - chrono::Duration::hours(1);
+        let clock = Arc::new(MockClock::new(start));
+        let mut adapter = OrchestratorAdapter::with_clock("orchestrator-1", clock.clone());
+
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-123",
+            "name": "document-processing",
+            "status": "completed",
+            "pipelines": [
+                {
+                    "pipeline_id": "pl-1",
+                    "name": "extract",
+                    "status": "completed",
+                    "steps": [
+                        {"step_id": "step-1", "name": "llm-extract", "step_type": "llm_completion", "status": "completed"}
+                    ]
+                }
+            ]
+        });
+
+        let workflow = adapter.parse_workflow_telemetry(&json_data).unwrap();
+        assert_eq!(workflow.start_time, start);
+        assert_eq!(workflow.pipelines[0].start_time, start);
+        assert_eq!(workflow.pipelines[0].steps[0].start_time, start);
+
+        clock.advance(chrono::Duration::seconds(5));
+        let workflow = adapter.parse_workflow_telemetry(&json_data).unwrap();
+        assert_eq!(workflow.start_time, start + chrono::Duration::seconds(5));
+    }
+
+    #[test]
+    fn test_all_steps_are_chronological() {
+        let clock = Arc::new(MockClock::new(Utc::now()));
+        let mut adapter = OrchestratorAdapter::with_clock("orchestrator-1", clock);
+
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-123",
+            "pipelines": [
+                {
+                    "pipeline_id": "pl-2",
+                    "span_id": "span-pl-2",
+                    "steps": [
+                        {"step_id": "c", "span_id": "span-c", "step_type": "transform"},
+                        {"step_id": "a", "span_id": "span-a", "step_type": "transform"}
+                    ]
+                },
+                {
+                    "pipeline_id": "pl-1",
+                    "span_id": "span-pl-1",
+                    "steps": [
+                        {"step_id": "b", "span_id": "span-b", "step_type": "transform"}
+                    ]
+                }
+            ]
+        });
+        adapter.parse_workflow_telemetry(&json_data).unwrap();
+
+        // Equal start times fall back to span ID order
+        let ids = |adapter: &OrchestratorAdapter| -> Vec<String> {
+            adapter
+                .all_steps()
+                .iter()
+                .map(|s| s.step_id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&adapter), ["a", "b", "c"]);
+        let pipelines: Vec<&str> = adapter
+            .all_pipelines()
+            .iter()
+            .map(|p| p.span_id.as_str())
+            .collect();
+        assert_eq!(pipelines, ["span-pl-1", "span-pl-2"]);
+
+        // Reported start times win over listing order and span IDs
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-456",
+            "pipelines": [
+                {
+                    "pipeline_id": "pl-3",
+                    "span_id": "span-pl-3",
+                    "start_time": "2025-01-01T00:00:05Z",
+                    "steps": [
+                        {"step_id": "z", "span_id": "span-z", "step_type": "transform",
+                         "start_time": "2025-01-01T00:00:30Z"},
+                        {"step_id": "x", "span_id": "span-x", "step_type": "transform",
+                         "start_time": "2025-01-01T00:00:10Z"}
+                    ]
+                },
+                {
+                    "pipeline_id": "pl-4",
+                    "span_id": "span-pl-4",
+                    "start_time": "2025-01-01T00:00:00Z",
+                    "steps": [
+                        {"step_id": "y", "span_id": "span-y", "step_type": "transform",
+                         "start_time": "2025-01-01T00:00:20Z"}
+                    ]
+                }
+            ]
+        });
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        adapter.parse_workflow_telemetry(&json_data).unwrap();
+        assert_eq!(ids(&adapter), ["x", "y", "z"]);
+        let pipelines: Vec<&str> = adapter
+            .all_pipelines()
+            .iter()
+            .map(|p| p.span_id.as_str())
+            .collect();
+        assert_eq!(pipelines, ["span-pl-4", "span-pl-3"]);
+    }
+
+    #[test]
+    fn test_invalid_start_time_is_rejected() {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-123",
+            "pipelines": [
+                {"pipeline_id": "pl-1", "start_time": "yesterday", "steps": []}
+            ]
+        });
+        assert!(matches!(
+            adapter.parse_workflow_telemetry(&json_data),
+            Err(OrchestratorAdapterError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_pipeline_id_gets_stable_generated_id() {
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-123",
+            "pipelines": [
+                {"name": "extract", "span_id": "span-pl", "steps": []}
+            ]
+        });
+
+        let parse = || {
+            let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+            adapter.set_id_generator(Arc::new(SequentialIdGenerator::new()));
+            adapter.parse_workflow_telemetry(&json_data).unwrap()
+        };
+        let workflow = parse();
+        assert_eq!(
+            workflow.pipelines[0].pipeline_id.as_str(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+        assert_eq!(workflow.pipelines[0].span_id, "span-pl");
+        assert_eq!(
+            parse().pipelines[0].pipeline_id,
+            workflow.pipelines[0].pipeline_id
+        );
+    }
+
     #[test]
     fn test_token_usage_aggregation() {
         let mut adapter = OrchestratorAdapter::new("orchestrator-1");
@@ -1054,6 +1541,180 @@ mod tests {
         assert!(!adapter.should_sample_workflow(&normal));
     }
 
+    /// Records every POST and accepts all spans
+    #[derive(Default)]
+    struct MockExportClient {
+        posted: std::sync::Mutex<Vec<(String, serde_json::Value)>>,
+    }
+
+    #[async_trait]
+    impl SpanExportClient for MockExportClient {
+        async fn post_spans(&self, endpoint: &str, spans: &[serde_json::Value]) -> Result<usize> {
+            let mut posted = self.posted.lock().unwrap();
+            for span in spans {
+                posted.push((endpoint.to_string(), span.clone()));
+            }
+            Ok(spans.len())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_spans_only_sends_sampled_workflows() {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        for (id, status) in [("wf-ok", "completed"), ("wf-failed", "failed")] {
+            let json_data = serde_json::json!({
+                "workflow_id": id,
+                "name": "pipeline",
+                "status": status,
+                "duration_ms": 1000
+            });
+            adapter.parse_workflow_telemetry(&json_data).unwrap();
+        }
+
+        let client = MockExportClient::default();
+        let endpoint = "http://observatory/api/v1/traces";
+        let accepted = adapter.export_spans_with(&client, endpoint).await.unwrap();
+        assert_eq!(accepted, 1);
+
+        let posted = client.posted.lock().unwrap();
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].0, endpoint);
+        assert_eq!(posted[0].1["span_id"], "wf-failed");
+    }
+
+    /// Fails with the queued errors before accepting spans
+    struct FlakyExportClient {
+        failures: std::sync::Mutex<Vec<OrchestratorAdapterError>>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl FlakyExportClient {
+        fn new(failures: Vec<OrchestratorAdapterError>) -> Self {
+            Self {
+                failures: std::sync::Mutex::new(failures),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl SpanExportClient for FlakyExportClient {
+        async fn post_spans(&self, _endpoint: &str, spans: &[serde_json::Value]) -> Result<usize> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            match self.failures.lock().unwrap().pop() {
+                Some(err) => Err(err),
+                None => Ok(spans.len()),
+            }
+        }
+    }
+
+    fn adapter_with_failed_workflow() -> OrchestratorAdapter {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        adapter.set_retry_policy(
+            RetryPolicy::new(3).with_base_delay(std::time::Duration::from_millis(1)),
+        );
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-failed",
+            "name": "pipeline",
+            "status": "failed"
+        });
+        adapter.parse_workflow_telemetry(&json_data).unwrap();
+        adapter
+    }
+
+    #[tokio::test]
+    async fn test_export_spans_retries_transient_failures() {
+        let adapter = adapter_with_failed_workflow();
+        let client = FlakyExportClient::new(vec![
+            OrchestratorAdapterError::TransientExportError("503".to_string()),
+            OrchestratorAdapterError::TransientExportError("connection reset".to_string()),
+        ]);
+
+        let accepted = adapter
+            .export_spans_with(&client, "http://observatory/api/v1/traces")
+            .await
+            .unwrap();
+        assert_eq!(accepted, 1);
+        assert_eq!(client.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_export_spans_fails_fast_on_permanent_error() {
+        let adapter = adapter_with_failed_workflow();
+        let client = FlakyExportClient::new(vec![OrchestratorAdapterError::ExportError(
+            "400".to_string(),
+        )]);
+
+        let result = adapter
+            .export_spans_with(&client, "http://observatory/api/v1/traces")
+            .await;
+        assert!(matches!(
+            result,
+            Err(OrchestratorAdapterError::ExportError(_))
+        ));
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_export_does_not_record_latencies_again() {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        adapter.set_sampler(TailSampler::new().with_latency_percentile(LatencyPercentile::P95));
+        for i in 0..30u64 {
+            let json_data = serde_json::json!({
+                "workflow_id": format!("wf-{}", i),
+                "duration_ms": 1000 + i,
+            });
+            adapter.parse_workflow_telemetry(&json_data).unwrap();
+        }
+        let recorded = adapter.sampler().latency_percentiles().unwrap();
+        assert_eq!(recorded.samples, 30);
+
+        let client = MockExportClient::default();
+        for _ in 0..2 {
+            adapter
+                .export_spans_with(&client, "http://observatory/api/v1/traces")
+                .await
+                .unwrap();
+        }
+        assert_eq!(adapter.sampler().latency_percentiles(), Some(recorded));
+    }
+
+    #[test]
+    fn test_duration_percentiles() {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        assert_eq!(adapter.workflow_duration_percentiles(), (0, 0, 0));
+        assert_eq!(adapter.pipeline_duration_percentiles(), (0, 0, 0));
+
+        // A long tail: mostly ~1s workflows and one 60s outlier
+        for i in 0..20u64 {
+            let duration = if i == 19 { 60_000 } else { 1000 + i * 10 };
+            let json_data = serde_json::json!({
+                "workflow_id": format!("wf-{}", i),
+                "duration_ms": duration,
+                "pipelines": [
+                    {"pipeline_id": format!("pl-{}", i), "duration_ms": duration / 2, "steps": []}
+                ]
+            });
+            adapter.parse_workflow_telemetry(&json_data).unwrap();
+        }
+
+        let (p50, p90, p99) = adapter.workflow_duration_percentiles();
+        assert!(p50 <= p90 && p90 <= p99);
+        assert_eq!(p50, 1100);
+        assert_eq!(p99, 60_000);
+        assert!(adapter.stats().avg_workflow_duration_ms > p50 as f64);
+
+        let (p50, _, p99) = adapter.pipeline_duration_percentiles();
+        assert_eq!((p50, p99), (550, 30_000));
+
+        adapter.clear();
+        assert_eq!(adapter.workflow_duration_percentiles(), (0, 0, 0));
+    }
+
     #[test]
     fn test_stats_tracking() {
         let mut adapter = OrchestratorAdapter::new("orchestrator-1");
@@ -1151,6 +1812,104 @@ mod tests {
         assert_eq!(json["status"], "ok");
     }
 
+    #[test]
+    fn test_workflow_filters() {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+        let base = Utc::now();
+        let workflow = |id: &str, status: WorkflowStatus, offset_secs: i64| WorkflowTelemetry {
+            workflow_id: WorkflowId::new(id),
+            name: "test".to_string(),
+            orchestrator_id: OrchestratorId::new("orchestrator-1"),
+            trace_id: None,
+            version: None,
+            start_time: base + chrono::Duration::seconds(offset_secs),
+            end_time: None,
+            duration_ms: None,
+            status,
+            pipelines: Vec::new(),
+            total_token_usage: None,
+            total_cost_usd: None,
+            input_params: HashMap::new(),
+            output_results: HashMap::new(),
+            metadata: HashMap::new(),
+        };
+        adapter.workflows = vec![
+            workflow("wf-1", WorkflowStatus::Completed, 0),
+            workflow("wf-2", WorkflowStatus::Failed, 60),
+            workflow("wf-3", WorkflowStatus::Completed, 120),
+            workflow("wf-4", WorkflowStatus::Failed, 180),
+        ];
+
+        let ids = |workflows: Vec<&WorkflowTelemetry>| -> Vec<String> {
+            workflows
+                .iter()
+                .map(|w| w.workflow_id.as_str().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            ids(adapter.workflows_with_status(WorkflowStatus::Failed)),
+            ["wf-2", "wf-4"]
+        );
+        assert!(adapter
+            .workflows_with_status(WorkflowStatus::Running)
+            .is_empty());
+
+        let start = base + chrono::Duration::seconds(60);
+        let end = base + chrono::Duration::seconds(180);
+        assert_eq!(
+            ids(adapter.workflows_in_range(start, end)),
+            ["wf-2", "wf-3"]
+        );
+        assert!(adapter.workflows_in_range(end, start).is_empty());
+    }
+
+    #[test]
+    fn test_step_dependency_graph_fan_out_fan_in() {
+        let mut adapter = OrchestratorAdapter::new("orchestrator-1");
+
+        let step = |id: &str, step_type: &str, parent: &str| {
+            serde_json::json!({
+                "step_id": id,
+                "step_type": step_type,
+                "status": "completed",
+                "span_id": format!("span-{}", id),
+                "parent_span_id": parent
+            })
+        };
+        let json_data = serde_json::json!({
+            "workflow_id": "wf-dag",
+            "name": "fan-out",
+            "status": "completed",
+            "pipelines": [{
+                "pipeline_id": "pl-1",
+                "name": "dag",
+                "status": "completed",
+                "span_id": "span-pl-1",
+                "steps": [
+                    step("prepare", "transform", "span-pl-1"),
+                    step("fan-out", "parallel", "span-pl-1"),
+                    step("branch-a", "llm_chat", "span-fan-out"),
+                    step("branch-b", "llm_chat", "span-fan-out"),
+                    step("branch-c", "api_call", "span-fan-out"),
+                    step("fan-in", "transform", "span-pl-1")
+                ]
+            }]
+        });
+        let workflow = adapter.parse_workflow_telemetry(&json_data).unwrap();
+        let pipeline = &workflow.pipelines[0];
+
+        let graph = adapter.step_dependency_graph(pipeline);
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph["fan-out"], ["branch-a", "branch-b", "branch-c"]);
+        assert!(graph["prepare"].is_empty());
+        assert!(graph["fan-in"].is_empty());
+        assert!(graph["branch-a"].is_empty());
+
+        let groups = adapter.parallel_groups(pipeline);
+        assert_eq!(groups, vec![vec!["branch-a", "branch-b", "branch-c"]]);
+    }
+
     #[test]
     fn test_clear() {
         let mut adapter = OrchestratorAdapter::new("orchestrator-1");
@@ -1170,4 +1929,70 @@ mod tests {
         assert!(adapter.workflows().is_empty());
         assert_eq!(adapter.stats().total_workflows, 0);
     }
+
+    #[test]
+    fn test_enums_round_trip_through_strings() {
+        for value in [
+            WorkflowStatus::Pending,
+            WorkflowStatus::Running,
+            WorkflowStatus::Completed,
+            WorkflowStatus::Failed,
+            WorkflowStatus::Cancelled,
+            WorkflowStatus::Timeout,
+            WorkflowStatus::Paused,
+        ] {
+            let parsed: WorkflowStatus = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<WorkflowStatus>().is_err());
+
+        for value in [
+            PipelineStatus::Pending,
+            PipelineStatus::Running,
+            PipelineStatus::Completed,
+            PipelineStatus::Failed,
+            PipelineStatus::Skipped,
+            PipelineStatus::Retried,
+        ] {
+            let parsed: PipelineStatus = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<PipelineStatus>().is_err());
+
+        for value in [
+            StepType::LlmCompletion,
+            StepType::LlmChat,
+            StepType::LlmEmbedding,
+            StepType::Transform,
+            StepType::ApiCall,
+            StepType::Database,
+            StepType::Cache,
+            StepType::Condition,
+            StepType::Parallel,
+            StepType::Loop,
+        ] {
+            let parsed: StepType = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        let custom = StepType::Custom("my_custom".to_string());
+        let parsed: StepType = custom.to_string().parse().unwrap();
+        assert_eq!(parsed, custom);
+
+        for value in [
+            StepStatus::Pending,
+            StepStatus::Running,
+            StepStatus::Completed,
+            StepStatus::Failed,
+            StepStatus::Skipped,
+            StepStatus::Waiting,
+        ] {
+            let parsed: StepStatus = value.to_string().parse().unwrap();
+            assert_eq!(parsed, value);
+            assert_eq!(serde_json::to_value(&value).unwrap(), value.to_string());
+        }
+        assert!("bogus".parse::<StepStatus>().is_err());
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! String conversions for adapter enums.
+//!
+//! Upstream systems report statuses and kinds as `snake_case` strings.
+//! [`impl_variant_str!`] gives an enum `FromStr` and `Display` impls that use
+//! the same spelling as its `#[serde(rename_all = "snake_case")]` attribute,
+//! so adapters parse with `str::parse` and values round-trip through
+//! `to_string()`.
+
+use thiserror::Error;
+
+/// Error returned when a string does not name a known enum variant.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown {kind}: '{value}'")]
+pub struct ParseVariantError {
+    /// Name of the enum being parsed
+    pub kind: &'static str,
+    /// The unrecognized input
+    pub value: String,
+}
+
+impl ParseVariantError {
+    /// Create an error for an unrecognized `value` of enum `kind`.
+    pub fn new(kind: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            kind,
+            value: value.into(),
+        }
+    }
+}
+
+/// Implement `FromStr` and `Display` for a unit-variant enum.
+///
+/// Pass `custom = Variant` for enums with a `Variant(String)` catch-all: it
+/// parses any unknown string and displays its inner value. Enums without one
+/// reject unknown strings with [`ParseVariantError`].
+macro_rules! impl_variant_str {
+    ($ty:ident { $($variant:ident => $name:literal),+ $(,)? } $(, custom = $custom:ident)?) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str($name),)+
+                    $(Self::$custom(name) => f.write_str(name),)?
+                }
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = $crate::upstream::parse::ParseVariantError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                match s {
+                    $($name => Ok(Self::$variant),)+
+                    other => impl_variant_str!(@unknown $ty, other $(, $custom)?),
+                }
+            }
+        }
+    };
+    (@unknown $ty:ident, $other:ident, $custom:ident) => {
+        Ok(Self::$custom($other.to_string()))
+    };
+    (@unknown $ty:ident, $other:ident) => {
+        Err($crate::upstream::parse::ParseVariantError::new(stringify!($ty), $other))
+    };
+}
+
+pub(crate) use impl_variant_str;
@@ -0,0 +1,186 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shared retry with exponential backoff for upstream adapter calls.
+//!
+//! Export paths wrap their network calls in [`retry_with_backoff`], which
+//! retries only errors that report themselves as transient through
+//! [`Retryable`] and fails fast on everything else.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use llm_observatory_adapters::upstream::retry::{retry_with_backoff, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! let policy = RetryPolicy::new(5)
+//!     .with_base_delay(Duration::from_millis(50))
+//!     .with_max_delay(Duration::from_secs(2));
+//!
+//! let accepted = retry_with_backoff(&policy, || client.post_spans(endpoint, &spans)).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Errors that can tell whether retrying the failed call may succeed.
+pub trait Retryable {
+    /// Return `true` for transient failures (network errors, 5xx responses).
+    fn is_retryable(&self) -> bool;
+}
+
+/// How often and how patiently to retry a failed call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (at least one is always made)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles on each further retry
+    pub base_delay: Duration,
+    /// Upper bound on any single delay
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy making up to `max_attempts` attempts with default delays.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// A policy that makes a single attempt.
+    pub fn no_retry() -> Self {
+        Self::new(1)
+    }
+
+    /// Set the delay before the first retry.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound on any single delay.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Delay to wait after the given failed attempt (1-based).
+    ///
+    /// The exponential delay is capped at `max_delay`, then jittered down to
+    /// between half and all of it so concurrent callers spread out.
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        delay.mul_f64(0.5 + jitter() * 0.5)
+    }
+}
+
+/// Uniform value in `[0, 1)` from the randomly seeded std hasher.
+fn jitter() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Run `operation`, retrying retryable errors according to `policy`.
+///
+/// Returns the first success, the first non-retryable error, or the last
+/// error once `max_attempts` attempts have been made.
+pub async fn retry_with_backoff<F, Fut, T, E>(
+    policy: &RetryPolicy,
+    mut operation: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Retryable,
+{
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) if err.is_retryable() && attempt < policy.max_attempts => {
+                tokio::time::sleep(policy.delay_for(attempt)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Debug, PartialEq)]
+    struct TestError {
+        transient: bool,
+    }
+
+    impl Retryable for TestError {
+        fn is_retryable(&self) -> bool {
+            self.transient
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts).with_base_delay(Duration::from_millis(1))
+    }
+
+    #[test]
+    fn test_delay_for_backs_off_and_caps() {
+        let policy = RetryPolicy::new(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500));
+
+        for (attempt, full) in [(1, 100), (2, 200), (3, 400), (4, 500), (40, 500)] {
+            let delay = policy.delay_for(attempt);
+            assert!(delay >= Duration::from_millis(full / 2), "{:?}", delay);
+            assert!(delay <= Duration::from_millis(full), "{:?}", delay);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_attempts() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = retry_with_backoff(&fast_policy(3), || {
+            calls.set(calls.get() + 1);
+            async { Err(TestError { transient: true }) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_fails_fast_on_permanent_error() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = retry_with_backoff(&fast_policy(5), || {
+            calls.set(calls.get() + 1);
+            async { Err(TestError { transient: false }) }
+        })
+        .await;
+
+        assert_eq!(result, Err(TestError { transient: false }));
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -0,0 +1,517 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shared tail-based sampling for upstream adapters.
+//!
+//! Adapters describe a finished unit of work (an inference, a workflow, a
+//! span) as a [`SamplingContext`] and ask a [`Sampler`] whether to keep it.
+//! [`TailSampler`] is the threshold-based implementation the adapters hold,
+//! so sampling can be configured once and reused across adapters.
+//!
+//! Besides fixed thresholds, [`TailSampler::with_latency_percentile`] samples
+//! work slower than the running p95/p99 of recently seen latencies, so
+//! relative outliers are caught even when absolute latency is low.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use llm_observatory_adapters::upstream::sampling::{Sampler, SamplingContext, TailSampler};
+//!
+//! let sampler = TailSampler::new()
+//!     .with_latency_ms(5000)
+//!     .with_cost_usd(1.0)
+//!     .with_normal_sample_rate(0.01);
+//!
+//! let ctx = SamplingContext {
+//!     latency_ms: Some(7500),
+//!     ..Default::default()
+//! };
+//! assert!(sampler.should_sample(&ctx));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Default number of recent latencies the rolling percentile covers.
+pub const DEFAULT_LATENCY_WINDOW: usize = 1000;
+
+/// Latencies needed before the rolling percentile threshold applies.
+pub const MIN_PERCENTILE_SAMPLES: usize = 20;
+
+/// Observed properties of a unit of work, used to make a sampling decision.
+///
+/// Unknown values are left as `None` and never trigger a threshold.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SamplingContext {
+    /// End-to-end latency in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Total cost in USD
+    pub cost_usd: Option<f64>,
+    /// Total tokens consumed
+    pub token_count: Option<u64>,
+    /// Whether the work failed
+    pub error: bool,
+    /// Stable key (e.g. a request ID) for deterministic rate-based sampling
+    pub key: Option<String>,
+}
+
+/// Rolling latency percentile used as an adaptive sampling threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LatencyPercentile {
+    /// 95th percentile
+    P95,
+    /// 99th percentile
+    P99,
+}
+
+impl LatencyPercentile {
+    /// The percentile as a quantile in 0.0-1.0.
+    pub fn quantile(self) -> f64 {
+        match self {
+            Self::P95 => 0.95,
+            Self::P99 => 0.99,
+        }
+    }
+}
+
+/// Current rolling latency percentiles, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    /// 95th percentile latency
+    pub p95_ms: u64,
+    /// 99th percentile latency
+    pub p99_ms: u64,
+    /// Number of latencies the percentiles were computed over
+    pub samples: usize,
+}
+
+impl LatencyPercentiles {
+    /// The latency at `percentile`.
+    pub fn threshold_ms(&self, percentile: LatencyPercentile) -> u64 {
+        match percentile {
+            LatencyPercentile::P95 => self.p95_ms,
+            LatencyPercentile::P99 => self.p99_ms,
+        }
+    }
+}
+
+/// Recently observed latencies, behind a lock so sampling can stay `&self`.
+///
+/// This is runtime state rather than configuration: it is not serialized and
+/// is ignored when comparing samplers.
+#[derive(Debug, Default)]
+struct LatencyWindow(Mutex<WindowState>);
+
+/// The window's latencies in arrival order, plus the same values kept sorted
+/// so percentiles are a lookup rather than a sort.
+#[derive(Debug, Clone, Default)]
+struct WindowState {
+    recent: VecDeque<u64>,
+    sorted: Vec<u64>,
+}
+
+impl WindowState {
+    fn record(&mut self, latency_ms: u64, capacity: usize) {
+        self.recent.push_back(latency_ms);
+        let index = self.sorted.partition_point(|&ms| ms < latency_ms);
+        self.sorted.insert(index, latency_ms);
+        while self.recent.len() > capacity.max(1) {
+            if let Some(oldest) = self.recent.pop_front() {
+                if let Ok(index) = self.sorted.binary_search(&oldest) {
+                    self.sorted.remove(index);
+                }
+            }
+        }
+    }
+
+    /// Nearest-rank percentiles of the window, once it holds enough samples.
+    fn percentiles(&self) -> Option<LatencyPercentiles> {
+        let sorted = &self.sorted;
+        if sorted.len() < MIN_PERCENTILE_SAMPLES {
+            return None;
+        }
+        let rank = |q: f64| {
+            let index = (q * sorted.len() as f64).ceil() as usize;
+            sorted[index.saturating_sub(1).min(sorted.len() - 1)]
+        };
+        Some(LatencyPercentiles {
+            p95_ms: rank(LatencyPercentile::P95.quantile()),
+            p99_ms: rank(LatencyPercentile::P99.quantile()),
+            samples: sorted.len(),
+        })
+    }
+
+    fn exceeds(&self, latency_ms: u64, percentile: LatencyPercentile) -> bool {
+        self.percentiles()
+            .is_some_and(|percentiles| latency_ms > percentiles.threshold_ms(percentile))
+    }
+}
+
+impl LatencyWindow {
+    fn lock(&self) -> std::sync::MutexGuard<'_, WindowState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record(&self, latency_ms: u64, capacity: usize) {
+        self.lock().record(latency_ms, capacity);
+    }
+
+    fn percentiles(&self) -> Option<LatencyPercentiles> {
+        self.lock().percentiles()
+    }
+
+    fn exceeds(&self, latency_ms: u64, percentile: LatencyPercentile) -> bool {
+        self.lock().exceeds(latency_ms, percentile)
+    }
+
+    /// Whether `latency_ms` exceeds the window's `percentile` before it is
+    /// added, then add it, without releasing the lock in between.
+    fn check_and_record(
+        &self,
+        latency_ms: u64,
+        percentile: LatencyPercentile,
+        capacity: usize,
+    ) -> bool {
+        let mut window = self.lock();
+        let exceeds = window.exceeds(latency_ms, percentile);
+        window.record(latency_ms, capacity);
+        exceeds
+    }
+}
+
+impl Clone for LatencyWindow {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.lock().clone()))
+    }
+}
+
+/// Decides whether a unit of work should be kept.
+pub trait Sampler {
+    /// Return `true` if the work described by `ctx` should be sampled.
+    fn should_sample(&self, ctx: &SamplingContext) -> bool;
+}
+
+/// Threshold-based tail sampler.
+///
+/// Errors are always sampled. Otherwise work is sampled when it exceeds any
+/// configured threshold, or for a deterministic fraction of the remaining
+/// ("normal") work selected by hashing [`SamplingContext::key`].
+///
+/// With a [`LatencyPercentile`] set, every latency seen by
+/// [`Sampler::should_sample`] is also recorded in a rolling window, and work
+/// slower than that percentile of the window (before it was added) is
+/// sampled. Callers that decide more than once per unit of work should
+/// record it once with [`TailSampler::record_latency`] and decide with
+/// [`TailSampler::matches`], which leaves the window alone.
+///
+/// Samplers compare equal when their configuration does; the window is not
+/// compared.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TailSampler {
+    /// Sample work slower than this (milliseconds)
+    pub latency_ms: Option<u64>,
+    /// Sample work costing more than this (USD)
+    pub cost_usd: Option<f64>,
+    /// Sample work using more tokens than this
+    pub token_count: Option<u64>,
+    /// Fraction (0.0-1.0) of otherwise-normal work to sample
+    pub sample_rate_for_normal: f64,
+    /// Sample work slower than this rolling latency percentile
+    #[serde(default)]
+    pub latency_percentile: Option<LatencyPercentile>,
+    /// Number of recent latencies the percentile covers
+    /// (default [`DEFAULT_LATENCY_WINDOW`])
+    #[serde(default)]
+    pub latency_window: Option<usize>,
+    /// Recently observed latencies
+    #[serde(skip)]
+    recent_latencies: LatencyWindow,
+}
+
+impl TailSampler {
+    /// Create a sampler with no thresholds, which samples only errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample work slower than `latency_ms`.
+    pub fn with_latency_ms(mut self, latency_ms: u64) -> Self {
+        self.latency_ms = Some(latency_ms);
+        self
+    }
+
+    /// Sample work costing more than `cost_usd`.
+    pub fn with_cost_usd(mut self, cost_usd: f64) -> Self {
+        self.cost_usd = Some(cost_usd);
+        self
+    }
+
+    /// Sample work using more than `token_count` tokens.
+    pub fn with_token_count(mut self, token_count: u64) -> Self {
+        self.token_count = Some(token_count);
+        self
+    }
+
+    /// Sample this fraction (0.0-1.0) of otherwise-normal work.
+    pub fn with_normal_sample_rate(mut self, rate: f64) -> Self {
+        self.sample_rate_for_normal = rate;
+        self
+    }
+
+    /// Sample work slower than the rolling `percentile` of recent latencies.
+    pub fn with_latency_percentile(mut self, percentile: LatencyPercentile) -> Self {
+        self.latency_percentile = Some(percentile);
+        self
+    }
+
+    /// Compute the rolling percentile over the last `samples` latencies.
+    pub fn with_latency_window(mut self, samples: usize) -> Self {
+        self.latency_window = Some(samples);
+        self
+    }
+
+    /// Current rolling latency percentiles, once at least
+    /// [`MIN_PERCENTILE_SAMPLES`] latencies have been seen.
+    pub fn latency_percentiles(&self) -> Option<LatencyPercentiles> {
+        self.recent_latencies.percentiles()
+    }
+
+    /// The rolling latency threshold currently in effect, if any.
+    pub fn current_latency_threshold_ms(&self) -> Option<u64> {
+        let percentile = self.latency_percentile?;
+        Some(self.latency_percentiles()?.threshold_ms(percentile))
+    }
+
+    /// Add `latency_ms` to the rolling window without making a decision.
+    ///
+    /// Does nothing unless a [`LatencyPercentile`] is set.
+    pub fn record_latency(&self, latency_ms: u64) {
+        if self.latency_percentile.is_some() {
+            self.recent_latencies
+                .record(latency_ms, self.latency_window_capacity());
+        }
+    }
+
+    /// Whether the work described by `ctx` should be sampled, judged against
+    /// the current window without recording its latency.
+    pub fn matches(&self, ctx: &SamplingContext) -> bool {
+        let slower_than_percentile = match (ctx.latency_ms, self.latency_percentile) {
+            (Some(latency_ms), Some(percentile)) => {
+                self.recent_latencies.exceeds(latency_ms, percentile)
+            }
+            _ => false,
+        };
+        slower_than_percentile || self.exceeds_thresholds(ctx)
+    }
+
+    fn latency_window_capacity(&self) -> usize {
+        self.latency_window.unwrap_or(DEFAULT_LATENCY_WINDOW)
+    }
+
+    /// Whether `ctx` is an error, exceeds a fixed threshold or falls within
+    /// the normal sample rate.
+    fn exceeds_thresholds(&self, ctx: &SamplingContext) -> bool {
+        fn exceeds<T: PartialOrd>(value: Option<T>, threshold: Option<T>) -> bool {
+            matches!((value, threshold), (Some(v), Some(t)) if v > t)
+        }
+
+        ctx.error
+            || exceeds(ctx.latency_ms, self.latency_ms)
+            || exceeds(ctx.cost_usd, self.cost_usd)
+            || exceeds(ctx.token_count, self.token_count)
+            || self.samples_normal(ctx.key.as_deref())
+    }
+
+    /// Whether `key` falls within the normal sample rate.
+    ///
+    /// Uses FNV-1a so the same key always gives the same answer, across
+    /// processes and Rust versions. Work without a key is never rate-sampled.
+    fn samples_normal(&self, key: Option<&str>) -> bool {
+        let Some(key) = key else {
+            return false;
+        };
+        if self.sample_rate_for_normal <= 0.0 {
+            return false;
+        }
+        let hash = key.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+        ((hash % 10_000) as f64) < self.sample_rate_for_normal * 10_000.0
+    }
+}
+
+impl PartialEq for TailSampler {
+    fn eq(&self, other: &Self) -> bool {
+        self.latency_ms == other.latency_ms
+            && self.cost_usd == other.cost_usd
+            && self.token_count == other.token_count
+            && self.sample_rate_for_normal == other.sample_rate_for_normal
+            && self.latency_percentile == other.latency_percentile
+            && self.latency_window == other.latency_window
+    }
+}
+
+impl Sampler for TailSampler {
+    fn should_sample(&self, ctx: &SamplingContext) -> bool {
+        // Checked and recorded first so every latency is recorded, even for
+        // work that another rule would sample anyway
+        let slower_than_percentile = match (ctx.latency_ms, self.latency_percentile) {
+            (Some(latency_ms), Some(percentile)) => self.recent_latencies.check_and_record(
+                latency_ms,
+                percentile,
+                self.latency_window_capacity(),
+            ),
+            _ => false,
+        };
+
+        slower_than_percentile || self.exceeds_thresholds(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_sampler_thresholds() {
+        let sampler = TailSampler::new()
+            .with_latency_ms(1000)
+            .with_cost_usd(0.5)
+            .with_token_count(5000);
+
+        assert!(!sampler.should_sample(&SamplingContext::default()));
+        assert!(sampler.should_sample(&SamplingContext {
+            error: true,
+            ..Default::default()
+        }));
+        assert!(sampler.should_sample(&SamplingContext {
+            latency_ms: Some(1001),
+            ..Default::default()
+        }));
+        assert!(sampler.should_sample(&SamplingContext {
+            cost_usd: Some(0.75),
+            ..Default::default()
+        }));
+        assert!(sampler.should_sample(&SamplingContext {
+            token_count: Some(6000),
+            ..Default::default()
+        }));
+        assert!(!sampler.should_sample(&SamplingContext {
+            latency_ms: Some(1000),
+            cost_usd: Some(0.5),
+            token_count: Some(5000),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_tail_sampler_unset_thresholds_never_trigger() {
+        let sampler = TailSampler::new();
+        assert!(!sampler.should_sample(&SamplingContext {
+            latency_ms: Some(u64::MAX),
+            cost_usd: Some(1e9),
+            token_count: Some(u64::MAX),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_tail_sampler_normal_rate_is_deterministic() {
+        let sampler = TailSampler::new().with_normal_sample_rate(0.5);
+        let ctx = |i: usize| SamplingContext {
+            key: Some(format!("req-{}", i)),
+            ..Default::default()
+        };
+
+        let first: Vec<bool> = (0..100).map(|i| sampler.should_sample(&ctx(i))).collect();
+        let second: Vec<bool> = (0..100).map(|i| sampler.should_sample(&ctx(i))).collect();
+        assert_eq!(first, second);
+        assert!(first.iter().any(|&s| s) && first.iter().any(|&s| !s));
+
+        let all = TailSampler::new().with_normal_sample_rate(1.0);
+        assert!((0..100).all(|i| all.should_sample(&ctx(i))));
+        assert!(!all.should_sample(&SamplingContext::default()));
+    }
+
+    #[test]
+    fn test_latency_percentile_threshold_adapts() {
+        let sampler = TailSampler::new()
+            .with_latency_percentile(LatencyPercentile::P95)
+            .with_latency_window(100);
+        let latency = |ms: u64| SamplingContext {
+            latency_ms: Some(ms),
+            ..Default::default()
+        };
+
+        // Not enough history yet
+        assert!(!sampler.should_sample(&latency(500)));
+        assert_eq!(sampler.current_latency_threshold_ms(), None);
+
+        // Fast traffic: 50-149ms, so even 200ms is a relative outlier
+        for ms in 50..149 {
+            sampler.should_sample(&latency(ms));
+        }
+        let before = sampler.latency_percentiles().unwrap();
+        assert_eq!(before.samples, 100);
+        assert!(before.p95_ms < 200 && before.p95_ms <= before.p99_ms);
+        assert!(sampler.should_sample(&latency(200)));
+        assert!(!sampler.should_sample(&latency(60)));
+
+        // Traffic shifts slower; the window forgets the fast requests
+        for i in 0..100 {
+            sampler.should_sample(&latency(1000 + i * 10));
+        }
+        let after = sampler.current_latency_threshold_ms().unwrap();
+        assert!(after >= 1900, "threshold {} did not adapt", after);
+        assert!(!sampler.should_sample(&latency(200)));
+        assert!(sampler.should_sample(&latency(5000)));
+
+        let p99 = TailSampler::new().with_latency_percentile(LatencyPercentile::P99);
+        assert_eq!(p99.latency_percentiles(), None);
+    }
+
+    #[test]
+    fn test_matches_does_not_record() {
+        let sampler = TailSampler::new()
+            .with_latency_percentile(LatencyPercentile::P95)
+            .with_latency_window(100);
+        for ms in 1..=100 {
+            sampler.record_latency(ms);
+        }
+        let before = sampler.latency_percentiles().unwrap();
+        assert_eq!(before.p95_ms, 95);
+
+        let slow = SamplingContext {
+            latency_ms: Some(500),
+            ..Default::default()
+        };
+        for _ in 0..10 {
+            assert!(sampler.matches(&slow));
+        }
+        assert_eq!(sampler.latency_percentiles(), Some(before));
+
+        // The window stays sorted as old latencies are evicted
+        for ms in (101..=150).rev() {
+            sampler.record_latency(ms);
+        }
+        let after = sampler.latency_percentiles().unwrap();
+        assert_eq!(after.samples, 100);
+        assert_eq!(after.p95_ms, 145);
+        assert_eq!(after.p99_ms, 149);
+    }
+
+    #[test]
+    fn test_samplers_compare_by_configuration() {
+        let sampler = TailSampler::new()
+            .with_latency_percentile(LatencyPercentile::P99)
+            .with_latency_ms(1000);
+        let busy = sampler.clone();
+        for ms in 0..50 {
+            busy.record_latency(ms);
+        }
+        assert_eq!(sampler, busy);
+        assert_ne!(sampler, sampler.clone().with_latency_window(10));
+    }
+}
@@ -12,6 +12,7 @@
 //! - Span data validation against schemas
 //! - Schema versioning support
 //! - Compatibility checking
+//! - Cached registration through a pluggable [`SchemaRegistryClient`]
 //!
 //! # Example
 //!
@@ -27,12 +28,15 @@
 //! }
 //! ```
 
+use jsonschema::{error::ValidationErrorKind, JSONSchema};
+use once_cell::sync::Lazy;
 use schema_registry_core::{
-    CompatibilityMode, RegisteredSchema, SchemaInput, SchemaMetadata, SchemaState,
-    SemanticVersion, SerializationFormat,
+    CompatibilityMode, RegisteredSchema, SchemaInput, SchemaMetadata, SchemaState, SemanticVersion,
+    SerializationFormat,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use thiserror::Error;
 
 /// Errors that can occur during schema operations.
@@ -97,6 +101,248 @@ pub struct SchemaRef {
     pub full_name: String,
 }
 
+/// JSON Schema for LLM Observatory spans.
+const SPAN_SCHEMA: &str = r#"{
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "type": "object",
+    "required": ["span_id", "trace_id", "name", "provider", "model", "input", "latency"],
+    "properties": {
+        "span_id": {"type": "string"},
+        "trace_id": {"type": "string"},
+        "parent_span_id": {"type": ["string", "null"]},
+        "name": {"type": "string"},
+        "provider": {"type": "string"},
+        "model": {"type": "string"},
+        "input": {"type": "object"},
+        "output": {"type": ["object", "null"]},
+        "token_usage": {
+            "type": ["object", "null"],
+            "properties": {
+                "prompt_tokens": {"type": "integer", "minimum": 0},
+                "completion_tokens": {"type": "integer", "minimum": 0},
+                "total_tokens": {"type": "integer", "minimum": 0}
+            }
+        },
+        "cost": {
+            "type": ["object", "null"],
+            "properties": {
+                "amount_usd": {"type": "number", "minimum": 0}
+            }
+        },
+        "latency": {
+            "type": "object",
+            "required": ["total_ms", "start_time", "end_time"],
+            "properties": {
+                "total_ms": {"type": "integer", "minimum": 0},
+                "ttft_ms": {"type": ["integer", "null"], "minimum": 0},
+                "start_time": {"type": "string", "format": "date-time"},
+                "end_time": {"type": "string", "format": "date-time"}
+            }
+        },
+        "status": {"type": "string", "enum": ["OK", "ERROR", "UNSET"]}
+    }
+}"#;
+
+/// JSON Schema for the span JSON emitted by the adapters' `*_to_span_json`
+/// exporters and `ExecutionResult::to_otel_spans`.
+const TELEMETRY_SPAN_SCHEMA: &str = r#"{
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "type": "object",
+    "required": ["span_id", "name", "start_time"],
+    "properties": {
+        "trace_id": {"type": ["string", "null"]},
+        "span_id": {"type": "string", "minLength": 1},
+        "parent_span_id": {"type": ["string", "null"]},
+        "name": {"type": "string", "minLength": 1},
+        "start_time": {"type": "string", "format": "date-time"},
+        "end_time": {"type": ["string", "null"], "format": "date-time"},
+        "duration_ms": {"type": ["integer", "null"], "minimum": 0},
+        "status": {"type": "string", "enum": ["ok", "error", "unset"]},
+        "status_code": {"type": ["integer", "null"]},
+        "attributes": {"type": "object"},
+        "children": {"type": "array"}
+    }
+}"#;
+
+/// JSON Schema for agentic execution spans (`ExecutionSpan` in the core crate).
+const EXECUTION_SPAN_SCHEMA: &str = r#"{
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "type": "object",
+    "required": ["span_id", "execution_id", "parent_span_id", "kind", "repo_name", "status", "start_time"],
+    "properties": {
+        "span_id": {"type": "string", "minLength": 1},
+        "execution_id": {"type": "string", "minLength": 1},
+        "parent_span_id": {"type": "string"},
+        "kind": {"type": "string", "enum": ["repo", "agent"]},
+        "repo_name": {"type": "string"},
+        "agent_name": {"type": ["string", "null"]},
+        "status": {"type": "string", "enum": ["RUNNING", "COMPLETED", "FAILED", "CANCELLED"]},
+        "start_time": {"type": "string", "format": "date-time"},
+        "end_time": {"type": ["string", "null"], "format": "date-time"},
+        "duration_ms": {"type": ["integer", "null"], "minimum": 0},
+        "artifacts": {"type": "array"},
+        "events": {"type": "array"},
+        "attributes": {"type": "object"},
+        "error_message": {"type": ["string", "null"]}
+    }
+}"#;
+
+/// Compile one of the built-in schema constants above.
+///
+/// These are fixed at build time, so failing to compile is a bug, not an
+/// input error.
+fn compile_builtin_schema(schema_content: &str) -> JSONSchema {
+    let schema: serde_json::Value =
+        serde_json::from_str(schema_content).expect("built-in schema is valid JSON");
+    JSONSchema::compile(&schema).expect("built-in schema is a valid JSON Schema")
+}
+
+/// [`SPAN_SCHEMA`], compiled once on first use
+static SPAN_VALIDATOR: Lazy<JSONSchema> = Lazy::new(|| compile_builtin_schema(SPAN_SCHEMA));
+
+/// [`TELEMETRY_SPAN_SCHEMA`], compiled once on first use
+static TELEMETRY_SPAN_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_builtin_schema(TELEMETRY_SPAN_SCHEMA));
+
+/// [`EXECUTION_SPAN_SCHEMA`], compiled once on first use
+static EXECUTION_SPAN_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_builtin_schema(EXECUTION_SPAN_SCHEMA));
+
+/// Run a compiled schema over `data`, reporting each violation with the
+/// JSON pointer of the offending value.
+fn validate_compiled(compiled: &JSONSchema, data: &serde_json::Value) -> ValidationResult {
+    let errors: Vec<ValidationError> = match compiled.validate(data) {
+        Ok(()) => Vec::new(),
+        Err(violations) => violations
+            .map(|violation| {
+                let pointer = violation.instance_path.to_string();
+                ValidationError {
+                    message: violation.to_string(),
+                    field_path: (!pointer.is_empty()).then_some(pointer),
+                    code: schema_error_code(&violation.kind).to_string(),
+                }
+            })
+            .collect(),
+    };
+
+    ValidationResult {
+        is_valid: errors.is_empty(),
+        errors,
+        warnings: vec![],
+    }
+}
+
+/// Map a JSON Schema violation to a validation error code.
+fn schema_error_code(kind: &ValidationErrorKind) -> &'static str {
+    match kind {
+        ValidationErrorKind::Required { .. } => "REQUIRED_FIELD_MISSING",
+        ValidationErrorKind::Type { .. } => "INVALID_TYPE",
+        ValidationErrorKind::Enum { .. } => "INVALID_ENUM",
+        ValidationErrorKind::Minimum { .. } | ValidationErrorKind::ExclusiveMinimum { .. } => {
+            "INVALID_VALUE"
+        }
+        ValidationErrorKind::Format { .. } => "INVALID_FORMAT",
+        _ => "SCHEMA_VIOLATION",
+    }
+}
+
+/// Warnings for LLM span JSON that is valid but likely misconfigured.
+fn span_warnings(json_data: &serde_json::Value) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let token_usage = json_data.get("token_usage").filter(|v| !v.is_null());
+
+    let is_embedding = json_data
+        .get("name")
+        .and_then(|v| v.as_str())
+        .is_some_and(|name| name.to_lowercase().contains("embed"));
+    let completion_tokens = token_usage
+        .and_then(|usage| usage.get("completion_tokens"))
+        .and_then(|v| v.as_i64());
+    if completion_tokens == Some(0) && !is_embedding {
+        warnings.push("token_usage.completion_tokens is 0 on a non-embedding span".to_string());
+    }
+
+    if token_usage.is_some() && json_data.get("cost").filter(|v| !v.is_null()).is_none() {
+        warnings.push("token_usage is present but cost is missing".to_string());
+    }
+
+    let span_id = json_data.get("span_id").and_then(|v| v.as_str());
+    let parent_span_id = json_data.get("parent_span_id").and_then(|v| v.as_str());
+    if span_id.is_some() && span_id == parent_span_id {
+        warnings.push("parent_span_id is the span's own span_id".to_string());
+    }
+
+    warnings
+}
+
+/// Client for a schema registry that [`SchemaAdapter::register_or_get`]
+/// registers schemas with.
+pub trait SchemaRegistryClient: Send + Sync + std::fmt::Debug {
+    /// Look up the current registration of `namespace.name`, if any.
+    fn lookup(&self, namespace: &str, name: &str) -> Result<Option<SchemaRef>>;
+
+    /// Register `input` and return a reference to the registered schema.
+    fn register(&self, input: SchemaInput) -> Result<SchemaRef>;
+}
+
+/// In-memory schema registry for tests and local development.
+///
+/// Registering a name again bumps its major version. Call counters let
+/// tests check how often the registry was actually consulted.
+#[derive(Debug, Default)]
+pub struct InMemorySchemaRegistry {
+    schemas: Mutex<HashMap<String, SchemaRef>>,
+    lookups: Mutex<usize>,
+    registrations: Mutex<usize>,
+}
+
+impl InMemorySchemaRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of `lookup` calls served.
+    pub fn lookup_count(&self) -> usize {
+        *self.lookups.lock().unwrap()
+    }
+
+    /// Number of `register` calls served.
+    pub fn registration_count(&self) -> usize {
+        *self.registrations.lock().unwrap()
+    }
+
+    fn major(version: &str) -> Option<u64> {
+        version.split('.').next()?.parse().ok()
+    }
+}
+
+impl SchemaRegistryClient for InMemorySchemaRegistry {
+    fn lookup(&self, namespace: &str, name: &str) -> Result<Option<SchemaRef>> {
+        *self.lookups.lock().unwrap() += 1;
+        let full_name = format!("{}.{}", namespace, name);
+        Ok(self.schemas.lock().unwrap().get(&full_name).cloned())
+    }
+
+    fn register(&self, input: SchemaInput) -> Result<SchemaRef> {
+        *self.registrations.lock().unwrap() += 1;
+        let full_name = format!("{}.{}", input.namespace, input.name);
+        let mut schemas = self.schemas.lock().unwrap();
+        let major = schemas
+            .get(&full_name)
+            .and_then(|existing| Self::major(&existing.version))
+            .map_or(1, |major| major + 1);
+        let schema_ref = SchemaRef {
+            namespace: input.namespace,
+            name: input.name,
+            version: format!("{}.0.0", major),
+            full_name: full_name.clone(),
+        };
+        schemas.insert(full_name, schema_ref.clone());
+        Ok(schema_ref)
+    }
+}
+
 /// Adapter for consuming schema-registry-core functionality.
 ///
 /// Provides a simplified interface for Observatory to interact with
@@ -107,6 +353,8 @@ pub struct SchemaAdapter {
     default_namespace: String,
     /// Cached schema references
     schema_cache: HashMap<String, SchemaRef>,
+    /// Registry used by `register_or_get`
+    registry: Option<Arc<dyn SchemaRegistryClient>>,
 }
 
 impl Default for SchemaAdapter {
@@ -121,6 +369,7 @@ impl SchemaAdapter {
         Self {
             default_namespace: "observatory".to_string(),
             schema_cache: HashMap::new(),
+            registry: None,
         }
     }
 
@@ -129,14 +378,49 @@ impl SchemaAdapter {
         Self {
             default_namespace: namespace.into(),
             schema_cache: HashMap::new(),
+            registry: None,
         }
     }
 
+    /// Use `registry` for `register_or_get`.
+    pub fn with_registry(mut self, registry: Arc<dyn SchemaRegistryClient>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
     /// Get the default namespace.
     pub fn default_namespace(&self) -> &str {
         &self.default_namespace
     }
 
+    /// Register `input` unless it is already registered, returning its reference.
+    ///
+    /// Results are cached by full name, so repeated calls for the same
+    /// schema don't reach the registry again. An existing registration is
+    /// reused as-is rather than re-registered.
+    pub fn register_or_get(&mut self, input: SchemaInput) -> Result<SchemaRef> {
+        let full_name = format!("{}.{}", input.namespace, input.name);
+        if let Some(schema_ref) = self.schema_cache.get(&full_name) {
+            return Ok(schema_ref.clone());
+        }
+
+        let registry = self.registry.as_ref().ok_or_else(|| {
+            SchemaAdapterError::RegistryError("no schema registry configured".to_string())
+        })?;
+        let schema_ref = match registry.lookup(&input.namespace, &input.name)? {
+            Some(existing) => existing,
+            None => registry.register(input)?,
+        };
+
+        self.schema_cache.insert(full_name, schema_ref.clone());
+        Ok(schema_ref)
+    }
+
+    /// Cached reference for `full_name` (`namespace.name`), if registered.
+    pub fn cached_schema(&self, full_name: &str) -> Option<&SchemaRef> {
+        self.schema_cache.get(full_name)
+    }
+
     /// Create a schema input for registration.
     ///
     /// This helper creates a properly formatted SchemaInput that can be
@@ -164,59 +448,237 @@ impl SchemaAdapter {
 
     /// Create a schema input for LLM span validation.
     pub fn create_span_schema_input(&self) -> SchemaInput {
-        let schema_content = r#"{
-            "$schema": "http://json-schema.org/draft-07/schema#",
-            "type": "object",
-            "required": ["span_id", "trace_id", "name", "provider", "model", "input", "latency"],
-            "properties": {
-                "span_id": {"type": "string"},
-                "trace_id": {"type": "string"},
-                "parent_span_id": {"type": ["string", "null"]},
-                "name": {"type": "string"},
-                "provider": {"type": "string"},
-                "model": {"type": "string"},
-                "input": {"type": "object"},
-                "output": {"type": ["object", "null"]},
-                "token_usage": {
-                    "type": ["object", "null"],
-                    "properties": {
-                        "prompt_tokens": {"type": "integer", "minimum": 0},
-                        "completion_tokens": {"type": "integer", "minimum": 0},
-                        "total_tokens": {"type": "integer", "minimum": 0}
-                    }
-                },
-                "cost": {
-                    "type": ["object", "null"],
-                    "properties": {
-                        "amount_usd": {"type": "number", "minimum": 0}
-                    }
-                },
-                "latency": {
-                    "type": "object",
-                    "required": ["total_ms", "start_time", "end_time"],
-                    "properties": {
-                        "total_ms": {"type": "integer", "minimum": 0},
-                        "ttft_ms": {"type": ["integer", "null"], "minimum": 0},
-                        "start_time": {"type": "string", "format": "date-time"},
-                        "end_time": {"type": "string", "format": "date-time"}
-                    }
-                },
-                "status": {"type": "string", "enum": ["OK", "ERROR", "UNSET"]}
+        self.create_schema_input("LlmSpan", SPAN_SCHEMA, "Schema for LLM Observatory spans")
+    }
+
+    /// Validate JSON data against a JSON Schema document.
+    ///
+    /// Performs full JSON Schema validation (types, enums, `required`,
+    /// `minimum`, formats, ...). Each violation is reported as a
+    /// [`ValidationError`] whose `field_path` is the JSON pointer of the
+    /// offending value (e.g. `/latency/total_ms`), or `None` for the root.
+    ///
+    /// Returns an error if `schema_content` is not a valid JSON Schema. The
+    /// schema is compiled on every call, so this suits ad-hoc schemas; the
+    /// built-in span validators below reuse schemas compiled once per process.
+    pub fn validate_against_schema(
+        &self,
+        data: &serde_json::Value,
+        schema_content: &str,
+    ) -> Result<ValidationResult> {
+        let schema: serde_json::Value = serde_json::from_str(schema_content)?;
+        let compiled = JSONSchema::compile(&schema)
+            .map_err(|e| SchemaAdapterError::ValidationFailed(format!("Invalid schema: {}", e)))?;
+
+        Ok(validate_compiled(&compiled, data))
+    }
+
+    /// Validate span JSON against the full LLM span schema.
+    ///
+    /// See [`Self::validate_against_schema`]. Use
+    /// [`Self::validate_span_json_basic`] for a cheaper required-field check.
+    /// Suspicious but legal data (zero completion tokens on a non-embedding
+    /// span, token usage without cost, a span that is its own parent) is
+    /// reported in `warnings` and does not affect `is_valid`.
+    pub fn validate_span_json(&self, json_data: &serde_json::Value) -> ValidationResult {
+        let mut result = validate_compiled(&SPAN_VALIDATOR, json_data);
+        result.warnings = span_warnings(json_data);
+        result
+    }
+
+    /// Validate span JSON produced by the telemetry exporters.
+    ///
+    /// This is the lighter OpenTelemetry-style shape emitted by
+    /// `telemetry_to_span_json`, `workflow_to_span_json`, `trace_to_span_json`
+    /// and `ExecutionResult::to_otel_spans`, as opposed to the full LLM span
+    /// checked by [`SchemaAdapter::validate_span_json`].
+    pub fn validate_telemetry_span_json(&self, json_data: &serde_json::Value) -> ValidationResult {
+        validate_compiled(&TELEMETRY_SPAN_VALIDATOR, json_data)
+    }
+
+    /// Validate span JSON in either accepted shape.
+    ///
+    /// Full LLM spans (those carrying a `latency` object) go through
+    /// [`Self::validate_span_json`]; anything else is treated as exporter
+    /// output and checked with [`Self::validate_telemetry_span_json`]. Both
+    /// shapes get the same suspicious-data warnings.
+    pub fn validate_any_span_json(&self, json_data: &serde_json::Value) -> ValidationResult {
+        if json_data.get("latency").is_some_and(|v| v.is_object()) {
+            return self.validate_span_json(json_data);
+        }
+        let mut result = self.validate_telemetry_span_json(json_data);
+        result.warnings = span_warnings(json_data);
+        result
+    }
+
+    /// Create a schema input for agentic execution span validation.
+    pub fn create_execution_span_schema_input(&self) -> SchemaInput {
+        self.create_schema_input(
+            "ExecutionSpan",
+            EXECUTION_SPAN_SCHEMA,
+            "Schema for LLM Observatory agentic execution spans",
+        )
+    }
+
+    /// Validate an `ExecutionResult` JSON payload before deserializing it.
+    ///
+    /// The repo span and every agent span are checked against the execution
+    /// span schema, then the invariants enforced by `ExecutionResult::validate`
+    /// are checked at the JSON level:
+    ///
+    /// - A repo span is present, has kind `repo` and a non-empty `parent_span_id`
+    /// - At least one agent span is present
+    /// - Every agent span resolves, through its parent agents, to the repo span
+    /// - No two agent spans share a `span_id`
+    ///
+    /// Each broken rule is reported as its own [`ValidationError`].
+    pub fn validate_execution_result_json(
+        &self,
+        json_data: &serde_json::Value,
+    ) -> ValidationResult {
+        let mut errors = Vec::new();
+        let mut error = |message: String, field_path: String, code: &str| {
+            errors.push(ValidationError {
+                message,
+                field_path: Some(field_path),
+                code: code.to_string(),
+            })
+        };
+
+        // Rule: repo span must be present and well-formed
+        let repo_span = json_data.get("repo_span").filter(|v| v.is_object());
+        let repo_span_id = repo_span
+            .and_then(|span| span.get("span_id"))
+            .and_then(|v| v.as_str());
+        match repo_span {
+            None => error(
+                "Missing required field: repo_span".to_string(),
+                "/repo_span".to_string(),
+                "MISSING_REPO_SPAN",
+            ),
+            Some(span) => {
+                if span.get("kind").and_then(|v| v.as_str()) != Some("repo") {
+                    error(
+                        "repo_span must have kind \"repo\"".to_string(),
+                        "/repo_span/kind".to_string(),
+                        "INVALID_SPAN_KIND",
+                    );
+                }
+                if span
+                    .get("parent_span_id")
+                    .and_then(|v| v.as_str())
+                    .map_or(true, str::is_empty)
+                {
+                    error(
+                        "Repo span is missing parent_span_id from caller".to_string(),
+                        "/repo_span/parent_span_id".to_string(),
+                        "MISSING_PARENT_SPAN_ID",
+                    );
+                }
+            }
+        }
+
+        // Rule: must have at least one agent span
+        let agent_spans = json_data
+            .get("agent_spans")
+            .and_then(|v| v.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if agent_spans.is_empty() {
+            error(
+                "No agent spans emitted -- execution has no evidence of agent work".to_string(),
+                "/agent_spans".to_string(),
+                "NO_AGENT_SPANS",
+            );
+        }
+
+        // Rules: agent parents resolve to the repo span, no duplicate span ids
+        let parents: HashMap<&str, &str> = agent_spans
+            .iter()
+            .filter_map(|span| {
+                let span_id = span.get("span_id")?.as_str()?;
+                Some((span_id, span.get("parent_span_id")?.as_str()?))
+            })
+            .collect();
+        let resolves_to_repo = |mut current: &str| {
+            for _ in 0..=agent_spans.len() {
+                if Some(current) == repo_span_id {
+                    return true;
+                }
+                match parents.get(current) {
+                    Some(parent) => current = *parent,
+                    None => return false,
+                }
+            }
+            false
+        };
+
+        let mut seen_ids = std::collections::HashSet::new();
+        for (index, span) in agent_spans.iter().enumerate() {
+            let parent = span.get("parent_span_id").and_then(|v| v.as_str());
+            if repo_span_id.is_some() && !parent.is_some_and(resolves_to_repo) {
+                error(
+                    format!(
+                        "Agent span {} has parent_span_id {} but expected repo span {} as ancestor",
+                        index,
+                        parent.unwrap_or("<missing>"),
+                        repo_span_id.unwrap_or_default()
+                    ),
+                    format!("/agent_spans/{}/parent_span_id", index),
+                    "INVALID_AGENT_PARENT",
+                );
             }
-        }"#;
 
-        self.create_schema_input("LlmSpan", schema_content, "Schema for LLM Observatory spans")
+            if let Some(span_id) = span.get("span_id").and_then(|v| v.as_str()) {
+                if !seen_ids.insert(span_id) {
+                    error(
+                        format!("Duplicate agent span_id: {}", span_id),
+                        format!("/agent_spans/{}/span_id", index),
+                        "DUPLICATE_SPAN_ID",
+                    );
+                }
+            }
+        }
+
+        // Structural checks for each span against the execution span schema
+        let spans = repo_span
+            .map(|span| ("/repo_span".to_string(), span))
+            .into_iter()
+            .chain(
+                agent_spans
+                    .iter()
+                    .enumerate()
+                    .map(|(index, span)| (format!("/agent_spans/{}", index), span)),
+            );
+        for (prefix, span) in spans {
+            for mut violation in validate_compiled(&EXECUTION_SPAN_VALIDATOR, span).errors {
+                violation.field_path = Some(format!(
+                    "{}{}",
+                    prefix,
+                    violation.field_path.unwrap_or_default()
+                ));
+                errors.push(violation);
+            }
+        }
+
+        ValidationResult {
+            is_valid: errors.is_empty(),
+            errors,
+            warnings: vec![],
+        }
     }
 
     /// Validate JSON data against a simple schema structure.
     ///
     /// This is a lightweight validation that checks required fields
     /// without requiring a full schema registry connection.
-    pub fn validate_span_json(&self, json_data: &serde_json::Value) -> ValidationResult {
+    pub fn validate_span_json_basic(&self, json_data: &serde_json::Value) -> ValidationResult {
         let mut errors = Vec::new();
 
         // Check required fields
-        let required_fields = ["span_id", "trace_id", "name", "provider", "model", "input", "latency"];
+        let required_fields = [
+            "span_id", "trace_id", "name", "provider", "model", "input", "latency",
+        ];
 
         for field in required_fields {
             if json_data.get(field).is_none() {
@@ -246,7 +708,8 @@ impl SchemaAdapter {
                     if let Some(total_val) = total.as_i64() {
                         if total_val < 0 {
                             errors.push(ValidationError {
-                                message: "token_usage.total_tokens must be non-negative".to_string(),
+                                message: "token_usage.total_tokens must be non-negative"
+                                    .to_string(),
                                 field_path: Some("token_usage.total_tokens".to_string()),
                                 code: "INVALID_VALUE".to_string(),
                             });
@@ -362,6 +825,43 @@ mod tests {
         assert_eq!(schema_ref.full_name, "observatory.LlmSpan");
     }
 
+    #[test]
+    fn test_register_or_get_caches() {
+        let registry = Arc::new(InMemorySchemaRegistry::new());
+        let mut adapter = SchemaAdapter::new().with_registry(registry.clone());
+
+        let first = adapter
+            .register_or_get(adapter.create_span_schema_input())
+            .unwrap();
+        assert_eq!(first.full_name, "observatory.LlmSpan");
+        assert_eq!(first.version, "1.0.0");
+        assert_eq!(registry.lookup_count(), 1);
+        assert_eq!(registry.registration_count(), 1);
+
+        let second = adapter
+            .register_or_get(adapter.create_span_schema_input())
+            .unwrap();
+        assert_eq!(second.version, first.version);
+        assert_eq!(registry.lookup_count(), 1);
+        assert_eq!(registry.registration_count(), 1);
+        assert!(adapter.cached_schema("observatory.LlmSpan").is_some());
+
+        // A fresh adapter finds the existing registration instead of re-registering
+        let mut other = SchemaAdapter::new().with_registry(registry.clone());
+        let existing = other
+            .register_or_get(other.create_span_schema_input())
+            .unwrap();
+        assert_eq!(existing.version, "1.0.0");
+        assert_eq!(registry.registration_count(), 1);
+    }
+
+    #[test]
+    fn test_register_or_get_without_registry() {
+        let mut adapter = SchemaAdapter::new();
+        let result = adapter.register_or_get(adapter.create_span_schema_input());
+        assert!(matches!(result, Err(SchemaAdapterError::RegistryError(_))));
+    }
+
     #[test]
     fn test_parse_version() {
         let version = SchemaAdapter::parse_version("1.2.3").unwrap();
@@ -390,6 +890,271 @@ mod tests {
         assert!(result.errors.is_empty());
     }
 
+    #[test]
+    fn test_validate_telemetry_span_json() {
+        let adapter = SchemaAdapter::new();
+        let span = serde_json::json!({
+            "trace_id": null,
+            "span_id": "span_123",
+            "name": "inference.openai",
+            "start_time": "2025-01-01T00:00:00Z",
+            "end_time": null,
+            "duration_ms": 1500,
+            "status": "ok",
+            "attributes": {"gateway.id": "gateway-1"}
+        });
+        let result = adapter.validate_telemetry_span_json(&span);
+        assert!(result.is_valid, "{:?}", result.errors);
+
+        let mut invalid = span.clone();
+        invalid["status"] = serde_json::json!("OK");
+        invalid.as_object_mut().unwrap().remove("name");
+        let result = adapter.validate_telemetry_span_json(&invalid);
+        let codes: Vec<&str> = result.errors.iter().map(|e| e.code.as_str()).collect();
+        assert!(codes.contains(&"REQUIRED_FIELD_MISSING"));
+        assert!(codes.contains(&"INVALID_ENUM"));
+    }
+
+    fn valid_span_json() -> serde_json::Value {
+        serde_json::json!({
+            "span_id": "span_123",
+            "trace_id": "trace_456",
+            "name": "llm.completion",
+            "provider": "openai",
+            "model": "gpt-4",
+            "input": {"type": "text", "prompt": "Hello"},
+            "latency": {
+                "total_ms": 100,
+                "start_time": "2025-01-01T00:00:00Z",
+                "end_time": "2025-01-01T00:00:00Z"
+            },
+            "status": "OK"
+        })
+    }
+
+    #[test]
+    fn test_validate_span_json_warnings() {
+        let adapter = SchemaAdapter::new();
+        assert!(adapter
+            .validate_span_json(&valid_span_json())
+            .warnings
+            .is_empty());
+
+        let mut span = valid_span_json();
+        span["token_usage"] = serde_json::json!({
+            "prompt_tokens": 12,
+            "completion_tokens": 0,
+            "total_tokens": 12
+        });
+        span["cost"] = serde_json::json!({"amount_usd": 0.0001});
+        let result = adapter.validate_span_json(&span);
+        assert!(result.is_valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("completion_tokens"));
+
+        // Embeddings legitimately produce no completion tokens
+        span["name"] = serde_json::json!("llm.embedding");
+        assert!(adapter.validate_span_json(&span).warnings.is_empty());
+
+        span["cost"] = serde_json::Value::Null;
+        span["parent_span_id"] = span["span_id"].clone();
+        let result = adapter.validate_span_json(&span);
+        assert!(result.is_valid);
+        assert_eq!(result.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_any_span_json() {
+        let adapter = SchemaAdapter::new();
+        assert!(adapter.validate_any_span_json(&valid_span_json()).is_valid);
+
+        let exported = serde_json::json!({
+            "span_id": "span-1",
+            "parent_span_id": "span-1",
+            "name": "inference.openai",
+            "start_time": "2025-01-01T00:00:00Z",
+            "status": "ok"
+        });
+        let result = adapter.validate_any_span_json(&exported);
+        assert!(result.is_valid);
+        assert_eq!(result.warnings.len(), 1);
+
+        // A full span missing its required fields is not let through as exporter output
+        let mut partial = valid_span_json();
+        partial.as_object_mut().unwrap().remove("model");
+        assert!(!adapter.validate_any_span_json(&partial).is_valid);
+    }
+
+    #[test]
+    fn test_validate_span_json_rejects_wrong_type() {
+        let adapter = SchemaAdapter::new();
+        let mut json = valid_span_json();
+        json["latency"]["total_ms"] = serde_json::json!("100");
+
+        // The basic check only looks for presence
+        assert!(adapter.validate_span_json_basic(&json).is_valid);
+
+        let result = adapter.validate_span_json(&json);
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].field_path.as_deref(),
+            Some("/latency/total_ms")
+        );
+        assert_eq!(result.errors[0].code, "INVALID_TYPE");
+    }
+
+    #[test]
+    fn test_validate_span_json_rejects_enum_and_minimum() {
+        let adapter = SchemaAdapter::new();
+        let mut json = valid_span_json();
+        json["status"] = serde_json::json!("MAYBE");
+        json["token_usage"] = serde_json::json!({"total_tokens": -1});
+
+        let result = adapter.validate_span_json(&json);
+        assert!(!result.is_valid);
+        let codes: Vec<&str> = result.errors.iter().map(|e| e.code.as_str()).collect();
+        assert!(codes.contains(&"INVALID_ENUM"));
+        assert!(codes.contains(&"INVALID_VALUE"));
+    }
+
+    #[test]
+    fn test_validate_against_invalid_schema() {
+        let adapter = SchemaAdapter::new();
+        assert!(adapter
+            .validate_against_schema(&valid_span_json(), "not json")
+            .is_err());
+    }
+
+    #[test]
+    fn test_builtin_validators_match_ad_hoc_validation() {
+        let adapter = SchemaAdapter::new();
+        let mut invalid = valid_span_json();
+        invalid["latency"]["total_ms"] = serde_json::json!(-1);
+
+        for data in [valid_span_json(), invalid] {
+            let cached = adapter.validate_span_json(&data);
+            let ad_hoc = adapter.validate_against_schema(&data, SPAN_SCHEMA).unwrap();
+            assert_eq!(cached.is_valid, ad_hoc.is_valid);
+            assert_eq!(cached.errors.len(), ad_hoc.errors.len());
+        }
+
+        let telemetry = serde_json::json!({ "span_id": 42 });
+        let cached = adapter.validate_telemetry_span_json(&telemetry);
+        let ad_hoc = adapter
+            .validate_against_schema(&telemetry, TELEMETRY_SPAN_SCHEMA)
+            .unwrap();
+        assert!(!cached.is_valid);
+        assert_eq!(cached.errors.len(), ad_hoc.errors.len());
+
+        let execution_span = serde_json::json!({ "span_id": "" });
+        let cached = validate_compiled(&EXECUTION_SPAN_VALIDATOR, &execution_span);
+        let ad_hoc = adapter
+            .validate_against_schema(&execution_span, EXECUTION_SPAN_SCHEMA)
+            .unwrap();
+        assert!(!cached.is_valid);
+        assert_eq!(cached.errors.len(), ad_hoc.errors.len());
+    }
+
+    fn execution_span_json(span_id: &str, parent: &str, kind: &str) -> serde_json::Value {
+        let mut span = serde_json::json!({
+            "span_id": span_id,
+            "execution_id": "exec-1",
+            "parent_span_id": parent,
+            "kind": kind,
+            "repo_name": "llm-observatory",
+            "status": "COMPLETED",
+            "start_time": "2025-01-01T00:00:00Z"
+        });
+        if kind == "agent" {
+            span["agent_name"] = serde_json::json!("test-agent");
+        }
+        span
+    }
+
+    #[test]
+    fn test_validate_execution_result_json_valid() {
+        let adapter = SchemaAdapter::new();
+        let json = serde_json::json!({
+            "execution_id": "exec-1",
+            "repo_span": execution_span_json("repo-1", "caller-1", "repo"),
+            "agent_spans": [execution_span_json("agent-1", "repo-1", "agent")]
+        });
+
+        let result = adapter.validate_execution_result_json(&json);
+        assert!(result.is_valid, "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_validate_execution_result_json_rules() {
+        let adapter = SchemaAdapter::new();
+
+        let no_agents = serde_json::json!({
+            "execution_id": "exec-1",
+            "repo_span": execution_span_json("repo-1", "", "repo"),
+            "agent_spans": []
+        });
+        let result = adapter.validate_execution_result_json(&no_agents);
+        let codes: Vec<&str> = result.errors.iter().map(|e| e.code.as_str()).collect();
+        assert!(!result.is_valid);
+        assert!(codes.contains(&"NO_AGENT_SPANS"));
+        assert!(codes.contains(&"MISSING_PARENT_SPAN_ID"));
+
+        let bad_agents = serde_json::json!({
+            "execution_id": "exec-1",
+            "repo_span": execution_span_json("repo-1", "caller-1", "repo"),
+            "agent_spans": [
+                execution_span_json("agent-1", "repo-1", "agent"),
+                execution_span_json("agent-1", "wrong-parent", "agent")
+            ]
+        });
+        let result = adapter.validate_execution_result_json(&bad_agents);
+        assert!(!result.is_valid);
+        assert!(result.errors.iter().any(|e| {
+            e.code == "INVALID_AGENT_PARENT"
+                && e.field_path.as_deref() == Some("/agent_spans/1/parent_span_id")
+        }));
+        assert!(result.errors.iter().any(|e| e.code == "DUPLICATE_SPAN_ID"));
+
+        let missing_repo = serde_json::json!({
+            "execution_id": "exec-1",
+            "agent_spans": [execution_span_json("agent-1", "repo-1", "agent")]
+        });
+        let result = adapter.validate_execution_result_json(&missing_repo);
+        assert!(result.errors.iter().any(|e| e.code == "MISSING_REPO_SPAN"));
+
+        let nested_agents = serde_json::json!({
+            "execution_id": "exec-1",
+            "repo_span": execution_span_json("repo-1", "caller-1", "repo"),
+            "agent_spans": [
+                execution_span_json("agent-1", "repo-1", "agent"),
+                execution_span_json("agent-2", "agent-1", "agent")
+            ]
+        });
+        let result = adapter.validate_execution_result_json(&nested_agents);
+        assert!(result.is_valid, "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_validate_execution_result_json_schema_errors() {
+        let adapter = SchemaAdapter::new();
+        let mut agent = execution_span_json("agent-1", "repo-1", "agent");
+        agent["status"] = serde_json::json!("DONE");
+        let json = serde_json::json!({
+            "execution_id": "exec-1",
+            "repo_span": execution_span_json("repo-1", "caller-1", "repo"),
+            "agent_spans": [agent]
+        });
+
+        let result = adapter.validate_execution_result_json(&json);
+        assert!(!result.is_valid);
+        assert_eq!(
+            result.errors[0].field_path.as_deref(),
+            Some("/agent_spans/0/status")
+        );
+        assert_eq!(result.errors[0].code, "INVALID_ENUM");
+    }
+
     #[test]
     fn test_validate_span_json_invalid() {
         let adapter = SchemaAdapter::new();
@@ -401,5 +1166,9 @@ mod tests {
         let result = adapter.validate_span_json(&invalid_json);
         assert!(!result.is_valid);
         assert!(!result.errors.is_empty());
+
+        let result = adapter.validate_span_json_basic(&invalid_json);
+        assert!(!result.is_valid);
+        assert!(!result.errors.is_empty());
     }
 }
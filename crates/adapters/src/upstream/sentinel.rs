@@ -10,6 +10,13 @@
 //!
 //! - Telemetry event creation from Observatory spans
 //! - Anomaly detection thresholds
+//! - Z-score latency detection against a baseline learned per model
+//! - Throughput degradation detection (tokens per second)
+//! - CUSUM change-point detection of slow latency or cost drifts
+//! - IQR (Tukey fence) latency outlier detection over batches of spans
+//! - Input/output drift detection from externally supplied embeddings
+//! - PII redaction of prompt and response text (on by default)
+//! - Allowlist/denylist redaction of span metadata and attributes
 //! - Alert event consumption
 //! - Integration with Observatory's sampling system
 //!
@@ -29,15 +36,19 @@
 //! }
 //! ```
 
+use super::latency::LatencyAdapter;
+use super::redact::{redact_text, AttributeRedactionPolicy};
+use super::sampling::{Sampler, SamplingContext, TailSampler};
+use super::stats::AdapterStats;
+use chrono::{DateTime, Utc};
+use llm_observatory_core::span::{LlmInput, LlmOutput, LlmSpan, SpanStatus};
+use llm_observatory_core::types::Provider as ObsProvider;
 use llm_sentinel_core::{
     AnomalyContext, AnomalyDetails, AnomalyEvent, AnomalyType, DetectionMethod, ModelId,
     PromptInfo, ResponseInfo, ServiceId, Severity, TelemetryEvent,
 };
-use llm_observatory_core::span::{LlmInput, LlmOutput, LlmSpan, SpanStatus};
-use llm_observatory_core::types::Provider as ObsProvider;
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -75,19 +86,51 @@ pub struct AnomalyThresholds {
     pub error_rate_threshold: f64,
     /// Token usage spike threshold (multiplier of average)
     pub token_spike_multiplier: f64,
+    /// Standard deviations above the learned mean latency that count as a spike
+    #[serde(default = "default_latency_z_score")]
+    pub latency_z_score: f64,
+    /// Flag throughput below this fraction of the baseline (0.0 - 1.0)
+    #[serde(default = "default_throughput_fraction")]
+    pub throughput_degradation_fraction: f64,
+    /// Cosine distance from the embedding centroid that counts as drift (0.0 - 2.0)
+    #[serde(default = "default_drift_distance")]
+    pub drift_cosine_distance: f64,
+}
+
+fn default_latency_z_score() -> f64 {
+    3.0
+}
+
+fn default_throughput_fraction() -> f64 {
+    0.5
+}
+
+fn default_drift_distance() -> f64 {
+    0.3
 }
 
 impl Default for AnomalyThresholds {
     fn default() -> Self {
         Self {
-            latency_threshold_ms: 5000,      // 5 seconds
-            cost_threshold_usd: 1.0,         // $1.00
-            error_rate_threshold: 0.1,       // 10%
-            token_spike_multiplier: 3.0,     // 3x average
+            latency_threshold_ms: 5000,  // 5 seconds
+            cost_threshold_usd: 1.0,     // $1.00
+            error_rate_threshold: 0.1,   // 10%
+            token_spike_multiplier: 3.0, // 3x average
+            latency_z_score: default_latency_z_score(),
+            throughput_degradation_fraction: default_throughput_fraction(),
+            drift_cosine_distance: default_drift_distance(),
         }
     }
 }
 
+impl From<&AnomalyThresholds> for TailSampler {
+    fn from(thresholds: &AnomalyThresholds) -> Self {
+        TailSampler::new()
+            .with_latency_ms(thresholds.latency_threshold_ms)
+            .with_cost_usd(thresholds.cost_threshold_usd)
+    }
+}
+
 /// Detected anomaly from Observatory data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetectedAnomaly {
@@ -107,6 +150,12 @@ pub struct DetectedAnomaly {
     pub value: f64,
     /// Threshold that was exceeded
     pub threshold: f64,
+    /// Learned baseline (e.g. mean) the value was compared against
+    #[serde(default)]
+    pub baseline: Option<f64>,
+    /// Standard deviations from the baseline, for z-score detections
+    #[serde(default)]
+    pub deviation_sigma: Option<f64>,
     /// Timestamp
     pub timestamp: DateTime<Utc>,
     /// Related span ID
@@ -128,6 +177,182 @@ pub struct AnomalyStats {
     pub error_anomalies: u64,
     /// Token usage anomalies
     pub token_anomalies: u64,
+    /// Throughput degradation anomalies
+    #[serde(default)]
+    pub throughput_anomalies: u64,
+    /// Input and output drift anomalies
+    #[serde(default)]
+    pub drift_anomalies: u64,
+}
+
+/// Minimum observations before a learned baseline is used for detection.
+const MIN_BASELINE_SAMPLES: u64 = 10;
+
+/// Running mean and variance (Welford's algorithm).
+#[derive(Debug, Clone, Default)]
+struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn std_dev(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        (self.m2 / (self.count - 1) as f64).sqrt()
+    }
+
+    /// Standard deviations `value` lies above the mean, once enough samples
+    /// have been seen and they are not all identical.
+    fn z_score(&self, value: f64) -> Option<f64> {
+        let std_dev = self.std_dev();
+        if self.count < MIN_BASELINE_SAMPLES || std_dev == 0.0 {
+            return None;
+        }
+        Some((value - self.mean) / std_dev)
+    }
+}
+
+/// Metric accumulated by the CUSUM detector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CusumMetric {
+    /// Span latency in milliseconds
+    Latency,
+    /// Span cost in USD
+    Cost,
+}
+
+/// Settings for CUSUM change-point detection.
+///
+/// Each span adds `value - target - slack` to a cumulative sum that never
+/// drops below zero. A detection fires once the sum exceeds
+/// `decision_interval`, after which the sum restarts from zero. Values that
+/// sit slightly above target on every span therefore add up, even though no
+/// single span crosses a per-span threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CusumConfig {
+    /// Metric to accumulate
+    pub metric: CusumMetric,
+    /// Expected value of the metric (milliseconds or USD)
+    pub target: f64,
+    /// Allowed deviation per span before it accumulates (the "k" parameter)
+    pub slack: f64,
+    /// Cumulative sum that triggers a detection (the "h" parameter)
+    pub decision_interval: f64,
+}
+
+/// Minimum samples before IQR fences are computed.
+const MIN_IQR_SAMPLES: usize = 4;
+
+/// Tukey fence multiplier applied to the interquartile range.
+const IQR_FENCE_MULTIPLIER: f64 = 1.5;
+
+/// Value at quantile `q` (0.0 - 1.0) of sorted samples, interpolating
+/// linearly between neighbouring ranks.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Lower and upper Tukey fences (`Q1 - 1.5 * IQR`, `Q3 + 1.5 * IQR`), once
+/// there are enough finite samples.
+fn iqr_fences(samples: &[f64]) -> Option<(f64, f64)> {
+    let mut sorted: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    if sorted.len() < MIN_IQR_SAMPLES {
+        return None;
+    }
+    sorted.sort_by(f64::total_cmp);
+
+    let q1 = quantile(&sorted, 0.25);
+    let q3 = quantile(&sorted, 0.75);
+    let iqr = q3 - q1;
+    Some((
+        q1 - IQR_FENCE_MULTIPLIER * iqr,
+        q3 + IQR_FENCE_MULTIPLIER * iqr,
+    ))
+}
+
+/// Number of recent embeddings the drift centroid is computed over.
+const DRIFT_WINDOW: usize = 100;
+
+/// Which side of the LLM call an embedding describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftKind {
+    /// Prompt embeddings
+    Input,
+    /// Response embeddings
+    Output,
+}
+
+/// Sliding window of recent embeddings with a running sum for the centroid.
+#[derive(Debug, Clone, Default)]
+struct EmbeddingWindow {
+    entries: VecDeque<(String, Vec<f32>)>,
+    sum: Vec<f64>,
+}
+
+impl EmbeddingWindow {
+    fn push(&mut self, span_id: String, embedding: Vec<f32>) {
+        if self.sum.is_empty() {
+            self.sum = vec![0.0; embedding.len()];
+        }
+        for (total, value) in self.sum.iter_mut().zip(&embedding) {
+            *total += f64::from(*value);
+        }
+        self.entries.push_back((span_id, embedding));
+
+        if self.entries.len() > DRIFT_WINDOW {
+            if let Some((_, oldest)) = self.entries.pop_front() {
+                for (total, value) in self.sum.iter_mut().zip(&oldest) {
+                    *total -= f64::from(*value);
+                }
+            }
+        }
+    }
+
+    fn dimensions(&self) -> Option<usize> {
+        (!self.sum.is_empty()).then_some(self.sum.len())
+    }
+
+    fn get(&self, span_id: &str) -> Option<&Vec<f32>> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(id, _)| id == span_id)
+            .map(|(_, embedding)| embedding)
+    }
+
+    /// Cosine distance between `embedding` and the window's centroid, once
+    /// enough embeddings have been seen.
+    fn distance_from_centroid(&self, embedding: &[f32]) -> Option<f64> {
+        if (self.entries.len() as u64) < MIN_BASELINE_SAMPLES {
+            return None;
+        }
+        let n = self.entries.len() as f64;
+        let (mut dot, mut centroid_norm, mut embedding_norm) = (0.0, 0.0, 0.0);
+        for (total, value) in self.sum.iter().zip(embedding) {
+            let centroid = total / n;
+            let value = f64::from(*value);
+            dot += centroid * value;
+            centroid_norm += centroid * centroid;
+            embedding_norm += value * value;
+        }
+        if centroid_norm == 0.0 || embedding_norm == 0.0 {
+            return None;
+        }
+        Some(1.0 - dot / (centroid_norm.sqrt() * embedding_norm.sqrt()))
+    }
 }
 
 /// Adapter for consuming llm-sentinel-core functionality.
@@ -139,6 +364,8 @@ pub struct SentinelAdapter {
     service_id: ServiceId,
     /// Anomaly detection thresholds
     thresholds: AnomalyThresholds,
+    /// Per-model overrides of `thresholds`
+    model_thresholds: HashMap<String, AnomalyThresholds>,
     /// Detected anomalies
     anomalies: Vec<DetectedAnomaly>,
     /// Statistics
@@ -147,6 +374,24 @@ pub struct SentinelAdapter {
     baseline_latency_ms: Option<f64>,
     /// Baseline token usage
     baseline_tokens: Option<f64>,
+    /// Latency distribution learned from non-anomalous spans, by model
+    latency_stats: HashMap<String, RunningStats>,
+    /// Explicit baseline throughput (tokens/second)
+    baseline_throughput: Option<f64>,
+    /// Throughput distribution learned from non-anomalous spans, by model
+    throughput_stats: HashMap<String, RunningStats>,
+    /// Recent prompt embeddings
+    input_embeddings: EmbeddingWindow,
+    /// Recent response embeddings
+    output_embeddings: EmbeddingWindow,
+    /// Whether prompt/response text is redacted in telemetry events
+    redact_pii: bool,
+    /// Redaction applied to span metadata and attributes in telemetry events
+    attribute_policy: AttributeRedactionPolicy,
+    /// CUSUM detector settings, if enabled
+    cusum: Option<CusumConfig>,
+    /// Current CUSUM cumulative sum
+    cusum_value: f64,
 }
 
 impl SentinelAdapter {
@@ -155,25 +400,42 @@ impl SentinelAdapter {
         Self {
             service_id: ServiceId::new(service_name),
             thresholds: AnomalyThresholds::default(),
+            model_thresholds: HashMap::new(),
             anomalies: Vec::new(),
             stats: AnomalyStats::default(),
             baseline_latency_ms: None,
             baseline_tokens: None,
+            latency_stats: HashMap::new(),
+            baseline_throughput: None,
+            throughput_stats: HashMap::new(),
+            input_embeddings: EmbeddingWindow::default(),
+            output_embeddings: EmbeddingWindow::default(),
+            redact_pii: true,
+            attribute_policy: AttributeRedactionPolicy::default(),
+            cusum: None,
+            cusum_value: 0.0,
         }
     }
 
     /// Create a new SentinelAdapter with custom thresholds.
-    pub fn with_thresholds(
-        service_name: impl Into<String>,
-        thresholds: AnomalyThresholds,
-    ) -> Self {
+    pub fn with_thresholds(service_name: impl Into<String>, thresholds: AnomalyThresholds) -> Self {
         Self {
             service_id: ServiceId::new(service_name),
             thresholds,
+            model_thresholds: HashMap::new(),
             anomalies: Vec::new(),
             stats: AnomalyStats::default(),
             baseline_latency_ms: None,
             baseline_tokens: None,
+            latency_stats: HashMap::new(),
+            baseline_throughput: None,
+            throughput_stats: HashMap::new(),
+            input_embeddings: EmbeddingWindow::default(),
+            output_embeddings: EmbeddingWindow::default(),
+            redact_pii: true,
+            attribute_policy: AttributeRedactionPolicy::default(),
+            cusum: None,
+            cusum_value: 0.0,
         }
     }
 
@@ -192,6 +454,20 @@ impl SentinelAdapter {
         self.thresholds = thresholds;
     }
 
+    /// Use `thresholds` instead of the defaults for spans of `model`.
+    pub fn set_model_thresholds(
+        &mut self,
+        model: impl Into<String>,
+        thresholds: AnomalyThresholds,
+    ) {
+        self.model_thresholds.insert(model.into(), thresholds);
+    }
+
+    /// Get the thresholds applied to spans of `model`.
+    pub fn thresholds_for(&self, model: &str) -> &AnomalyThresholds {
+        self.model_thresholds.get(model).unwrap_or(&self.thresholds)
+    }
+
     /// Set baseline latency for deviation detection.
     pub fn set_baseline_latency(&mut self, latency_ms: f64) {
         self.baseline_latency_ms = Some(latency_ms);
@@ -202,9 +478,77 @@ impl SentinelAdapter {
         self.baseline_tokens = Some(tokens);
     }
 
+    /// Set baseline completion throughput (tokens/second) for every model,
+    /// overriding the baselines learned from observed spans.
+    pub fn set_baseline_throughput(&mut self, tokens_per_second: f64) {
+        self.baseline_throughput = Some(tokens_per_second);
+    }
+
+    /// Baseline completion throughput (tokens/second) for `model`, if set or
+    /// learned from that model's spans.
+    pub fn baseline_throughput(&self, model: &str) -> Option<f64> {
+        self.baseline_throughput.or_else(|| {
+            self.throughput_stats
+                .get(model)
+                .filter(|stats| stats.count >= MIN_BASELINE_SAMPLES)
+                .map(|stats| stats.mean)
+        })
+    }
+
+    /// Whether PII is redacted from telemetry event text.
+    pub fn redacts_pii(&self) -> bool {
+        self.redact_pii
+    }
+
+    /// Enable or disable PII redaction of telemetry event text.
+    ///
+    /// Mirrors `ObservatoryConfigKey::EnablePiiRedaction`, which defaults to
+    /// enabled.
+    pub fn set_pii_redaction(&mut self, enabled: bool) {
+        self.redact_pii = enabled;
+    }
+
+    /// Redaction policy applied to span metadata and attributes.
+    pub fn attribute_policy(&self) -> &AttributeRedactionPolicy {
+        &self.attribute_policy
+    }
+
+    /// Replace the redaction policy for span metadata and attributes.
+    ///
+    /// Use [`AttributeRedactionPolicy::allow_all`] to forward them unchanged.
+    pub fn set_attribute_policy(&mut self, policy: AttributeRedactionPolicy) {
+        self.attribute_policy = policy;
+    }
+
+    /// Enable CUSUM change-point detection, resetting the cumulative sum.
+    pub fn set_cusum(&mut self, config: CusumConfig) {
+        self.cusum = Some(config);
+        self.cusum_value = 0.0;
+    }
+
+    /// CUSUM detector settings, if enabled.
+    pub fn cusum_config(&self) -> Option<&CusumConfig> {
+        self.cusum.as_ref()
+    }
+
+    /// Current CUSUM cumulative sum.
+    pub fn cusum_value(&self) -> f64 {
+        self.cusum_value
+    }
+
+    /// Reset the CUSUM cumulative sum to zero, e.g. after a known change
+    /// such as a deployment or model switch.
+    pub fn reset_cusum(&mut self) {
+        self.cusum_value = 0.0;
+    }
+
     /// Convert an LLM span to a Sentinel telemetry event.
+    ///
+    /// With PII redaction enabled, prompt and response text pass through
+    /// [`redact_text`] first. Span metadata and attributes are forwarded as
+    /// event metadata after the adapter's [`AttributeRedactionPolicy`].
     pub fn span_to_telemetry_event(&self, span: &LlmSpan) -> Result<TelemetryEvent> {
-        let prompt_text = self.extract_prompt_text(&span.input)?;
+        let prompt_text = self.redact(self.extract_prompt_text(&span.input)?);
         let prompt_tokens = span
             .token_usage
             .as_ref()
@@ -213,7 +557,7 @@ impl SentinelAdapter {
 
         let (response_text, response_tokens) = match &span.output {
             Some(output) => (
-                output.content.clone(),
+                self.redact(output.content.clone()),
                 span.token_usage
                     .as_ref()
                     .map(|u| u.completion_tokens)
@@ -234,6 +578,17 @@ impl SentinelAdapter {
         if let Some(env) = &span.metadata.environment {
             metadata.insert("environment".to_string(), env.clone());
         }
+        for (key, value) in &span.metadata.attributes {
+            metadata.insert(key.clone(), value.clone());
+        }
+        for (key, value) in &span.attributes {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            metadata.insert(key.clone(), value);
+        }
+        let metadata = self.attribute_policy.apply(metadata);
 
         let errors = if span.status == SpanStatus::Error {
             vec!["Request failed".to_string()]
@@ -241,13 +596,13 @@ impl SentinelAdapter {
             vec![]
         };
 
-        Ok(TelemetryEvent::new(
+        let mut event = TelemetryEvent::new(
             self.service_id.clone(),
             ModelId::new(&span.model),
             PromptInfo {
                 text: prompt_text,
                 tokens: prompt_tokens,
-                embedding: None,
+                embedding: self.input_embeddings.get(&span.span_id).cloned(),
             },
             ResponseInfo {
                 text: response_text,
@@ -257,11 +612,23 @@ impl SentinelAdapter {
                     .as_ref()
                     .and_then(|o| o.finish_reason.clone())
                     .unwrap_or_else(|| "unknown".to_string()),
-                embedding: None,
+                embedding: self.output_embeddings.get(&span.span_id).cloned(),
             },
             span.latency.total_ms as f64,
             cost_usd,
-        ))
+        );
+        event.metadata = metadata;
+        event.errors = errors;
+        Ok(event)
+    }
+
+    /// Apply PII redaction to `text` if enabled.
+    fn redact(&self, text: String) -> String {
+        if self.redact_pii {
+            redact_text(&text)
+        } else {
+            text
+        }
     }
 
     /// Extract prompt text from LLM input.
@@ -280,7 +647,9 @@ impl SentinelAdapter {
                 let text = parts
                     .iter()
                     .filter_map(|p| match p {
-                        llm_observatory_core::span::ContentPart::Text { text } => Some(text.clone()),
+                        llm_observatory_core::span::ContentPart::Text { text } => {
+                            Some(text.clone())
+                        }
                         _ => None,
                     })
                     .collect::<Vec<_>>()
@@ -291,21 +660,28 @@ impl SentinelAdapter {
     }
 
     /// Check a span for anomalies.
+    ///
+    /// Uses the thresholds registered for `span.model`, falling back to the
+    /// adapter-wide thresholds.
     pub fn check_span_anomaly(&mut self, span: &LlmSpan) -> Option<DetectedAnomaly> {
+        let thresholds = self.thresholds_for(&span.model).clone();
+
         // Check latency anomaly
-        if span.latency.total_ms > self.thresholds.latency_threshold_ms {
+        if span.latency.total_ms > thresholds.latency_threshold_ms {
             let anomaly = DetectedAnomaly {
                 id: Uuid::new_v4(),
                 anomaly_type: "LatencySpike".to_string(),
                 severity: self.calculate_severity(
                     span.latency.total_ms as f64,
-                    self.thresholds.latency_threshold_ms as f64,
+                    thresholds.latency_threshold_ms as f64,
                 ),
                 detection_method: "Threshold".to_string(),
                 confidence: 0.9,
                 metric: "latency_ms".to_string(),
                 value: span.latency.total_ms as f64,
-                threshold: self.thresholds.latency_threshold_ms as f64,
+                threshold: thresholds.latency_threshold_ms as f64,
+                baseline: None,
+                deviation_sigma: None,
                 timestamp: Utc::now(),
                 span_id: Some(span.span_id.clone()),
                 trace_id: Some(span.trace_id.clone()),
@@ -317,19 +693,19 @@ impl SentinelAdapter {
 
         // Check cost anomaly
         if let Some(cost) = &span.cost {
-            if cost.amount_usd > self.thresholds.cost_threshold_usd {
+            if cost.amount_usd > thresholds.cost_threshold_usd {
                 let anomaly = DetectedAnomaly {
                     id: Uuid::new_v4(),
                     anomaly_type: "CostAnomaly".to_string(),
-                    severity: self.calculate_severity(
-                        cost.amount_usd,
-                        self.thresholds.cost_threshold_usd,
-                    ),
+                    severity: self
+                        .calculate_severity(cost.amount_usd, thresholds.cost_threshold_usd),
                     detection_method: "Threshold".to_string(),
                     confidence: 0.95,
                     metric: "cost_usd".to_string(),
                     value: cost.amount_usd,
-                    threshold: self.thresholds.cost_threshold_usd,
+                    threshold: thresholds.cost_threshold_usd,
+                    baseline: None,
+                    deviation_sigma: None,
                     timestamp: Utc::now(),
                     span_id: Some(span.span_id.clone()),
                     trace_id: Some(span.trace_id.clone()),
@@ -351,6 +727,8 @@ impl SentinelAdapter {
                 metric: "error".to_string(),
                 value: 1.0,
                 threshold: 0.0,
+                baseline: None,
+                deviation_sigma: None,
                 timestamp: Utc::now(),
                 span_id: Some(span.span_id.clone()),
                 trace_id: Some(span.trace_id.clone()),
@@ -363,7 +741,7 @@ impl SentinelAdapter {
         // Check token spike (if baseline is set)
         if let (Some(baseline), Some(usage)) = (self.baseline_tokens, &span.token_usage) {
             let total = usage.total_tokens as f64;
-            if total > baseline * self.thresholds.token_spike_multiplier {
+            if total > baseline * thresholds.token_spike_multiplier {
                 let anomaly = DetectedAnomaly {
                     id: Uuid::new_v4(),
                     anomaly_type: "TokenUsageSpike".to_string(),
@@ -372,7 +750,9 @@ impl SentinelAdapter {
                     confidence: 0.85,
                     metric: "total_tokens".to_string(),
                     value: total,
-                    threshold: baseline * self.thresholds.token_spike_multiplier,
+                    threshold: baseline * thresholds.token_spike_multiplier,
+                    baseline: Some(baseline),
+                    deviation_sigma: None,
                     timestamp: Utc::now(),
                     span_id: Some(span.span_id.clone()),
                     trace_id: Some(span.trace_id.clone()),
@@ -383,9 +763,308 @@ impl SentinelAdapter {
             }
         }
 
+        // Check latency against the distribution learned for this model
+        let latency = span.latency.total_ms as f64;
+        let throughput = Self::completion_throughput(span);
+        let latency_stats = self
+            .latency_stats
+            .get(&span.model)
+            .cloned()
+            .unwrap_or_default();
+        if let Some(sigma) = latency_stats.z_score(latency) {
+            if sigma > thresholds.latency_z_score {
+                let mean = latency_stats.mean;
+                let anomaly = DetectedAnomaly {
+                    id: Uuid::new_v4(),
+                    anomaly_type: "LatencySpike".to_string(),
+                    severity: self.calculate_severity(sigma, thresholds.latency_z_score),
+                    detection_method: "ZScore".to_string(),
+                    confidence: 0.8,
+                    metric: "latency_ms".to_string(),
+                    value: latency,
+                    threshold: mean + thresholds.latency_z_score * latency_stats.std_dev(),
+                    baseline: Some(mean),
+                    deviation_sigma: Some(sigma),
+                    timestamp: Utc::now(),
+                    span_id: Some(span.span_id.clone()),
+                    trace_id: Some(span.trace_id.clone()),
+                };
+
+                self.record_anomaly(anomaly.clone(), AnomalyType::LatencySpike);
+                return Some(anomaly);
+            }
+        }
+
+        // Check completion throughput against the baseline
+        if let (Some(baseline), Some(tps)) = (self.baseline_throughput(&span.model), throughput) {
+            let floor = baseline * thresholds.throughput_degradation_fraction;
+            if tps < floor {
+                let anomaly = DetectedAnomaly {
+                    id: Uuid::new_v4(),
+                    anomaly_type: "ThroughputDegradation".to_string(),
+                    severity: self.calculate_severity(floor, tps.max(f64::EPSILON)),
+                    detection_method: "BaselineDeviation".to_string(),
+                    confidence: 0.85,
+                    metric: "tokens_per_second".to_string(),
+                    value: tps,
+                    threshold: floor,
+                    baseline: Some(baseline),
+                    deviation_sigma: None,
+                    timestamp: Utc::now(),
+                    span_id: Some(span.span_id.clone()),
+                    trace_id: Some(span.trace_id.clone()),
+                };
+
+                self.record_anomaly(anomaly.clone(), AnomalyType::ThroughputDegradation);
+                return Some(anomaly);
+            }
+        }
+
+        // Accumulate drift from the CUSUM target
+        if let Some(anomaly) = self.check_cusum(span) {
+            return Some(anomaly);
+        }
+
+        // Only normal spans feed the baselines, so outliers don't skew them
+        self.latency_stats
+            .entry(span.model.clone())
+            .or_default()
+            .push(latency);
+        if let Some(tps) = throughput {
+            self.throughput_stats
+                .entry(span.model.clone())
+                .or_default()
+                .push(tps);
+        }
         None
     }
 
+    /// Add the span's deviation from target to the CUSUM sum, reporting an
+    /// anomaly once it exceeds the decision interval.
+    fn check_cusum(&mut self, span: &LlmSpan) -> Option<DetectedAnomaly> {
+        let config = self.cusum.clone()?;
+        let (value, metric, type_name, anomaly_type) = match config.metric {
+            CusumMetric::Latency => (
+                span.latency.total_ms as f64,
+                "latency_ms",
+                "LatencySpike",
+                AnomalyType::LatencySpike,
+            ),
+            CusumMetric::Cost => (
+                span.cost.as_ref()?.amount_usd,
+                "cost_usd",
+                "CostAnomaly",
+                AnomalyType::CostAnomaly,
+            ),
+        };
+
+        self.cusum_value = (self.cusum_value + value - config.target - config.slack).max(0.0);
+        if self.cusum_value <= config.decision_interval {
+            return None;
+        }
+
+        let anomaly = DetectedAnomaly {
+            id: Uuid::new_v4(),
+            anomaly_type: type_name.to_string(),
+            severity: self.calculate_severity(self.cusum_value, config.decision_interval),
+            detection_method: "Cusum".to_string(),
+            confidence: 0.75,
+            metric: format!("{}_cusum", metric),
+            value: self.cusum_value,
+            threshold: config.decision_interval,
+            baseline: Some(config.target),
+            deviation_sigma: None,
+            timestamp: Utc::now(),
+            span_id: Some(span.span_id.clone()),
+            trace_id: Some(span.trace_id.clone()),
+        };
+
+        self.cusum_value = 0.0;
+        self.record_anomaly(anomaly.clone(), anomaly_type);
+        Some(anomaly)
+    }
+
+    /// Indices of samples outside the Tukey fences `Q1 - 1.5 * IQR` and
+    /// `Q3 + 1.5 * IQR`.
+    ///
+    /// Quartiles ignore skew in the tails, which makes this more robust than
+    /// z-scores for latency. Returns no indices for fewer than four finite
+    /// samples; non-finite samples are never reported.
+    pub fn detect_iqr_outliers(metric_samples: &[f64]) -> Vec<usize> {
+        let Some((lower, upper)) = iqr_fences(metric_samples) else {
+            return Vec::new();
+        };
+        metric_samples
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| value.is_finite() && (**value < lower || **value > upper))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Check a batch of collected spans for latency outliers using
+    /// [`Self::detect_iqr_outliers`].
+    ///
+    /// Only spans slower than the upper fence are reported, as
+    /// `LatencySpike` anomalies with the `Iqr` detection method. Each one is
+    /// recorded like any other anomaly.
+    pub fn check_latency_outliers(&mut self, spans: &[LlmSpan]) -> Vec<DetectedAnomaly> {
+        let latencies: Vec<f64> = spans.iter().map(|s| s.latency.total_ms as f64).collect();
+        let Some((_, upper)) = iqr_fences(&latencies) else {
+            return Vec::new();
+        };
+
+        let mut sorted = latencies.clone();
+        sorted.sort_by(f64::total_cmp);
+        let median = quantile(&sorted, 0.5);
+
+        let mut anomalies = Vec::new();
+        for index in Self::detect_iqr_outliers(&latencies) {
+            let latency = latencies[index];
+            if latency <= upper {
+                continue;
+            }
+
+            let span = &spans[index];
+            let anomaly = DetectedAnomaly {
+                id: Uuid::new_v4(),
+                anomaly_type: "LatencySpike".to_string(),
+                severity: self.calculate_severity(latency, upper),
+                detection_method: "Iqr".to_string(),
+                confidence: 0.8,
+                metric: "latency_ms".to_string(),
+                value: latency,
+                threshold: upper,
+                baseline: Some(median),
+                deviation_sigma: None,
+                timestamp: Utc::now(),
+                span_id: Some(span.span_id.clone()),
+                trace_id: Some(span.trace_id.clone()),
+            };
+
+            self.record_anomaly(anomaly.clone(), AnomalyType::LatencySpike);
+            anomalies.push(anomaly);
+        }
+        anomalies
+    }
+
+    /// Completion tokens per second, for spans that produced output tokens.
+    fn completion_throughput(span: &LlmSpan) -> Option<f64> {
+        let tokens = span.token_usage.as_ref()?.completion_tokens;
+        let duration = LatencyAdapter::latency_to_duration(&span.latency);
+        if tokens == 0 || duration.is_zero() {
+            return None;
+        }
+        Some(LatencyAdapter::calculate_throughput(tokens, duration))
+    }
+
+    /// Record a span's prompt embedding and check it for input drift.
+    ///
+    /// Embeddings are not computed here; they must be supplied by the caller
+    /// (e.g. from the embedding model used for retrieval). See [`Self::check_drift`].
+    ///
+    /// Only embeddings that are not flagged join the centroid, so a lasting
+    /// shift keeps being reported rather than becoming the new normal. Call
+    /// [`Self::reset_drift_baseline`] after an intended change to relearn it.
+    pub fn record_embedding(
+        &mut self,
+        span_id: impl Into<String>,
+        embedding: Vec<f32>,
+    ) -> Result<Option<DetectedAnomaly>> {
+        self.record_drift_embedding(DriftKind::Input, span_id.into(), embedding)
+    }
+
+    /// Record a span's response embedding and check it for output drift.
+    pub fn record_output_embedding(
+        &mut self,
+        span_id: impl Into<String>,
+        embedding: Vec<f32>,
+    ) -> Result<Option<DetectedAnomaly>> {
+        self.record_drift_embedding(DriftKind::Output, span_id.into(), embedding)
+    }
+
+    /// Cosine distance of `embedding` from the centroid of recent embeddings
+    /// of the given kind, if it exceeds the drift threshold.
+    ///
+    /// Returns `None` until enough embeddings have been recorded to form a
+    /// centroid. Does not record the embedding.
+    pub fn check_drift(&self, kind: DriftKind, embedding: &[f32]) -> Option<f64> {
+        self.embedding_window(kind)
+            .distance_from_centroid(embedding)
+            .filter(|distance| *distance > self.thresholds.drift_cosine_distance)
+    }
+
+    /// Forget the embeddings of the given kind, e.g. after a prompt or model
+    /// change, so the centroid is relearned from the next embeddings.
+    pub fn reset_drift_baseline(&mut self, kind: DriftKind) {
+        match kind {
+            DriftKind::Input => self.input_embeddings = EmbeddingWindow::default(),
+            DriftKind::Output => self.output_embeddings = EmbeddingWindow::default(),
+        }
+    }
+
+    fn embedding_window(&self, kind: DriftKind) -> &EmbeddingWindow {
+        match kind {
+            DriftKind::Input => &self.input_embeddings,
+            DriftKind::Output => &self.output_embeddings,
+        }
+    }
+
+    fn record_drift_embedding(
+        &mut self,
+        kind: DriftKind,
+        span_id: String,
+        embedding: Vec<f32>,
+    ) -> Result<Option<DetectedAnomaly>> {
+        if embedding.is_empty() {
+            return Err(SentinelAdapterError::InvalidData(
+                "embedding must not be empty".to_string(),
+            ));
+        }
+        if let Some(dimensions) = self.embedding_window(kind).dimensions() {
+            if embedding.len() != dimensions {
+                return Err(SentinelAdapterError::InvalidData(format!(
+                    "embedding has {} dimensions, expected {}",
+                    embedding.len(),
+                    dimensions
+                )));
+            }
+        }
+
+        if let Some(distance) = self.check_drift(kind, &embedding) {
+            let (anomaly_type, name, metric) = match kind {
+                DriftKind::Input => (AnomalyType::InputDrift, "InputDrift", "input_embedding"),
+                DriftKind::Output => (AnomalyType::OutputDrift, "OutputDrift", "output_embedding"),
+            };
+            let threshold = self.thresholds.drift_cosine_distance;
+            let anomaly = DetectedAnomaly {
+                id: Uuid::new_v4(),
+                anomaly_type: name.to_string(),
+                severity: self.calculate_severity(distance, threshold),
+                detection_method: "CosineDistance".to_string(),
+                confidence: 0.75,
+                metric: format!("{}_cosine_distance", metric),
+                value: distance,
+                threshold,
+                baseline: None,
+                deviation_sigma: None,
+                timestamp: Utc::now(),
+                span_id: Some(span_id),
+                trace_id: None,
+            };
+
+            self.record_anomaly(anomaly.clone(), anomaly_type);
+            return Ok(Some(anomaly));
+        }
+
+        // Drifted embeddings stay out of the centroid, like other baselines
+        match kind {
+            DriftKind::Input => self.input_embeddings.push(span_id, embedding),
+            DriftKind::Output => self.output_embeddings.push(span_id, embedding),
+        }
+        Ok(None)
+    }
+
     /// Calculate severity based on value vs threshold.
     fn calculate_severity(&self, value: f64, threshold: f64) -> String {
         let ratio = value / threshold;
@@ -410,6 +1089,8 @@ impl SentinelAdapter {
             AnomalyType::CostAnomaly => self.stats.cost_anomalies += 1,
             AnomalyType::ErrorRateIncrease => self.stats.error_anomalies += 1,
             AnomalyType::TokenUsageSpike => self.stats.token_anomalies += 1,
+            AnomalyType::ThroughputDegradation => self.stats.throughput_anomalies += 1,
+            AnomalyType::InputDrift | AnomalyType::OutputDrift => self.stats.drift_anomalies += 1,
             _ => {}
         }
     }
@@ -430,29 +1111,42 @@ impl SentinelAdapter {
         self.stats = AnomalyStats::default();
     }
 
+    /// Convert every buffered anomaly into an [`AnomalyEvent`] for forwarding
+    /// to Sentinel, and clear the buffer.
+    ///
+    /// Each anomaly's model is looked up by its span ID in `model_by_span`;
+    /// anomalies with no known model are reported against `"unknown"`.
+    /// Statistics are left untouched.
+    pub fn drain_anomaly_events(
+        &mut self,
+        model_by_span: &HashMap<String, String>,
+    ) -> Vec<AnomalyEvent> {
+        let anomalies = std::mem::take(&mut self.anomalies);
+        anomalies
+            .iter()
+            .map(|anomaly| {
+                let model = anomaly
+                    .span_id
+                    .as_ref()
+                    .and_then(|span_id| model_by_span.get(span_id))
+                    .map_or("unknown", String::as_str);
+                self.to_anomaly_event(anomaly, model)
+            })
+            .collect()
+    }
+
     /// Check if a span should be sampled based on anomaly detection.
     ///
     /// This implements tail-based sampling where we always sample
     /// spans that have anomalies.
     pub fn should_sample(&self, span: &LlmSpan) -> bool {
-        // Always sample errors
-        if span.status == SpanStatus::Error {
-            return true;
-        }
-
-        // Always sample slow requests
-        if span.latency.total_ms > self.thresholds.latency_threshold_ms {
-            return true;
-        }
-
-        // Always sample expensive requests
-        if let Some(cost) = &span.cost {
-            if cost.amount_usd > self.thresholds.cost_threshold_usd {
-                return true;
-            }
-        }
-
-        false
+        TailSampler::from(self.thresholds_for(&span.model)).should_sample(&SamplingContext {
+            latency_ms: Some(span.latency.total_ms),
+            cost_usd: span.cost.as_ref().map(|c| c.amount_usd),
+            token_count: None,
+            error: span.status == SpanStatus::Error,
+            key: None,
+        })
     }
 
     /// Create an AnomalyEvent from a DetectedAnomaly.
@@ -462,6 +1156,9 @@ impl SentinelAdapter {
             "CostAnomaly" => AnomalyType::CostAnomaly,
             "ErrorRateIncrease" => AnomalyType::ErrorRateIncrease,
             "TokenUsageSpike" => AnomalyType::TokenUsageSpike,
+            "ThroughputDegradation" => AnomalyType::ThroughputDegradation,
+            "InputDrift" => AnomalyType::InputDrift,
+            "OutputDrift" => AnomalyType::OutputDrift,
             other => AnomalyType::Custom(other.to_string()),
         };
 
@@ -473,7 +1170,9 @@ impl SentinelAdapter {
         };
 
         let detection_method = match detected.detection_method.as_str() {
-            "Threshold" => DetectionMethod::ZScore, // Using ZScore as proxy for threshold
+            "ZScore" => DetectionMethod::ZScore,
+            "Cusum" => DetectionMethod::Cusum,
+            "Iqr" => DetectionMethod::Iqr,
             "BaselineDeviation" => DetectionMethod::Mad,
             "StatusCheck" => DetectionMethod::Custom("StatusCheck".to_string()),
             other => DetectionMethod::Custom(other.to_string()),
@@ -482,9 +1181,9 @@ impl SentinelAdapter {
         let details = AnomalyDetails {
             metric: detected.metric.clone(),
             value: detected.value,
-            baseline: detected.threshold,
+            baseline: detected.baseline.unwrap_or(detected.threshold),
             threshold: detected.threshold,
-            deviation_sigma: None,
+            deviation_sigma: detected.deviation_sigma,
             additional: HashMap::new(),
         };
 
@@ -525,7 +1224,12 @@ impl SentinelAdapter {
 
     /// Get supported severity levels.
     pub fn supported_severities() -> Vec<Severity> {
-        vec![Severity::Low, Severity::Medium, Severity::High, Severity::Critical]
+        vec![
+            Severity::Low,
+            Severity::Medium,
+            Severity::High,
+            Severity::Critical,
+        ]
     }
 
     /// Get supported detection methods.
@@ -540,6 +1244,16 @@ impl SentinelAdapter {
     }
 }
 
+impl AdapterStats for SentinelAdapter {
+    fn adapter_name(&self) -> String {
+        format!("sentinel/{}", self.service_id.as_str())
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(&self.stats).unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -648,6 +1362,263 @@ mod tests {
         assert_eq!(stats.error_anomalies, 1);
     }
 
+    #[test]
+    fn test_zscore_latency_detection_populates_sigma() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        for i in 0..20 {
+            let span = create_test_span(100 + (i % 5) * 10, 0.01, SpanStatus::Ok);
+            assert!(adapter.check_span_anomaly(&span).is_none());
+        }
+
+        // Well under the fixed 5s threshold, but far outside the learned spread
+        let span = create_test_span(1000, 0.01, SpanStatus::Ok);
+        let anomaly = adapter.check_span_anomaly(&span).unwrap();
+        assert_eq!(anomaly.anomaly_type, "LatencySpike");
+        assert_eq!(anomaly.detection_method, "ZScore");
+        let baseline = anomaly.baseline.unwrap();
+        assert!((baseline - 120.0).abs() < 1e-9);
+        let sigma = anomaly.deviation_sigma.unwrap();
+        assert!(sigma > adapter.thresholds().latency_z_score);
+
+        let event = adapter.to_anomaly_event(&anomaly, "gpt-4");
+        assert_eq!(event.details.deviation_sigma, Some(sigma));
+        assert_eq!(event.details.baseline, baseline);
+        assert!(matches!(event.detection_method, DetectionMethod::ZScore));
+    }
+
+    #[test]
+    fn test_latency_baseline_is_learned_per_model() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        let span_for = |model: &str, latency_ms: u64| {
+            let mut span = create_test_span(latency_ms, 0.01, SpanStatus::Ok);
+            span.model = model.to_string();
+            span
+        };
+        for i in 0..20 {
+            let jitter = (i % 5) * 10;
+            assert!(adapter
+                .check_span_anomaly(&span_for("fast-model", 100 + jitter))
+                .is_none());
+            assert!(adapter
+                .check_span_anomaly(&span_for("slow-model", 2000 + jitter))
+                .is_none());
+        }
+
+        // Normal for the slow model, even though it dwarfs the fast one's
+        assert!(adapter
+            .check_span_anomaly(&span_for("slow-model", 2040))
+            .is_none());
+
+        // Far outside the fast model's spread, but below the mixed average
+        let anomaly = adapter
+            .check_span_anomaly(&span_for("fast-model", 600))
+            .unwrap();
+        assert_eq!(anomaly.detection_method, "ZScore");
+        assert!((anomaly.baseline.unwrap() - 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_threshold_detection_is_not_reported_as_zscore() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        let span = create_test_span(10000, 0.01, SpanStatus::Ok);
+        let anomaly = adapter.check_span_anomaly(&span).unwrap();
+
+        let event = adapter.to_anomaly_event(&anomaly, "gpt-4");
+        assert!(event.details.deviation_sigma.is_none());
+        assert!(!matches!(event.detection_method, DetectionMethod::ZScore));
+    }
+
+    #[test]
+    fn test_detect_throughput_degradation() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        // 200 completion tokens in 1s: 200 tokens/second
+        for _ in 0..MIN_BASELINE_SAMPLES {
+            let span = create_test_span(1000, 0.01, SpanStatus::Ok);
+            assert!(adapter.check_span_anomaly(&span).is_none());
+        }
+        assert_eq!(adapter.baseline_throughput("gpt-4"), Some(200.0));
+        assert_eq!(adapter.baseline_throughput("gpt-4o-mini"), None);
+
+        // Same tokens over 4s: 50 tokens/second, under half the baseline
+        let slow = create_test_span(4000, 0.01, SpanStatus::Ok);
+        let anomaly = adapter.check_span_anomaly(&slow).unwrap();
+        assert_eq!(anomaly.anomaly_type, "ThroughputDegradation");
+        assert_eq!(anomaly.value, 50.0);
+        assert_eq!(anomaly.threshold, 100.0);
+        assert_eq!(adapter.stats().throughput_anomalies, 1);
+
+        let event = adapter.to_anomaly_event(&anomaly, "gpt-4");
+        assert!(matches!(
+            event.anomaly_type,
+            AnomalyType::ThroughputDegradation
+        ));
+    }
+
+    #[test]
+    fn test_cusum_detects_gradual_latency_climb() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        adapter.set_cusum(CusumConfig {
+            metric: CusumMetric::Latency,
+            target: 1000.0,
+            slack: 50.0,
+            decision_interval: 1000.0,
+        });
+
+        // Each span is 100ms slower than the last, far below the 5s threshold
+        for step in 0..5 {
+            let span = create_test_span(1000 + step * 100, 0.01, SpanStatus::Ok);
+            assert!(adapter.check_span_anomaly(&span).is_none());
+        }
+        assert!((adapter.cusum_value() - 800.0).abs() < 1e-9);
+
+        let span = create_test_span(1500, 0.01, SpanStatus::Ok);
+        let anomaly = adapter.check_span_anomaly(&span).unwrap();
+        assert_eq!(anomaly.anomaly_type, "LatencySpike");
+        assert_eq!(anomaly.detection_method, "Cusum");
+        assert!((anomaly.value - 1250.0).abs() < 1e-9);
+        assert_eq!(adapter.cusum_value(), 0.0);
+
+        let event = adapter.to_anomaly_event(&anomaly, "gpt-4");
+        assert!(matches!(event.detection_method, DetectionMethod::Cusum));
+
+        adapter.check_span_anomaly(&create_test_span(1600, 0.01, SpanStatus::Ok));
+        assert!(adapter.cusum_value() > 0.0);
+        adapter.reset_cusum();
+        assert_eq!(adapter.cusum_value(), 0.0);
+    }
+
+    #[test]
+    fn test_iqr_flags_obvious_latency_outlier() {
+        let samples = [120.0, 95.0, 110.0, 105.0, 100.0, 4000.0, 115.0, 90.0];
+        assert_eq!(SentinelAdapter::detect_iqr_outliers(&samples), [5]);
+        assert!(SentinelAdapter::detect_iqr_outliers(&[1.0, 1000.0]).is_empty());
+
+        let mut adapter = SentinelAdapter::new("test-service");
+        let spans: Vec<LlmSpan> = samples
+            .iter()
+            .map(|&ms| create_test_span(ms as u64, 0.01, SpanStatus::Ok))
+            .collect();
+        let anomalies = adapter.check_latency_outliers(&spans);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].value, 4000.0);
+        assert_eq!(anomalies[0].detection_method, "Iqr");
+        assert_eq!(adapter.stats().latency_anomalies, 1);
+
+        let event = adapter.to_anomaly_event(&anomalies[0], "gpt-4");
+        assert!(matches!(event.detection_method, DetectionMethod::Iqr));
+    }
+
+    #[test]
+    fn test_input_drift_flags_outlier_embedding() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        for i in 0..MIN_BASELINE_SAMPLES {
+            let embedding = vec![1.0, 0.01 * i as f32, 0.0];
+            let result = adapter.record_embedding(format!("span_{}", i), embedding);
+            assert!(result.unwrap().is_none());
+        }
+
+        let near = [0.9, 0.05, 0.01];
+        assert!(adapter.check_drift(DriftKind::Input, &near).is_none());
+
+        let anomaly = adapter
+            .record_embedding("outlier", vec![0.0, 0.0, 1.0])
+            .unwrap()
+            .unwrap();
+        assert_eq!(anomaly.anomaly_type, "InputDrift");
+        assert_eq!(anomaly.span_id.as_deref(), Some("outlier"));
+        assert!(anomaly.value > 0.9);
+        assert_eq!(adapter.stats().drift_anomalies, 1);
+
+        // Output embeddings are tracked separately
+        let outlier = [0.0, 0.0, 1.0];
+        assert!(adapter.check_drift(DriftKind::Output, &outlier).is_none());
+    }
+
+    #[test]
+    fn test_drifted_embeddings_do_not_move_the_centroid() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        for i in 0..MIN_BASELINE_SAMPLES {
+            adapter
+                .record_embedding(format!("span_{}", i), vec![1.0, 0.0, 0.0])
+                .unwrap();
+        }
+
+        // A lasting shift is reported every time instead of being absorbed
+        for i in 0..DRIFT_WINDOW {
+            let result = adapter.record_embedding(format!("shifted_{}", i), vec![0.0, 0.0, 1.0]);
+            assert!(result.unwrap().is_some());
+        }
+        assert_eq!(adapter.stats().drift_anomalies, DRIFT_WINDOW as u64);
+
+        // Resetting relearns the baseline from the new distribution
+        adapter.reset_drift_baseline(DriftKind::Input);
+        for i in 0..MIN_BASELINE_SAMPLES {
+            let result = adapter.record_embedding(format!("relearn_{}", i), vec![0.0, 0.0, 1.0]);
+            assert!(result.unwrap().is_none());
+        }
+        assert!(adapter
+            .check_drift(DriftKind::Input, &[0.0, 0.0, 1.0])
+            .is_none());
+        assert!(adapter
+            .check_drift(DriftKind::Input, &[1.0, 0.0, 0.0])
+            .is_some());
+    }
+
+    #[test]
+    fn test_record_embedding_rejects_dimension_mismatch() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        adapter.record_embedding("a", vec![1.0, 0.0]).unwrap();
+
+        let result = adapter.record_embedding("b", vec![1.0, 0.0, 0.0]);
+        assert!(matches!(result, Err(SentinelAdapterError::InvalidData(_))));
+        assert!(adapter.record_embedding("c", Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_drain_anomaly_events() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        let slow = create_test_span(10000, 0.01, SpanStatus::Ok);
+        let mut expensive = create_test_span(100, 5.0, SpanStatus::Ok);
+        expensive.span_id = "span_unmapped".to_string();
+        adapter.check_span_anomaly(&slow).unwrap();
+        adapter.check_span_anomaly(&expensive).unwrap();
+
+        let model_by_span = HashMap::from([(slow.span_id.clone(), slow.model.clone())]);
+        let events = adapter.drain_anomaly_events(&model_by_span);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0].anomaly_type, AnomalyType::LatencySpike));
+        assert!(matches!(events[1].anomaly_type, AnomalyType::CostAnomaly));
+
+        assert!(adapter.anomalies().is_empty());
+        assert_eq!(adapter.stats().total_detected, 2);
+        assert!(adapter.drain_anomaly_events(&model_by_span).is_empty());
+    }
+
+    #[test]
+    fn test_model_specific_thresholds() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        adapter.set_model_thresholds(
+            "gpt-4o-mini",
+            AnomalyThresholds {
+                latency_threshold_ms: 1000,
+                ..AnomalyThresholds::default()
+            },
+        );
+
+        // 2s is fine under the default 5s limit for gpt-4...
+        let span = create_test_span(2000, 0.01, SpanStatus::Ok);
+        assert!(adapter.check_span_anomaly(&span).is_none());
+
+        // ...but a latency spike for a model expected to answer within 1s
+        let mut fast_model_span = span.clone();
+        fast_model_span.model = "gpt-4o-mini".to_string();
+        let anomaly = adapter.check_span_anomaly(&fast_model_span).unwrap();
+        assert_eq!(anomaly.anomaly_type, "LatencySpike");
+        assert_eq!(anomaly.threshold, 1000.0);
+        assert!(adapter.should_sample(&fast_model_span));
+        assert!(!adapter.should_sample(&span));
+    }
+
     #[test]
     fn test_span_to_telemetry_event() {
         let adapter = SentinelAdapter::new("test-service");
@@ -656,4 +1627,47 @@ mod tests {
         let event = adapter.span_to_telemetry_event(&span);
         assert!(event.is_ok());
     }
+
+    #[test]
+    fn test_span_to_telemetry_event_redacts_pii() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        let mut span = create_test_span(100, 0.01, SpanStatus::Ok);
+        span.input = LlmInput::Text {
+            prompt: "Reply to jane.doe@example.com".to_string(),
+        };
+
+        let event = adapter.span_to_telemetry_event(&span).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(!json.contains("jane.doe@example.com"));
+        assert!(json.contains("Reply to [EMAIL]"));
+
+        adapter.set_pii_redaction(false);
+        let event = adapter.span_to_telemetry_event(&span).unwrap();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("jane.doe@example.com"));
+    }
+
+    #[test]
+    fn test_span_to_telemetry_event_redacts_attributes() {
+        let mut adapter = SentinelAdapter::new("test-service");
+        let mut span = create_test_span(100, 0.01, SpanStatus::Ok);
+        span.metadata.environment = Some("production".to_string());
+        span.metadata
+            .attributes
+            .insert("password".to_string(), "hunter2".to_string());
+        span.attributes
+            .insert("customer".to_string(), serde_json::json!("acme"));
+
+        let event = adapter.span_to_telemetry_event(&span).unwrap();
+        assert_eq!(event.metadata["environment"], "production");
+        assert!(!event.metadata.contains_key("password"));
+        assert!(event.metadata["customer"].starts_with("sha256:"));
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(!json.contains("hunter2"));
+        assert!(!json.contains("acme"));
+
+        adapter.set_attribute_policy(AttributeRedactionPolicy::allow_all());
+        let event = adapter.span_to_telemetry_event(&span).unwrap();
+        assert_eq!(event.metadata["password"], "hunter2");
+    }
 }
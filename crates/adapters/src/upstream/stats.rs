@@ -0,0 +1,118 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Unified statistics reporting across upstream adapters.
+//!
+//! Every stateful adapter keeps its own stats struct. [`AdapterStats`] exposes
+//! each of them as JSON under a stable name, and [`collect_stats`] merges any
+//! set of adapters into a single [`AdapterStatsReport`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use llm_observatory_adapters::upstream::prelude::*;
+//!
+//! let report = collect_stats(&[&gateway_adapter, &orchestrator_adapter]);
+//! println!("{}", serde_json::to_string_pretty(&report)?);
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Adapters that can report their statistics as JSON.
+pub trait AdapterStats {
+    /// Name identifying this adapter instance, e.g. `inference_gateway/gw-1`.
+    fn adapter_name(&self) -> String;
+
+    /// Current statistics as JSON.
+    fn snapshot(&self) -> serde_json::Value;
+}
+
+/// Statistics from several adapters, keyed by adapter name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdapterStatsReport {
+    /// When the report was collected
+    pub generated_at: DateTime<Utc>,
+    /// Stats snapshot per adapter
+    pub adapters: BTreeMap<String, serde_json::Value>,
+}
+
+impl AdapterStatsReport {
+    /// Stats snapshot for the named adapter.
+    pub fn get(&self, adapter_name: &str) -> Option<&serde_json::Value> {
+        self.adapters.get(adapter_name)
+    }
+}
+
+/// Snapshot the stats of every adapter into one report.
+///
+/// Adapters sharing a name are disambiguated with a `#2`, `#3`, ... suffix.
+pub fn collect_stats(adapters: &[&dyn AdapterStats]) -> AdapterStatsReport {
+    let mut report = BTreeMap::new();
+    for adapter in adapters {
+        let name = adapter.adapter_name();
+        let mut key = name.clone();
+        let mut n = 1;
+        while report.contains_key(&key) {
+            n += 1;
+            key = format!("{}#{}", name, n);
+        }
+        report.insert(key, adapter.snapshot());
+    }
+
+    AdapterStatsReport {
+        generated_at: Utc::now(),
+        adapters: report,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::upstream::inference_gateway::InferenceGatewayAdapter;
+    use crate::upstream::infra::CacheAdapter;
+    use crate::upstream::orchestrator::OrchestratorAdapter;
+
+    #[test]
+    fn test_collect_stats_from_gateway_and_orchestrator() {
+        let mut gateway = InferenceGatewayAdapter::new("gw-1");
+        gateway
+            .parse_inference_telemetry(&serde_json::json!({
+                "request_id": "req-1",
+                "backend_id": "backend-openai",
+                "model": "gpt-4",
+                "provider": "openai",
+                "status": "success"
+            }))
+            .unwrap();
+        let orchestrator = OrchestratorAdapter::new("orch-1");
+        let second_gateway = InferenceGatewayAdapter::new("gw-1");
+
+        let report = collect_stats(&[&gateway, &orchestrator, &second_gateway]);
+        assert_eq!(report.adapters.len(), 3);
+
+        let gateway_stats = report.get("inference_gateway/gw-1").unwrap();
+        assert_eq!(gateway_stats["total_inference_requests"], 1);
+        assert_eq!(
+            report.get("inference_gateway/gw-1#2").unwrap()["total_inference_requests"],
+            0
+        );
+        assert!(report.get("orchestrator/orch-1").unwrap().is_object());
+    }
+
+    #[test]
+    fn test_collect_stats_includes_infra_cache() {
+        let mut cache: CacheAdapter<String> = CacheAdapter::new();
+        cache.set("key", "value".to_string());
+        cache.get("key");
+        cache.get("missing");
+
+        let report = collect_stats(&[&cache]);
+        let cache_stats = report.get("infra/cache").unwrap();
+        assert_eq!(cache_stats["hits"], 1);
+        assert_eq!(cache_stats["misses"], 1);
+        assert_eq!(cache_stats["entries"], 1);
+        assert_eq!(cache_stats["hit_rate"], 0.5);
+    }
+}
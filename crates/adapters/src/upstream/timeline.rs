@@ -0,0 +1,417 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Merged span timelines across the runtime adapters.
+//!
+//! The edge agent, inference gateway and orchestrator adapters each export
+//! spans as `serde_json::Value`. [`TimelineBuilder`] collects those spans,
+//! joins them by `trace_id` and links them by `parent_span_id` into one tree
+//! per trace, regardless of which adapter produced each span.
+//!
+//! # Features
+//!
+//! - Flattening of nested `children` arrays (as produced by the orchestrator);
+//!   nested spans inherit the enclosing `trace_id` and, when they have none,
+//!   use the enclosing span as their parent
+//! - Siblings and roots ordered by `start_time`, then `span_id`
+//! - Spans whose parent is unknown (or that form a parent cycle) become roots
+//! - Later spans with an already-seen `span_id` replace the earlier one
+//!
+//! # Example
+//!
+//! ```ignore
+//! use llm_observatory_adapters::upstream::timeline::TimelineBuilder;
+//!
+//! let mut builder = TimelineBuilder::new();
+//! builder.add_span(&orchestrator.workflow_to_span_json(&workflow))?;
+//! builder.add_span(&gateway.telemetry_to_span_json(&telemetry))?;
+//!
+//! for timeline in builder.build() {
+//!     println!("{}", serde_json::to_string_pretty(&timeline)?);
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// Errors that can occur while building timelines.
+#[derive(Debug, Error)]
+pub enum TimelineError {
+    /// Span is not a JSON object
+    #[error("Invalid span: {0}")]
+    InvalidSpan(String),
+
+    /// Missing required field
+    #[error("Missing required field: {0}")]
+    MissingField(String),
+
+    /// Timestamp is not valid RFC 3339
+    #[error("Invalid timestamp: {0}")]
+    InvalidTimestamp(String),
+}
+
+/// Result type for timeline operations.
+pub type Result<T> = std::result::Result<T, TimelineError>;
+
+/// A merged, time-ordered span tree for one trace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timeline {
+    /// Trace shared by every span in the timeline
+    pub trace_id: String,
+    /// Root spans, each with its descendants nested under `children`
+    pub roots: Vec<serde_json::Value>,
+}
+
+impl Timeline {
+    /// Total number of spans in the timeline.
+    pub fn span_count(&self) -> usize {
+        fn count(span: &serde_json::Value) -> usize {
+            1 + span
+                .get("children")
+                .and_then(|c| c.as_array())
+                .map_or(0, |children| children.iter().map(count).sum())
+        }
+        self.roots.iter().map(count).sum()
+    }
+}
+
+/// A span with its nesting removed and its links resolved.
+#[derive(Debug, Clone)]
+struct FlatSpan {
+    span_id: String,
+    parent_span_id: Option<String>,
+    start_time: DateTime<Utc>,
+    span: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Collects spans from multiple adapters and merges them per trace.
+#[derive(Debug, Clone, Default)]
+pub struct TimelineBuilder {
+    /// Spans by trace ID, then by span ID
+    traces: HashMap<String, HashMap<String, FlatSpan>>,
+}
+
+impl TimelineBuilder {
+    /// Create an empty timeline builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a span, including any spans nested under its `children`.
+    ///
+    /// The top-level span must carry a `trace_id`; every span needs a
+    /// `span_id` and an RFC 3339 `start_time`. Nothing is added if any span
+    /// in the tree is invalid.
+    pub fn add_span(&mut self, span: &serde_json::Value) -> Result<()> {
+        let mut flat = Vec::new();
+        flatten(span, None, None, &mut flat)?;
+
+        for (trace_id, span) in flat {
+            self.traces
+                .entry(trace_id)
+                .or_default()
+                .insert(span.span_id.clone(), span);
+        }
+        Ok(())
+    }
+
+    /// Add several spans, stopping at the first invalid one.
+    pub fn add_spans<'a>(
+        &mut self,
+        spans: impl IntoIterator<Item = &'a serde_json::Value>,
+    ) -> Result<()> {
+        spans.into_iter().try_for_each(|span| self.add_span(span))
+    }
+
+    /// Number of distinct spans collected across all traces.
+    pub fn span_count(&self) -> usize {
+        self.traces.values().map(HashMap::len).sum()
+    }
+
+    /// Collected trace IDs, sorted.
+    pub fn trace_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.traces.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Build the timeline for a single trace.
+    pub fn build_trace(&self, trace_id: &str) -> Option<Timeline> {
+        let spans = self.traces.get(trace_id)?;
+
+        let mut children: HashMap<&str, Vec<&FlatSpan>> = HashMap::new();
+        let mut roots = Vec::new();
+        for span in spans.values() {
+            match span.parent_span_id.as_deref() {
+                Some(parent) if parent != span.span_id && spans.contains_key(parent) => {
+                    children.entry(parent).or_default().push(span);
+                }
+                _ => roots.push(span),
+            }
+        }
+        for siblings in children.values_mut() {
+            sort_by_start(siblings);
+        }
+        sort_by_start(&mut roots);
+
+        let mut visited = HashSet::new();
+        let mut nodes: Vec<serde_json::Value> = roots
+            .into_iter()
+            .map(|root| build_node(root, &children, &mut visited))
+            .collect();
+
+        // Spans caught in a parent cycle are unreachable from any root;
+        // promote them so no span is dropped.
+        let mut orphans: Vec<&FlatSpan> = spans
+            .values()
+            .filter(|s| !visited.contains(s.span_id.as_str()))
+            .collect();
+        sort_by_start(&mut orphans);
+        for orphan in orphans {
+            if !visited.contains(orphan.span_id.as_str()) {
+                nodes.push(build_node(orphan, &children, &mut visited));
+            }
+        }
+
+        Some(Timeline {
+            trace_id: trace_id.to_string(),
+            roots: nodes,
+        })
+    }
+
+    /// Build timelines for every trace, ordered by their earliest span.
+    pub fn build(&self) -> Vec<Timeline> {
+        let mut ids: Vec<(&str, Option<DateTime<Utc>>)> = self
+            .traces
+            .iter()
+            .map(|(id, spans)| (id.as_str(), spans.values().map(|s| s.start_time).min()))
+            .collect();
+        ids.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+
+        ids.into_iter()
+            .filter_map(|(id, _)| self.build_trace(id))
+            .collect()
+    }
+
+    /// Remove all collected spans.
+    pub fn clear(&mut self) {
+        self.traces.clear();
+    }
+}
+
+/// Flatten `span` and its nested children into `out` as `(trace_id, span)`.
+fn flatten(
+    span: &serde_json::Value,
+    inherited_trace: Option<&str>,
+    enclosing_span: Option<&str>,
+    out: &mut Vec<(String, FlatSpan)>,
+) -> Result<()> {
+    let object = span
+        .as_object()
+        .ok_or_else(|| TimelineError::InvalidSpan(format!("expected object, got {}", span)))?;
+
+    let trace_id = object
+        .get("trace_id")
+        .and_then(|v| v.as_str())
+        .or(inherited_trace)
+        .ok_or_else(|| TimelineError::MissingField("trace_id".to_string()))?
+        .to_string();
+    let span_id = object
+        .get("span_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| TimelineError::MissingField("span_id".to_string()))?
+        .to_string();
+    let parent_span_id = object
+        .get("parent_span_id")
+        .and_then(|v| v.as_str())
+        .or(enclosing_span)
+        .map(String::from);
+    let start_time = object
+        .get("start_time")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| TimelineError::MissingField("start_time".to_string()))?;
+    let start_time = DateTime::parse_from_rfc3339(start_time)
+        .map_err(|e| TimelineError::InvalidTimestamp(format!("{}: {}", start_time, e)))?
+        .with_timezone(&Utc);
+
+    let mut flat = object.clone();
+    let nested = flat.remove("children");
+    flat.insert("trace_id".to_string(), trace_id.clone().into());
+    flat.insert("parent_span_id".to_string(), parent_span_id.clone().into());
+
+    out.push((
+        trace_id.clone(),
+        FlatSpan {
+            span_id: span_id.clone(),
+            parent_span_id,
+            start_time,
+            span: flat,
+        },
+    ));
+
+    if let Some(serde_json::Value::Array(nested)) = nested {
+        for child in &nested {
+            flatten(child, Some(&trace_id), Some(&span_id), out)?;
+        }
+    }
+    Ok(())
+}
+
+fn sort_by_start(spans: &mut [&FlatSpan]) {
+    spans.sort_by(|a, b| {
+        a.start_time
+            .cmp(&b.start_time)
+            .then_with(|| a.span_id.cmp(&b.span_id))
+    });
+}
+
+/// Render `span` and its unvisited descendants as a nested JSON span.
+fn build_node<'a>(
+    span: &'a FlatSpan,
+    children: &HashMap<&str, Vec<&'a FlatSpan>>,
+    visited: &mut HashSet<&'a str>,
+) -> serde_json::Value {
+    visited.insert(span.span_id.as_str());
+
+    let mut nested = Vec::new();
+    for &child in children.get(span.span_id.as_str()).into_iter().flatten() {
+        if !visited.contains(child.span_id.as_str()) {
+            nested.push(build_node(child, children, visited));
+        }
+    }
+
+    let mut node = span.span.clone();
+    node.insert("children".to_string(), nested.into());
+    serde_json::Value::Object(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::upstream::clock::MockClock;
+    use crate::upstream::inference_gateway::InferenceGatewayAdapter;
+    use crate::upstream::orchestrator::OrchestratorAdapter;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_merge_gateway_and_orchestrator_spans() {
+        let start = "2025-01-01T00:00:00Z".parse().unwrap();
+        let clock = Arc::new(MockClock::new(start));
+
+        let mut orchestrator = OrchestratorAdapter::with_clock("orchestrator-1", clock.clone());
+        let workflow = orchestrator
+            .parse_workflow_telemetry(&json!({
+                "workflow_id": "wf-1",
+                "name": "rag",
+                "trace_id": "trace-1",
+                "pipelines": [{
+                    "pipeline_id": "pl-1",
+                    "span_id": "pl-span-1",
+                    "name": "answer",
+                    "steps": [{
+                        "step_id": "step-1",
+                        "span_id": "step-span-1",
+                        "name": "generate",
+                        "step_type": "llm_completion"
+                    }]
+                }]
+            }))
+            .unwrap();
+
+        // The gateway request is issued by the orchestrator step, slightly later
+        clock.advance(chrono::Duration::seconds(3));
+        let mut gateway = InferenceGatewayAdapter::with_clock("gateway-1", clock);
+        let telemetry = gateway
+            .parse_inference_telemetry(&json!({
+                "request_id": "req-1",
+                "backend_id": "backend-openai",
+                "provider": "openai",
+                "model": "gpt-4",
+                "trace_id": "trace-1",
+                "parent_span_id": "step-span-1"
+            }))
+            .unwrap();
+        let inference_span_id = telemetry.telemetry_id.to_string();
+
+        let mut builder = TimelineBuilder::new();
+        builder
+            .add_spans([
+                &gateway.telemetry_to_span_json(&telemetry),
+                &orchestrator.workflow_to_span_json(&workflow),
+            ])
+            .unwrap();
+        assert_eq!(builder.span_count(), 4);
+
+        let timelines = builder.build();
+        assert_eq!(timelines.len(), 1);
+        let timeline = &timelines[0];
+        assert_eq!(timeline.trace_id, "trace-1");
+        assert_eq!(timeline.roots.len(), 1);
+        assert_eq!(timeline.span_count(), 4);
+
+        let root = &timeline.roots[0];
+        assert_eq!(root["span_id"], "wf-1");
+        let pipeline = &root["children"][0];
+        assert_eq!(pipeline["span_id"], "pl-span-1");
+        let step = &pipeline["children"][0];
+        assert_eq!(step["span_id"], "step-span-1");
+        assert_eq!(step["trace_id"], "trace-1");
+        assert_eq!(step["children"][0]["span_id"], inference_span_id.as_str());
+        assert_eq!(step["children"][0]["name"], "inference.openai");
+    }
+
+    #[test]
+    fn test_unknown_parents_become_ordered_roots() {
+        let mut builder = TimelineBuilder::new();
+        builder
+            .add_spans(&[
+                json!({
+                    "trace_id": "t", "span_id": "late", "parent_span_id": "missing",
+                    "start_time": "2025-01-01T00:00:05Z"
+                }),
+                json!({"trace_id": "t", "span_id": "early", "start_time": "2025-01-01T00:00:01Z"}),
+                json!({
+                    "trace_id": "t", "span_id": "a", "parent_span_id": "b",
+                    "start_time": "2025-01-01T00:00:07Z"
+                }),
+                json!({
+                    "trace_id": "t", "span_id": "b", "parent_span_id": "a",
+                    "start_time": "2025-01-01T00:00:06Z"
+                }),
+            ])
+            .unwrap();
+
+        let timeline = builder.build_trace("t").unwrap();
+        let roots: Vec<&str> = timeline
+            .roots
+            .iter()
+            .map(|r| r["span_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(roots, vec!["early", "late", "b"]);
+        assert_eq!(timeline.roots[2]["children"][0]["span_id"], "a");
+        assert_eq!(timeline.span_count(), 4);
+    }
+
+    #[test]
+    fn test_add_span_rejects_invalid_spans() {
+        let mut builder = TimelineBuilder::new();
+        let missing_trace = json!({"span_id": "s", "start_time": "2025-01-01T00:00:00Z"});
+        assert!(matches!(
+            builder.add_span(&missing_trace),
+            Err(TimelineError::MissingField(_))
+        ));
+
+        let bad_child = json!({
+            "trace_id": "t", "span_id": "s", "start_time": "2025-01-01T00:00:00Z",
+            "children": [{"span_id": "c", "start_time": "yesterday"}]
+        });
+        assert!(matches!(
+            builder.add_span(&bad_child),
+            Err(TimelineError::InvalidTimestamp(_))
+        ));
+        assert_eq!(builder.span_count(), 0);
+    }
+}
@@ -0,0 +1,59 @@
+//! Error types for the benchmark system.
+//!
+//! This module provides the `BenchmarkError` enum returned by the write and
+//! validation paths, so callers can tell I/O failures apart from bad data.
+
+use std::io;
+
+/// Result type alias using `BenchmarkError`.
+pub type Result<T> = std::result::Result<T, BenchmarkError>;
+
+/// Errors produced while validating or writing benchmarks.
+#[derive(Debug, thiserror::Error)]
+pub enum BenchmarkError {
+    /// Reading or writing benchmark output failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// Results could not be serialized or deserialized.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// A benchmark result is malformed.
+    #[error("Invalid benchmark result: {0}")]
+    Validation(String),
+}
+
+impl BenchmarkError {
+    /// Create a validation error.
+    pub fn validation(msg: impl Into<String>) -> Self {
+        Self::Validation(msg.into())
+    }
+}
+
+impl From<BenchmarkError> for io::Error {
+    fn from(err: BenchmarkError) -> Self {
+        let kind = match err {
+            BenchmarkError::Io(inner) => return inner,
+            BenchmarkError::Serialization(_) => io::ErrorKind::InvalidData,
+            BenchmarkError::Validation(_) => io::ErrorKind::InvalidInput,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_io_error_preserves_kind() {
+        let original = io::Error::new(io::ErrorKind::PermissionDenied, "read-only");
+        let err: io::Error = BenchmarkError::from(original).into();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        let err: io::Error = BenchmarkError::validation("empty target_id").into();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("empty target_id"));
+    }
+}
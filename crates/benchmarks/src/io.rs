@@ -1,13 +1,17 @@
 //! I/O operations for benchmark results.
 //!
 //! This module provides functionality to read and write benchmark
-//! results to the filesystem in various formats.
+//! results to the filesystem in various formats. Output file names can be
+//! customized, e.g. timestamped per run, with [`OutputNaming`].
 
-use crate::result::BenchmarkResult;
+use crate::error::{BenchmarkError, Result};
 use crate::markdown;
+use crate::result::BenchmarkResult;
+use chrono::{DateTime, Utc};
+use serde::ser::{SerializeSeq, Serializer};
 use std::fs;
-use std::io;
-use std::path::Path;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 /// Default output directory path.
 pub const OUTPUT_DIR: &str = "benchmarks/output";
@@ -18,6 +22,98 @@ pub const RAW_DIR: &str = "benchmarks/output/raw";
 /// Summary file path.
 pub const SUMMARY_FILE: &str = "benchmarks/output/summary.md";
 
+/// Newline-delimited JSON results file path.
+pub const NDJSON_FILE: &str = "benchmarks/output/all_results.ndjson";
+
+/// File name templates for benchmark outputs, relative to [`OUTPUT_DIR`]
+/// or the directory set with [`OutputNaming::with_output_dir`].
+///
+/// Templates may contain `{date}` (UTC, `2025-01-31`) and `{timestamp}`
+/// (UTC, `20250131T094500Z`) placeholders; the raw template also takes
+/// `{target}`, the target ID with `/` replaced by `_`. The defaults are the
+/// fixed names in [`SUMMARY_FILE`], [`RAW_DIR`] and `all_results.json`;
+/// NDJSON output is only written when a template is set with
+/// [`OutputNaming::with_ndjson`]. The summary also tabulates any metric
+/// pointers set with [`OutputNaming::with_summary_metrics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputNaming {
+    /// Directory the templates are resolved against
+    pub output_dir: PathBuf,
+    /// Markdown summary
+    pub summary: String,
+    /// Combined JSON results
+    pub all_results: String,
+    /// Newline-delimited JSON results, if wanted
+    pub ndjson: Option<String>,
+    /// Individual raw result per target
+    pub raw: String,
+    /// JSON Pointers of metrics tabulated in the summary
+    pub summary_metrics: Vec<String>,
+}
+
+impl Default for OutputNaming {
+    fn default() -> Self {
+        Self {
+            output_dir: PathBuf::from(OUTPUT_DIR),
+            summary: "summary.md".to_string(),
+            all_results: "all_results.json".to_string(),
+            ndjson: None,
+            raw: "raw/{target}.json".to_string(),
+            summary_metrics: Vec::new(),
+        }
+    }
+}
+
+impl OutputNaming {
+    /// Write outputs under `dir` instead of [`OUTPUT_DIR`].
+    pub fn with_output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = dir.into();
+        self
+    }
+
+    /// Also write NDJSON results under `template`.
+    pub fn with_ndjson(mut self, template: impl Into<String>) -> Self {
+        self.ndjson = Some(template.into());
+        self
+    }
+
+    /// Tabulate the metrics at `pointers` in the summary.
+    pub fn with_summary_metrics(mut self, pointers: Vec<String>) -> Self {
+        self.summary_metrics = pointers;
+        self
+    }
+
+    /// Path of the summary file for a run at `now`.
+    pub fn summary_path(&self, now: DateTime<Utc>) -> PathBuf {
+        self.resolve(&self.summary, now)
+    }
+
+    /// Path of the combined JSON file for a run at `now`.
+    pub fn all_results_path(&self, now: DateTime<Utc>) -> PathBuf {
+        self.resolve(&self.all_results, now)
+    }
+
+    /// Path of the NDJSON file for a run at `now`, if NDJSON is wanted.
+    pub fn ndjson_path(&self, now: DateTime<Utc>) -> Option<PathBuf> {
+        self.ndjson
+            .as_deref()
+            .map(|template| self.resolve(template, now))
+    }
+
+    /// Path of the raw result file for `target_id` in a run at `now`.
+    pub fn raw_path(&self, target_id: &str, now: DateTime<Utc>) -> PathBuf {
+        let template = self.raw.replace("{target}", &target_id.replace('/', "_"));
+        self.resolve(&template, now)
+    }
+
+    fn resolve(&self, template: &str, now: DateTime<Utc>) -> PathBuf {
+        let name = template
+            .replace("{date}", &now.format("%Y-%m-%d").to_string())
+            .replace("{timestamp}", &now.format("%Y%m%dT%H%M%SZ").to_string());
+        self.output_dir.join(name)
+    }
+}
+
 /// Ensure output directories exist.
 pub fn ensure_output_dirs() -> io::Result<()> {
     fs::create_dir_all(OUTPUT_DIR)?;
@@ -26,49 +122,275 @@ pub fn ensure_output_dirs() -> io::Result<()> {
 }
 
 /// Write benchmark results to JSON file.
-pub fn write_results_json(results: &[BenchmarkResult], path: impl AsRef<Path>) -> io::Result<()> {
-    let json = serde_json::to_string_pretty(results)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    fs::write(path, json)
+pub fn write_results_json(results: &[BenchmarkResult], path: impl AsRef<Path>) -> Result<()> {
+    let file = BufWriter::new(fs::File::create(path)?);
+    stream_results_json(results.iter().cloned(), file)
+}
+
+/// Stream benchmark results to `writer` as a pretty-printed JSON array.
+///
+/// Each result is serialized straight to the writer as it is produced, so
+/// large runs never hold the whole document in memory.
+pub fn stream_results_json(
+    results: impl Iterator<Item = BenchmarkResult>,
+    writer: impl Write,
+) -> Result<()> {
+    let mut serializer = serde_json::Serializer::pretty(writer);
+    let mut seq = serializer.serialize_seq(None).map_err(json_error)?;
+    for result in results {
+        seq.serialize_element(&result).map_err(json_error)?;
+    }
+    seq.end().map_err(json_error)?;
+    serializer.into_inner().flush()?;
+    Ok(())
+}
+
+/// Write benchmark results to a newline-delimited JSON file.
+pub fn write_results_ndjson(results: &[BenchmarkResult], path: impl AsRef<Path>) -> Result<()> {
+    let file = BufWriter::new(fs::File::create(path)?);
+    stream_results_ndjson(results.iter().cloned(), file)
+}
+
+/// Stream benchmark results to `writer` as newline-delimited JSON.
+///
+/// Each result is written as one compact JSON object followed by `\n`.
+pub fn stream_results_ndjson(
+    results: impl Iterator<Item = BenchmarkResult>,
+    mut writer: impl Write,
+) -> Result<()> {
+    for result in results {
+        serde_json::to_writer(&mut writer, &result).map_err(json_error)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Report write failures surfaced by serde_json as I/O errors.
+fn json_error(err: serde_json::Error) -> BenchmarkError {
+    if err.is_io() {
+        BenchmarkError::Io(err.into())
+    } else {
+        BenchmarkError::Serialization(err)
+    }
 }
 
 /// Write individual result to raw directory.
-pub fn write_raw_result(result: &BenchmarkResult) -> io::Result<()> {
+pub fn write_raw_result(result: &BenchmarkResult) -> Result<()> {
     ensure_output_dirs()?;
     let filename = format!("{}/{}.json", RAW_DIR, result.target_id.replace('/', "_"));
-    let json = serde_json::to_string_pretty(result)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    fs::write(filename, json)
+    let json = serde_json::to_string_pretty(result)?;
+    fs::write(filename, json)?;
+    Ok(())
 }
 
 /// Write summary markdown file.
-pub fn write_summary(results: &[BenchmarkResult]) -> io::Result<()> {
+pub fn write_summary(results: &[BenchmarkResult]) -> Result<()> {
     ensure_output_dirs()?;
     let summary = markdown::generate_summary(results);
-    fs::write(SUMMARY_FILE, summary)
+    fs::write(SUMMARY_FILE, summary)?;
+    Ok(())
 }
 
 /// Write all benchmark outputs (raw JSON and summary).
-pub fn write_all_outputs(results: &[BenchmarkResult]) -> io::Result<()> {
-    ensure_output_dirs()?;
+///
+/// Every result is validated first, so nothing is written if any result
+/// is malformed.
+pub fn write_all_outputs(results: &[BenchmarkResult]) -> Result<()> {
+    write_all_outputs_with(results, &OutputNaming::default()).map(|_| ())
+}
+
+/// Write all benchmark outputs under the file names from `naming`.
+///
+/// Placeholders are expanded once, so every file of a run shares the same
+/// timestamp. Returns the paths written, raw results first.
+pub fn write_all_outputs_with(
+    results: &[BenchmarkResult],
+    naming: &OutputNaming,
+) -> Result<Vec<PathBuf>> {
+    for result in results {
+        result.validate()?;
+    }
+
+    if naming.output_dir == Path::new(OUTPUT_DIR) {
+        ensure_output_dirs()?;
+    } else {
+        fs::create_dir_all(&naming.output_dir)?;
+    }
+    let now = Utc::now();
+    let mut written = Vec::with_capacity(results.len() + 3);
 
     // Write individual raw results
     for result in results {
-        write_raw_result(result)?;
+        let path = naming.raw_path(&result.target_id, now);
+        create_parent_dir(&path)?;
+        fs::write(&path, serde_json::to_string_pretty(result)?)?;
+        written.push(path);
     }
 
     // Write combined JSON
-    write_results_json(results, format!("{}/all_results.json", OUTPUT_DIR))?;
+    let path = naming.all_results_path(now);
+    create_parent_dir(&path)?;
+    write_results_json(results, &path)?;
+    written.push(path);
+
+    // Write NDJSON, if configured
+    if let Some(path) = naming.ndjson_path(now) {
+        create_parent_dir(&path)?;
+        write_results_ndjson(results, &path)?;
+        written.push(path);
+    }
 
     // Write summary
-    write_summary(results)?;
+    let path = naming.summary_path(now);
+    create_parent_dir(&path)?;
+    let pointers: Vec<&str> = naming.summary_metrics.iter().map(String::as_str).collect();
+    fs::write(
+        &path,
+        markdown::generate_summary_with_metrics(results, &pointers),
+    )?;
+    written.push(path);
 
-    Ok(())
+    Ok(written)
+}
+
+/// Create the directory `path` will be written into, if needed.
+fn create_parent_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
 }
 
 /// Read results from JSON file.
-pub fn read_results_json(path: impl AsRef<Path>) -> io::Result<Vec<BenchmarkResult>> {
+pub fn read_results_json(path: impl AsRef<Path>) -> Result<Vec<BenchmarkResult>> {
     let content = fs::read_to_string(path)?;
-    serde_json::from_str(&content)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Read results from a newline-delimited JSON file.
+///
+/// Blank lines, such as a trailing newline at end of file, are skipped.
+pub fn read_results_ndjson(path: impl AsRef<Path>) -> Result<Vec<BenchmarkResult>> {
+    let reader = BufReader::new(fs::File::open(path)?);
+    let mut results = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        results.push(serde_json::from_str(&line)?);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streamed_json_round_trips() {
+        let results = vec![
+            BenchmarkResult::new("a", serde_json::json!({"latency_ms": 12})),
+            BenchmarkResult::new("b", serde_json::json!({})).with_tags(vec!["cost".to_string()]),
+            BenchmarkResult::failed("c", "timed out after 5s"),
+        ];
+
+        let mut buf = Vec::new();
+        stream_results_json(results.clone().into_iter(), &mut buf).unwrap();
+        let parsed: Vec<BenchmarkResult> = serde_json::from_slice(&buf).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&results).unwrap()
+        );
+        assert_eq!(buf, serde_json::to_vec_pretty(&results).unwrap());
+    }
+
+    #[test]
+    fn test_ndjson_round_trips() {
+        let results = vec![
+            BenchmarkResult::new("a", serde_json::json!({"latency_ms": 12})),
+            BenchmarkResult::failed("b", "timed out after 5s"),
+        ];
+        let path = std::env::temp_dir().join(format!("observatory-{}.ndjson", std::process::id()));
+
+        write_results_ndjson(&results, &path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+
+        // Trailing blank lines are tolerated
+        fs::write(&path, format!("{}\n\n", content)).unwrap();
+        let parsed = read_results_ndjson(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&results).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_output_naming_templates() {
+        let now = DateTime::parse_from_rfc3339("2025-03-14T09:26:53Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let defaults = OutputNaming::default();
+        assert_eq!(defaults.summary_path(now), Path::new(SUMMARY_FILE));
+        assert_eq!(defaults.ndjson_path(now), None);
+        assert_eq!(
+            defaults
+                .clone()
+                .with_ndjson("all_results.ndjson")
+                .ndjson_path(now),
+            Some(PathBuf::from(NDJSON_FILE))
+        );
+        assert_eq!(
+            defaults.raw_path("observatory/ingest", now),
+            Path::new(RAW_DIR).join("observatory_ingest.json")
+        );
+
+        let naming = OutputNaming {
+            summary: "summary-{date}.md".to_string(),
+            all_results: "all_results-{timestamp}.json".to_string(),
+            raw: "raw/{date}/{target}.json".to_string(),
+            ..OutputNaming::default()
+        };
+        assert_eq!(
+            naming.summary_path(now),
+            Path::new(OUTPUT_DIR).join("summary-2025-03-14.md")
+        );
+        assert_eq!(
+            naming.all_results_path(now),
+            Path::new(OUTPUT_DIR).join("all_results-20250314T092653Z.json")
+        );
+        assert_eq!(
+            naming.raw_path("a/b", now),
+            Path::new(RAW_DIR).join("2025-03-14/a_b.json")
+        );
+    }
+
+    #[test]
+    fn test_output_naming_with_output_dir() {
+        let now = Utc::now();
+        let naming = OutputNaming::default().with_output_dir("/tmp/bench");
+
+        assert_eq!(naming.summary_path(now), Path::new("/tmp/bench/summary.md"));
+        assert_eq!(
+            naming.all_results_path(now),
+            Path::new("/tmp/bench/all_results.json")
+        );
+        assert_eq!(
+            naming.raw_path("a/b", now),
+            Path::new("/tmp/bench/raw/a_b.json")
+        );
+    }
+
+    #[test]
+    fn test_streamed_json_empty() {
+        let mut buf = Vec::new();
+        stream_results_json(std::iter::empty(), &mut buf).unwrap();
+        assert_eq!(buf, b"[]");
+    }
 }
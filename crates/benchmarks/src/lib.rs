@@ -20,17 +20,20 @@
 //! # Modules
 //!
 //! - [`result`] - The canonical `BenchmarkResult` struct
+//! - [`error`] - The `BenchmarkError` type returned by write and validate paths
 //! - [`io`] - I/O operations for reading/writing results
 //! - [`markdown`] - Markdown report generation
 
 #![warn(missing_docs, rust_2018_idioms)]
 #![deny(unsafe_code)]
 
+pub mod error;
 pub mod io;
 pub mod markdown;
 pub mod result;
 
-pub use result::BenchmarkResult;
+pub use error::{BenchmarkError, Result};
+pub use result::{BenchmarkResult, BenchmarkStatus};
 
 use chrono::Utc;
 
@@ -71,8 +74,9 @@ pub fn run_all_benchmarks() -> Vec<BenchmarkResult> {
 ///
 /// # Errors
 ///
-/// Returns an `io::Error` if writing output files fails.
-pub fn run_and_write_all() -> std::io::Result<Vec<BenchmarkResult>> {
+/// Returns a `BenchmarkError` if a result is invalid or writing output
+/// files fails.
+pub fn run_and_write_all() -> Result<Vec<BenchmarkResult>> {
     let results = run_all_benchmarks();
     io::write_all_outputs(&results)?;
     Ok(results)
@@ -94,5 +98,61 @@ mod tests {
         assert_eq!(result.target_id, "test");
         assert!(result.metrics.is_object());
         assert!(result.timestamp <= Utc::now());
+        assert!(result.tags.is_empty());
+        assert_eq!(result.status, BenchmarkStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_failed_result_serializes_reason() {
+        let result = BenchmarkResult::failed("slow", "timed out after 5s");
+        assert!(result.is_failed());
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["status"], "failed");
+        assert_eq!(json["error"], "timed out after 5s");
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_results() {
+        let valid = BenchmarkResult::new("ok", serde_json::json!({}));
+        assert!(valid.validate().is_ok());
+
+        let empty_id = BenchmarkResult::new(" ", serde_json::json!({}));
+        let err = empty_id.validate().unwrap_err();
+        assert!(matches!(err, BenchmarkError::Validation(_)));
+
+        let scalar = BenchmarkResult::new("scalar", serde_json::json!(42));
+        let err = scalar.validate().unwrap_err();
+        assert!(err.to_string().contains("scalar"));
+    }
+
+    #[test]
+    fn test_matches_filter_on_id_and_tags() {
+        let result = BenchmarkResult::new("observatory/ingest", serde_json::json!({}))
+            .with_tags(vec!["latency".to_string()]);
+        assert!(result.matches_filter("ingest"));
+        assert!(result.matches_filter("Latency"));
+        assert!(!result.matches_filter("cost"));
+    }
+
+    #[test]
+    fn test_get_metric_by_pointer() {
+        let result = BenchmarkResult::new(
+            "nested",
+            serde_json::json!({"latency": {"p50": 12, "p99": 48.5}, "model": "gpt-4o"}),
+        );
+        assert_eq!(result.get_metric_f64("/latency/p99"), Some(48.5));
+        assert_eq!(result.get_metric_f64("/latency/p50"), Some(12.0));
+        assert_eq!(result.get_metric("/model").unwrap(), "gpt-4o");
+        assert_eq!(result.get_metric_f64("/model"), None);
+        assert_eq!(result.get_metric("/latency/p95"), None);
+        assert_eq!(result.get_metric("latency"), None);
+    }
+
+    #[test]
+    fn test_tags_default_when_missing() {
+        let json = r#"{"target_id":"t","metrics":{},"timestamp":"2025-01-01T00:00:00Z"}"#;
+        let result: BenchmarkResult = serde_json::from_str(json).unwrap();
+        assert!(result.tags.is_empty());
     }
 }
@@ -8,6 +8,14 @@ use std::fmt::Write;
 
 /// Generate a markdown summary from benchmark results.
 pub fn generate_summary(results: &[BenchmarkResult]) -> String {
+    generate_summary_with_metrics(results, &[])
+}
+
+/// Generate a markdown summary that also tabulates the metrics at `pointers`.
+///
+/// With no pointers this is the same as [`generate_summary`]; otherwise a
+/// `Metrics` section built by [`generate_metric_table`] follows the results.
+pub fn generate_summary_with_metrics(results: &[BenchmarkResult], pointers: &[&str]) -> String {
     let mut output = String::new();
 
     writeln!(output, "# Benchmark Summary").unwrap();
@@ -20,7 +28,10 @@ pub fn generate_summary(results: &[BenchmarkResult]) -> String {
     writeln!(output, "|-----------|-----------|---------|").unwrap();
 
     for result in results {
-        let metrics_preview = result.metrics.to_string();
+        let metrics_preview = match &result.error {
+            Some(reason) if result.is_failed() => format!("FAILED: {}", reason),
+            _ => result.metrics.to_string(),
+        };
         let metrics_short = if metrics_preview.len() > 50 {
             format!("{}...", &metrics_preview[..47])
         } else {
@@ -32,7 +43,15 @@ pub fn generate_summary(results: &[BenchmarkResult]) -> String {
             result.target_id,
             result.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
             metrics_short
-        ).unwrap();
+        )
+        .unwrap();
+    }
+
+    if !pointers.is_empty() {
+        writeln!(output).unwrap();
+        writeln!(output, "## Metrics").unwrap();
+        writeln!(output).unwrap();
+        output.push_str(&generate_metric_table(results, pointers));
     }
 
     writeln!(output).unwrap();
@@ -42,6 +61,32 @@ pub fn generate_summary(results: &[BenchmarkResult]) -> String {
     output
 }
 
+/// Generate a table of specific metrics, one column per JSON Pointer.
+///
+/// Each cell holds the value at that pointer (see
+/// [`BenchmarkResult::get_metric`]), or `-` when the result lacks it.
+pub fn generate_metric_table(results: &[BenchmarkResult], pointers: &[&str]) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "| Target ID | {} |", pointers.join(" | ")).unwrap();
+    let separator = "---------|".repeat(pointers.len());
+    writeln!(output, "|-----------|{}", separator).unwrap();
+
+    for result in results {
+        let cells: Vec<String> = pointers
+            .iter()
+            .map(|pointer| match result.get_metric(pointer) {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(value) => value.to_string(),
+                None => "-".to_string(),
+            })
+            .collect();
+        writeln!(output, "| {} | {} |", result.target_id, cells.join(" | ")).unwrap();
+    }
+
+    output
+}
+
 /// Generate detailed markdown report.
 pub fn generate_detailed_report(results: &[BenchmarkResult]) -> String {
     let mut output = String::new();
@@ -58,10 +103,45 @@ pub fn generate_detailed_report(results: &[BenchmarkResult]) -> String {
         writeln!(output).unwrap();
         writeln!(output, "**Metrics:**").unwrap();
         writeln!(output, "```json").unwrap();
-        writeln!(output, "{}", serde_json::to_string_pretty(&result.metrics).unwrap_or_default()).unwrap();
+        writeln!(
+            output,
+            "{}",
+            serde_json::to_string_pretty(&result.metrics).unwrap_or_default()
+        )
+        .unwrap();
         writeln!(output, "```").unwrap();
         writeln!(output).unwrap();
     }
 
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_result() -> BenchmarkResult {
+        BenchmarkResult::new(
+            "nested",
+            serde_json::json!({"latency": {"p50": 12, "p99": 48.5}, "model": "gpt-4o"}),
+        )
+    }
+
+    #[test]
+    fn test_metric_table() {
+        let table = generate_metric_table(&[nested_result()], &["/latency/p99", "/missing"]);
+        assert!(table.contains("| Target ID | /latency/p99 | /missing |"));
+        assert!(table.contains("| nested | 48.5 | - |"));
+    }
+
+    #[test]
+    fn test_summary_includes_metric_table_when_configured() {
+        let results = [nested_result()];
+        assert!(!generate_summary(&results).contains("## Metrics"));
+
+        let summary = generate_summary_with_metrics(&results, &["/model"]);
+        assert!(summary.contains("## Metrics"));
+        assert!(summary.contains("| nested | gpt-4o |"));
+        assert!(summary.ends_with("Total benchmarks: 1\n"));
+    }
+}
@@ -3,9 +3,21 @@
 //! This module provides the canonical BenchmarkResult struct used for
 //! cross-project benchmark consistency.
 
+use crate::error::{BenchmarkError, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Outcome of a benchmark run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchmarkStatus {
+    /// The benchmark completed and its metrics are valid.
+    #[default]
+    Succeeded,
+    /// The benchmark did not complete (e.g. it timed out).
+    Failed,
+}
+
 /// Canonical benchmark result structure.
 ///
 /// This struct provides a standardized format for benchmark results
@@ -18,6 +30,18 @@ pub struct BenchmarkResult {
     pub metrics: serde_json::Value,
     /// Timestamp when the benchmark was executed.
     pub timestamp: DateTime<Utc>,
+    /// Categories of the benchmark target (e.g. "latency", "cost").
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Whether the benchmark completed.
+    #[serde(default)]
+    pub status: BenchmarkStatus,
+    /// Why the benchmark failed, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Wall-clock duration of the measured operation in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
 }
 
 impl BenchmarkResult {
@@ -27,6 +51,75 @@ impl BenchmarkResult {
             target_id: target_id.into(),
             metrics,
             timestamp: Utc::now(),
+            tags: Vec::new(),
+            status: BenchmarkStatus::Succeeded,
+            error: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a failed BenchmarkResult with empty metrics.
+    pub fn failed(target_id: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            status: BenchmarkStatus::Failed,
+            error: Some(reason.into()),
+            ..Self::new(target_id, serde_json::json!({}))
         }
     }
+
+    /// Check that the result is well-formed enough to be written.
+    ///
+    /// The target ID must be non-empty (it names the raw output file) and
+    /// metrics must be a JSON object.
+    pub fn validate(&self) -> Result<()> {
+        if self.target_id.trim().is_empty() {
+            return Err(BenchmarkError::validation("target_id must not be empty"));
+        }
+        if !self.metrics.is_object() {
+            return Err(BenchmarkError::validation(format!(
+                "metrics for {} must be a JSON object",
+                self.target_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether the benchmark failed.
+    pub fn is_failed(&self) -> bool {
+        self.status == BenchmarkStatus::Failed
+    }
+
+    /// Set the duration of the measured operation.
+    pub fn with_duration_ms(mut self, duration_ms: u64) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+
+    /// Set the tags for this result.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Whether this result matches a filter pattern.
+    ///
+    /// Matches when the target ID contains `filter`, or when any tag equals
+    /// it (case-insensitively).
+    pub fn matches_filter(&self, filter: &str) -> bool {
+        self.target_id.contains(filter) || self.tags.iter().any(|t| t.eq_ignore_ascii_case(filter))
+    }
+
+    /// Look up a metric by JSON Pointer, e.g. `/latency/p99`.
+    ///
+    /// The empty pointer `""` returns the whole metrics value.
+    pub fn get_metric(&self, pointer: &str) -> Option<&serde_json::Value> {
+        self.metrics.pointer(pointer)
+    }
+
+    /// Look up a numeric metric by JSON Pointer.
+    ///
+    /// Returns `None` when the metric is missing or not a number.
+    pub fn get_metric_f64(&self, pointer: &str) -> Option<f64> {
+        self.get_metric(pointer)?.as_f64()
+    }
 }
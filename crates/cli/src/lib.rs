@@ -2,22 +2,144 @@
 //!
 //! This crate provides the command-line interface for LLM Observatory,
 //! including the canonical benchmark `run` subcommand.
+//!
+//! # Exit codes
+//!
+//! - `0` ([`EXIT_SUCCESS`]): every benchmark succeeded
+//! - `1` ([`EXIT_BENCHMARKS_FAILED`]): the tool worked, but at least one
+//!   benchmark failed or `validate-execution` found the execution invalid
+//! - `2` ([`EXIT_ERROR`]): the tool itself failed (bad usage, I/O or
+//!   serialization errors)
 
 #![warn(missing_docs, rust_2018_idioms)]
 #![deny(unsafe_code)]
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use llm_observatory_adapters::{
+    all_async_targets, all_targets, run_all_benchmarks_with, AsyncBenchTarget, BenchTarget,
+    RunOptions,
+};
+use llm_observatory_benchmarks::{io, BenchmarkError, BenchmarkResult};
+use llm_observatory_core::{ExecutionResult, ExecutionSpan};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Quiet period after a file change before `watch` re-runs benchmarks.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Exit code when every benchmark succeeded.
+pub const EXIT_SUCCESS: i32 = 0;
+
+/// Exit code when outputs were written but at least one benchmark failed.
+pub const EXIT_BENCHMARKS_FAILED: i32 = 1;
+
+/// Exit code for usage, I/O and other tool errors (matches clap's usage errors).
+pub const EXIT_ERROR: i32 = 2;
+
+/// Error returned by [`run`] when benchmarks ran but some of them failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchmarksFailed {
+    /// IDs of the failed targets
+    pub failed: Vec<String>,
+    /// Number of benchmarks run
+    pub total: usize,
+}
+
+impl fmt::Display for BenchmarksFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} benchmarks failed: {}",
+            self.failed.len(),
+            self.total,
+            self.failed.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for BenchmarksFailed {}
+
+/// Error returned by [`run`] when `validate-execution` finds the execution invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidExecution {
+    /// Validation errors reported for the execution
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for InvalidExecution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid execution: {}", self.errors.join("; "))
+    }
+}
+
+impl std::error::Error for InvalidExecution {}
+
+/// Process exit code for an error returned by [`run`].
+pub fn exit_code(err: &(dyn std::error::Error + 'static)) -> i32 {
+    if err.is::<BenchmarksFailed>() || err.is::<InvalidExecution>() {
+        EXIT_BENCHMARKS_FAILED
+    } else {
+        EXIT_ERROR
+    }
+}
+
+/// Check that no benchmark failed.
+fn check_outcomes(results: &[BenchmarkResult]) -> Result<(), BenchmarksFailed> {
+    let failed: Vec<String> = results
+        .iter()
+        .filter(|result| result.is_failed())
+        .map(|result| result.target_id.clone())
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(BenchmarksFailed {
+            failed,
+            total: results.len(),
+        })
+    }
+}
 
 /// LLM Observatory CLI.
 #[derive(Parser, Debug)]
 #[command(name = "observatory")]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
+    /// Suppress progress and summary text.
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Print a machine-readable JSON summary on stdout instead of text.
+    #[arg(long, global = true)]
+    pub json: bool,
+
     /// Subcommand to run.
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// Benchmark output format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// JSON results only.
+    Json,
+    /// Markdown summary only.
+    Markdown,
+    /// Both JSON results and the Markdown summary.
+    #[default]
+    Both,
+    /// Newline-delimited JSON results, one per line, alongside the
+    /// standard outputs.
+    Ndjson,
+}
+
 /// Available CLI commands.
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -28,73 +150,926 @@ pub enum Commands {
     /// - benchmarks/output/raw/ - Individual JSON files per benchmark
     /// - benchmarks/output/all_results.json - Combined JSON file
     /// - benchmarks/output/summary.md - Markdown summary
+    /// - benchmarks/output/all_results.ndjson - One JSON result per line
+    ///   (with `--format ndjson`)
+    ///
+    /// The summary and combined file names can be changed with
+    /// `--summary-name` and `--results-name`.
     Run {
         /// Output directory override (optional).
         #[arg(short, long)]
         output: Option<String>,
 
-        /// Output format: json, markdown, or both (default: both).
-        #[arg(short, long, default_value = "both")]
-        format: String,
+        /// Output format: json, markdown, both, or ndjson (default: both).
+        #[arg(short, long, value_enum, default_value = "both")]
+        format: OutputFormat,
 
         /// Verbose output.
         #[arg(short, long)]
         verbose: bool,
+
+        /// Only keep benchmarks whose target ID contains, or one of whose
+        /// tags equals, this pattern.
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Fail any benchmark that runs longer than this many seconds.
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// Number of benchmarks to run in parallel.
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// File name template for the Markdown summary, relative to
+        /// benchmarks/output/. Supports `{date}` and `{timestamp}`.
+        #[arg(long)]
+        summary_name: Option<String>,
+
+        /// File name template for the combined JSON results, relative to
+        /// benchmarks/output/. Supports `{date}` and `{timestamp}`.
+        #[arg(long)]
+        results_name: Option<String>,
+
+        /// JSON Pointer of a metric to tabulate in the Markdown summary,
+        /// e.g. `/latency/p99`. May be repeated.
+        #[arg(long = "summary-metric")]
+        summary_metrics: Vec<String>,
     },
 
-    /// Show benchmark status and configuration.
+    /// Show registered benchmark targets and output configuration.
+    ///
+    /// Use as a preflight check before `run`: reports how many targets are
+    /// registered and whether the output directories exist and are writable.
     Status {
-        /// Show detailed status information.
+        /// Also list each registered target ID.
         #[arg(short, long)]
         detailed: bool,
     },
+
+    /// Rebuild and re-run all benchmarks whenever watched files change.
+    ///
+    /// Runs once on start, then again after each burst of changes, printing
+    /// the metrics that moved since the previous run. Each run goes through
+    /// `cargo run -p llm-observatory-cli -- run`, so source edits under
+    /// `crates/` are compiled in before the benchmarks run; a failed build is
+    /// reported and the previous results are kept. Changes under the output
+    /// directory and `target/` are ignored. Stop with Ctrl-C.
+    Watch {
+        /// Files or directories to watch recursively (default: `crates`,
+        /// relative to the workspace root).
+        #[arg(default_value = "crates")]
+        paths: Vec<String>,
+
+        /// Output format: json, markdown, both, or ndjson (default: both).
+        #[arg(short, long, value_enum, default_value = "both")]
+        format: OutputFormat,
+
+        /// Output directory override (optional).
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Only report changes to this metric, given as a JSON Pointer
+        /// (e.g. `/latency/p99`). May be repeated.
+        #[arg(long = "metric")]
+        metrics: Vec<String>,
+    },
+
+    /// Validate an execution result file offline.
+    ///
+    /// The file holds either a serialized `ExecutionResult` or a raw JSON
+    /// array of execution spans. Prints "valid" or one line per validation
+    /// error, and exits with status 1 when the execution is invalid.
+    ValidateExecution {
+        /// Path to the execution JSON file.
+        path: String,
+    },
+
+    /// Print a shell completion script to stdout.
+    ///
+    /// For example: `observatory completions bash > /etc/bash_completion.d/observatory`.
+    Completions {
+        /// Shell to generate completions for.
+        #[arg(value_enum)]
+        shell: Shell,
+    },
 }
 
 /// Run the CLI with the given arguments.
 ///
 /// # Returns
 ///
-/// Returns `Ok(())` on success, or an error if the command fails.
+/// Returns `Ok(())` on success, [`BenchmarksFailed`] if `run` wrote its
+/// outputs but some benchmarks failed, [`InvalidExecution`] if
+/// `validate-execution` found the execution invalid, or another error if the
+/// command itself failed. Map errors to a process exit code with [`exit_code`].
 pub fn run() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
+    // Prose goes to stdout only when neither flag asks for silence or JSON
+    let prose = !cli.quiet && !cli.json;
 
     match cli.command {
         Commands::Run {
-            output: _,
-            format: _,
+            output,
+            format,
             verbose,
+            filter,
+            timeout,
+            jobs,
+            summary_name,
+            results_name,
+            summary_metrics,
         } => {
-            if verbose {
+            if prose && verbose {
                 println!("Running all benchmarks...");
             }
 
-            let results = llm_observatory_benchmarks::run_and_write_all()?;
+            let options = RunOptions {
+                timeout: timeout.map(Duration::from_secs),
+                concurrency: jobs,
+            };
+            let mut naming = output_naming(output).with_summary_metrics(summary_metrics);
+            if let Some(summary_name) = summary_name {
+                naming.summary = summary_name;
+            }
+            if let Some(results_name) = results_name {
+                naming.all_results = results_name;
+            }
+            let (results, outputs) = run_and_write(&options, filter.as_deref(), format, &naming)?;
 
-            println!("Completed {} benchmarks", results.len());
-            println!("Results written to benchmarks/output/");
+            if cli.json {
+                let failures: Vec<_> = results
+                    .iter()
+                    .filter(|result| result.is_failed())
+                    .map(|result| {
+                        serde_json::json!({
+                            "target_id": result.target_id,
+                            "error": result.error,
+                        })
+                    })
+                    .collect();
+                let summary = serde_json::json!({
+                    "completed": results.len(),
+                    "failed": failures.len(),
+                    "output_dir": naming.output_dir,
+                    "outputs": outputs,
+                    "failures": failures,
+                });
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+            } else if prose {
+                println!("Completed {} benchmarks", results.len());
+                println!("Results written to {}/", naming.output_dir.display());
 
-            if verbose {
-                for result in &results {
-                    println!("  - {}: {}", result.target_id, result.metrics);
+                if verbose {
+                    for result in &results {
+                        match &result.error {
+                            Some(reason) => {
+                                println!("  - {}: FAILED ({})", result.target_id, reason)
+                            }
+                            None => println!("  - {}: {}", result.target_id, result.metrics),
+                        }
+                    }
                 }
             }
 
+            check_outcomes(&results)?;
             Ok(())
         }
         Commands::Status { detailed } => {
+            let targets = all_targets();
+            let format = OutputFormat::default()
+                .to_possible_value()
+                .map(|value| value.get_name().to_string())
+                .unwrap_or_default();
+
+            if cli.json {
+                let directories: serde_json::Map<_, _> = [io::OUTPUT_DIR, io::RAW_DIR]
+                    .into_iter()
+                    .map(|dir| (dir.to_string(), dir_status(Path::new(dir)).into()))
+                    .collect();
+                let mut summary = serde_json::json!({
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "registered_targets": targets.len(),
+                    "output_dir": resolve_dir(io::OUTPUT_DIR),
+                    "default_format": format,
+                    "directories": directories,
+                });
+                if detailed {
+                    let ids: Vec<String> = targets.iter().map(|target| target.id()).collect();
+                    summary["targets"] = ids.into();
+                }
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+                return Ok(());
+            }
+            if !prose {
+                return Ok(());
+            }
+
             println!("LLM Observatory Benchmark System");
             println!("Version: {}", env!("CARGO_PKG_VERSION"));
+            println!("Registered targets: {}", targets.len());
+            println!("Output directory: {}", resolve_dir(io::OUTPUT_DIR));
+            println!("Default format: {}", format);
+
+            println!("\nOutput directories:");
+            for dir in [io::OUTPUT_DIR, io::RAW_DIR] {
+                println!("  - {}/: {}", dir, dir_status(Path::new(dir)));
+            }
 
             if detailed {
-                println!("\nOutput directories:");
-                println!("  - benchmarks/output/");
-                println!("  - benchmarks/output/raw/");
-                println!("\nOutput files:");
-                println!("  - benchmarks/output/summary.md");
-                println!("  - benchmarks/output/all_results.json");
+                println!("\nTargets:");
+                if targets.is_empty() {
+                    println!("  (none registered)");
+                }
+                for target in &targets {
+                    match target.description() {
+                        Some(description) => println!("  - {}: {}", target.id(), description),
+                        None => println!("  - {}", target.id()),
+                    }
+                }
+            }
+
+            Ok(())
+        }
+        Commands::Watch {
+            paths,
+            format,
+            output,
+            metrics,
+        } => watch(&paths, format, output, &metrics, prose),
+        Commands::ValidateExecution { path } => {
+            let errors = validate_execution_file(Path::new(&path))?;
+
+            if cli.json {
+                let summary = serde_json::json!({
+                    "path": path,
+                    "valid": errors.is_empty(),
+                    "errors": errors,
+                });
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+            } else if prose {
+                if errors.is_empty() {
+                    println!("valid");
+                }
+                for error in &errors {
+                    println!("{}", error);
+                }
             }
 
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(InvalidExecution { errors }.into())
+            }
+        }
+        Commands::Completions { shell } => {
+            write_completions(shell, &mut std::io::stdout());
             Ok(())
         }
     }
 }
+
+/// Validate the execution stored at `path`, returning its validation errors.
+///
+/// A JSON array is treated as raw spans and assembled with
+/// [`ExecutionResult::from_spans`]; anything else must be a serialized
+/// [`ExecutionResult`], which is re-validated. Spans that cannot be assembled
+/// (no repo span, or more than one) are reported as a validation error.
+fn validate_execution_file(path: &Path) -> Result<Vec<String>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    let value: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| format!("could not parse {}: {}", path.display(), e))?;
+    let describe = |e: serde_json::Error| format!("unexpected JSON in {}: {}", path.display(), e);
+
+    let result = if value.is_array() {
+        let spans: Vec<ExecutionSpan> = serde_json::from_value(value).map_err(describe)?;
+        match ExecutionResult::from_spans(spans) {
+            Ok(result) => result,
+            Err(e) => return Ok(vec![e.to_string()]),
+        }
+    } else {
+        let result: ExecutionResult = serde_json::from_value(value).map_err(describe)?;
+        result.validate()
+    };
+    Ok(result.validation_errors)
+}
+
+/// Write the completion script for `shell` to `out`.
+fn write_completions(shell: Shell, out: &mut dyn std::io::Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+/// Run all benchmarks and write their outputs under the names in `naming`.
+///
+/// Returns the results and the paths written.
+fn run_and_write(
+    options: &RunOptions,
+    filter: Option<&str>,
+    format: OutputFormat,
+    naming: &io::OutputNaming,
+) -> Result<(Vec<BenchmarkResult>, Vec<String>), String> {
+    let results = run_filtered(all_async_targets(), filter, options);
+    let mut naming = naming.clone();
+    if format == OutputFormat::Ndjson && naming.ndjson.is_none() {
+        naming = naming.with_ndjson("all_results.ndjson");
+    }
+    let outputs = io::write_all_outputs_with(&results, &naming)
+        .map_err(describe_write_error)?
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect();
+    Ok((results, outputs))
+}
+
+/// Run the targets matching `filter`, or all of them without one.
+///
+/// Registered targets are filtered by ID and tag before anything runs. The
+/// built-in benchmarks used when no targets are registered are cheap and are
+/// filtered by their results instead.
+fn run_filtered(
+    targets: Vec<Arc<dyn AsyncBenchTarget>>,
+    filter: Option<&str>,
+    options: &RunOptions,
+) -> Vec<BenchmarkResult> {
+    let Some(filter) = filter else {
+        return run_all_benchmarks_with(&targets, options);
+    };
+    if targets.is_empty() {
+        let mut results = run_all_benchmarks_with(&targets, options);
+        results.retain(|result| result.matches_filter(filter));
+        return results;
+    }
+
+    let selected: Vec<_> = targets
+        .into_iter()
+        .filter(|target| target_matches_filter(target.as_ref(), filter))
+        .collect();
+    if selected.is_empty() {
+        // An empty list would fall back to the built-in benchmarks
+        return Vec::new();
+    }
+    run_all_benchmarks_with(&selected, options)
+}
+
+/// [`BenchmarkResult::matches_filter`] applied to a target before it runs.
+fn target_matches_filter(target: &dyn AsyncBenchTarget, filter: &str) -> bool {
+    target.id().contains(filter) || target.tags().iter().any(|t| t.eq_ignore_ascii_case(filter))
+}
+
+/// Output naming for `run` and `watch`, honouring an `--output` override.
+fn output_naming(output: Option<String>) -> io::OutputNaming {
+    match output {
+        Some(dir) => io::OutputNaming::default().with_output_dir(dir),
+        None => io::OutputNaming::default(),
+    }
+}
+
+/// The `cargo run` invocation `watch` uses for each run.
+///
+/// Going through cargo rebuilds the CLI first, so each run sees the current
+/// sources rather than the benchmark registry compiled into this process.
+fn watch_run_command(format: OutputFormat, output_dir: &Path) -> Command {
+    let format = format
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .args([
+            "run",
+            "--quiet",
+            "-p",
+            env!("CARGO_PKG_NAME"),
+            "--bin",
+            "observatory",
+        ])
+        .args(["--", "--quiet", "run", "--format", &format, "--output"])
+        .arg(output_dir);
+    command
+}
+
+/// Rebuild and run the benchmarks, returning the results written to
+/// `naming`'s combined JSON file.
+///
+/// A failed build is an error; benchmarks that ran but failed are not.
+fn rebuild_and_run(
+    format: OutputFormat,
+    naming: &io::OutputNaming,
+) -> Result<Vec<BenchmarkResult>, String> {
+    let status = watch_run_command(format, &naming.output_dir)
+        .status()
+        .map_err(|e| format!("could not run cargo: {}", e))?;
+    if status.code() != Some(EXIT_SUCCESS) && status.code() != Some(EXIT_BENCHMARKS_FAILED) {
+        return Err(format!("build or run failed ({})", status));
+    }
+    let path = naming.output_dir.join(&naming.all_results);
+    io::read_results_json(&path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+/// What woke the `watch` loop.
+enum WatchEvent {
+    /// A watched file changed
+    Changed,
+    /// Ctrl-C was pressed
+    Interrupted,
+}
+
+/// Rebuild and re-run benchmarks on file changes until interrupted.
+fn watch(
+    paths: &[String],
+    format: OutputFormat,
+    output: Option<String>,
+    metrics: &[String],
+    prose: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (tx, rx) = mpsc::channel();
+
+    // Benchmark runs write their own outputs and cargo writes build
+    // artifacts, neither of which must retrigger a run
+    let naming = output_naming(output);
+    let cwd = std::env::current_dir()?;
+    let ignored = vec![
+        cwd.join(&naming.output_dir),
+        std::env::var_os("CARGO_TARGET_DIR")
+            .map(|dir| cwd.join(dir))
+            .unwrap_or_else(|| cwd.join("target")),
+    ];
+    let changes = tx.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        let relevant = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) && event
+            .paths
+            .iter()
+            .any(|path| !ignored.iter().any(|dir| path.starts_with(dir)));
+        if relevant {
+            let _ = changes.send(WatchEvent::Changed);
+        }
+    })?;
+    for path in paths {
+        watcher.watch(Path::new(path), RecursiveMode::Recursive)?;
+    }
+
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build();
+        if let Ok(runtime) = runtime {
+            if runtime.block_on(tokio::signal::ctrl_c()).is_ok() {
+                let _ = tx.send(WatchEvent::Interrupted);
+            }
+        }
+    });
+
+    let mut previous = match rebuild_and_run(format, &naming) {
+        Ok(results) => results,
+        Err(reason) => {
+            eprintln!("Error: {}", reason);
+            Vec::new()
+        }
+    };
+    if prose {
+        println!(
+            "Completed {} benchmarks; watching {} for changes (Ctrl-C to stop)",
+            previous.len(),
+            paths.join(", ")
+        );
+    }
+
+    while let Ok(WatchEvent::Changed) = rx.recv() {
+        // Wait for the burst of change events to settle
+        loop {
+            match rx.recv_timeout(WATCH_DEBOUNCE) {
+                Ok(WatchEvent::Changed) => continue,
+                Err(RecvTimeoutError::Timeout) => break,
+                Ok(WatchEvent::Interrupted) | Err(RecvTimeoutError::Disconnected) => {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Keep watching through build errors; the next save may fix them
+        let results = match rebuild_and_run(format, &naming) {
+            Ok(results) => results,
+            Err(reason) => {
+                eprintln!("Error: {}", reason);
+                continue;
+            }
+        };
+        if prose {
+            let changes = metric_changes(&previous, &results, metrics);
+            println!(
+                "Re-ran {} benchmarks: {} changed",
+                results.len(),
+                changes.len()
+            );
+            for change in &changes {
+                println!("  - {}", change);
+            }
+        }
+        previous = results;
+    }
+
+    Ok(())
+}
+
+/// Describe how results changed between two runs, one line per change.
+///
+/// With no `pointers`, every top-level metric is compared. Otherwise only
+/// the metrics at those JSON Pointers are, labelled by target and pointer.
+fn metric_changes(
+    previous: &[BenchmarkResult],
+    current: &[BenchmarkResult],
+    pointers: &[String],
+) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    for result in current {
+        let id = &result.target_id;
+        let Some(before) = previous.iter().find(|p| &p.target_id == id) else {
+            changes.push(format!("{}: new", id));
+            continue;
+        };
+
+        if before.is_failed() != result.is_failed() {
+            let outcome = |r: &BenchmarkResult| if r.is_failed() { "failed" } else { "ok" };
+            changes.push(format!(
+                "{}: {} -> {}",
+                id,
+                outcome(before),
+                outcome(result)
+            ));
+        }
+
+        if !pointers.is_empty() {
+            for pointer in pointers {
+                if let Some(value) = result.get_metric(pointer) {
+                    let label = format!("{}{}", id, pointer);
+                    changes.extend(value_change(&label, before.get_metric(pointer), value));
+                }
+            }
+            continue;
+        }
+
+        let (Some(old), Some(new)) = (before.metrics.as_object(), result.metrics.as_object())
+        else {
+            continue;
+        };
+        for (key, value) in new {
+            let label = format!("{}.{}", id, key);
+            changes.extend(value_change(&label, old.get(key), value));
+        }
+    }
+
+    for before in previous {
+        if !current.iter().any(|r| r.target_id == before.target_id) {
+            changes.push(format!("{}: removed", before.target_id));
+        }
+    }
+
+    changes
+}
+
+/// Describe a metric moving from `old` to `new`, if it changed.
+fn value_change(
+    label: &str,
+    old: Option<&serde_json::Value>,
+    new: &serde_json::Value,
+) -> Option<String> {
+    let Some(old) = old else {
+        return Some(format!("{}: new ({})", label, new));
+    };
+    if old == new {
+        return None;
+    }
+    match (old.as_f64(), new.as_f64()) {
+        (Some(old_number), Some(new_number)) if old_number != 0.0 => Some(format!(
+            "{}: {} -> {} ({:+.1}%)",
+            label,
+            old,
+            new,
+            (new_number - old_number) / old_number * 100.0
+        )),
+        _ => Some(format!("{}: {} -> {}", label, old, new)),
+    }
+}
+
+/// Turn a write failure into a message naming what went wrong.
+fn describe_write_error(err: BenchmarkError) -> String {
+    match err {
+        BenchmarkError::Io(e) => format!("could not write to {}/: {}", io::OUTPUT_DIR, e),
+        BenchmarkError::Serialization(e) => format!("could not serialize results: {}", e),
+        BenchmarkError::Validation(msg) => format!("invalid benchmark result: {}", msg),
+    }
+}
+
+/// Absolute form of a path relative to the working directory.
+fn resolve_dir(dir: &str) -> String {
+    std::env::current_dir()
+        .map(|cwd| cwd.join(dir).display().to_string())
+        .unwrap_or_else(|_| dir.to_string())
+}
+
+/// Describe whether `dir` exists and can be written to.
+///
+/// Writability is checked by creating and removing a probe file, since
+/// permission bits alone don't account for read-only mounts or ACLs.
+fn dir_status(dir: &Path) -> &'static str {
+    if !dir.is_dir() {
+        return "missing (created on run)";
+    }
+    let probe = dir.join(".observatory-write-check");
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            "exists, writable"
+        }
+        Err(_) => "exists, not writable",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_dir_status_reports_missing_and_writable() {
+        let dir = std::env::temp_dir().join(format!("observatory-cli-{}", std::process::id()));
+        assert_eq!(dir_status(&dir), "missing (created on run)");
+
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(dir_status(&dir), "exists, writable");
+        assert!(!dir.join(".observatory-write-check").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_default_output_format_is_both() {
+        let cli = Cli::parse_from(["observatory", "run"]);
+        match cli.command {
+            Commands::Run { format, .. } => assert_eq!(format, OutputFormat::default()),
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    struct FailingTarget;
+
+    impl BenchTarget for FailingTarget {
+        fn id(&self) -> String {
+            "failing".to_string()
+        }
+
+        fn run(&self) -> BenchmarkResult {
+            BenchmarkResult::failed("failing", "dummy failure")
+        }
+    }
+
+    struct CountingTarget {
+        id: &'static str,
+        tags: Vec<String>,
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl BenchTarget for CountingTarget {
+        fn id(&self) -> String {
+            self.id.to_string()
+        }
+
+        fn run(&self) -> BenchmarkResult {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            BenchmarkResult::new(self.id, serde_json::json!({}))
+        }
+
+        fn tags(&self) -> Vec<String> {
+            self.tags.clone()
+        }
+    }
+
+    #[test]
+    fn test_filter_skips_unmatched_targets() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let target = |id, tag: &str| -> Arc<dyn AsyncBenchTarget> {
+            Arc::new(CountingTarget {
+                id,
+                tags: vec![tag.to_string()],
+                runs: runs.clone(),
+            })
+        };
+        let targets = vec![
+            target("adapters/latency", "latency"),
+            target("adapters/cost", "cost"),
+        ];
+
+        let results = run_filtered(targets.clone(), Some("Latency"), &RunOptions::default());
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].target_id, "adapters/latency");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // Matching nothing runs nothing, not the built-in fallback
+        let results = run_filtered(targets, Some("throughput"), &RunOptions::default());
+        assert!(results.is_empty());
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_failed_benchmark_sets_exit_code() {
+        let targets: Vec<Arc<dyn AsyncBenchTarget>> = vec![Arc::new(FailingTarget)];
+        let results = run_all_benchmarks_with(&targets, &RunOptions::default());
+
+        let err = check_outcomes(&results).unwrap_err();
+        assert_eq!(err.failed, ["failing"]);
+        assert_eq!(exit_code(&err), EXIT_BENCHMARKS_FAILED);
+
+        let tool_error = BenchmarkError::validation("bad result");
+        assert_eq!(exit_code(&tool_error), EXIT_ERROR);
+        assert!(check_outcomes(&[BenchmarkResult::new("ok", serde_json::json!({}))]).is_ok());
+    }
+
+    #[test]
+    fn test_metric_changes() {
+        let previous = vec![
+            BenchmarkResult::new(
+                "a",
+                serde_json::json!({"latency_ms": 100, "model": "gpt-4"}),
+            ),
+            BenchmarkResult::new("gone", serde_json::json!({})),
+        ];
+        let current = vec![
+            BenchmarkResult::new(
+                "a",
+                serde_json::json!({"latency_ms": 150, "model": "gpt-4"}),
+            ),
+            BenchmarkResult::failed("b", "boom"),
+        ];
+
+        let changes = metric_changes(&previous, &current, &[]);
+        assert_eq!(
+            changes,
+            [
+                "a.latency_ms: 100 -> 150 (+50.0%)",
+                "b: new",
+                "gone: removed"
+            ]
+        );
+        assert!(metric_changes(&current, &current, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_metric_changes_by_pointer() {
+        let previous = vec![BenchmarkResult::new(
+            "a",
+            serde_json::json!({"latency": {"p50": 10, "p99": 40}, "runs": 1}),
+        )];
+        let current = vec![BenchmarkResult::new(
+            "a",
+            serde_json::json!({"latency": {"p50": 12, "p99": 50}, "runs": 2}),
+        )];
+
+        let pointers = ["/latency/p99".to_string()];
+        let changes = metric_changes(&previous, &current, &pointers);
+        assert_eq!(changes, ["a/latency/p99: 40 -> 50 (+25.0%)"]);
+    }
+
+    #[test]
+    fn test_watch_command_parses_paths() {
+        let cli = Cli::parse_from(["observatory", "watch", "src", "crates", "-f", "json"]);
+        match cli.command {
+            Commands::Watch { paths, format, .. } => {
+                assert_eq!(paths, ["src", "crates"]);
+                assert_eq!(format, OutputFormat::Json);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+
+        let cli = Cli::parse_from(["observatory", "watch"]);
+        match cli.command {
+            Commands::Watch { paths, .. } => assert_eq!(paths, ["crates"]),
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_watch_runs_through_cargo_with_output_override() {
+        let cli = Cli::parse_from(["observatory", "watch", "-o", "/tmp/bench"]);
+        let Commands::Watch { output, format, .. } = cli.command else {
+            panic!("unexpected command: {:?}", cli.command);
+        };
+        let naming = output_naming(output);
+        assert_eq!(naming.output_dir, Path::new("/tmp/bench"));
+
+        let command = watch_run_command(format, &naming.output_dir);
+        let args: Vec<_> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy())
+            .collect();
+        assert_eq!(
+            args,
+            [
+                "run",
+                "--quiet",
+                "-p",
+                "llm-observatory-cli",
+                "--bin",
+                "observatory",
+                "--",
+                "--quiet",
+                "run",
+                "--format",
+                "both",
+                "--output",
+                "/tmp/bench",
+            ]
+        );
+        assert_eq!(output_naming(None), io::OutputNaming::default());
+    }
+
+    #[test]
+    fn test_bash_completions_name_the_binary() {
+        let mut out = Vec::new();
+        write_completions(Shell::Bash, &mut out);
+        let script = String::from_utf8(out).unwrap();
+        assert!(!script.is_empty());
+        assert!(script.contains("observatory"));
+    }
+
+    #[test]
+    fn test_quiet_and_json_flags() {
+        let cli = Cli::parse_from(["observatory", "--quiet", "run", "--json"]);
+        assert!(cli.quiet);
+        assert!(cli.json);
+
+        let cli = Cli::parse_from(["observatory", "status", "-q"]);
+        assert!(cli.quiet);
+        assert!(!cli.json);
+    }
+
+    fn fixture_path(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn test_validate_execution_reports_missing_agent_span() {
+        let errors = validate_execution_file(&fixture_path("missing_agent_span.json")).unwrap();
+        assert_eq!(
+            errors,
+            ["No agent spans emitted -- execution has no evidence of agent work"]
+        );
+        assert_eq!(
+            exit_code(&InvalidExecution { errors }),
+            EXIT_BENCHMARKS_FAILED
+        );
+
+        let cli = Cli::parse_from(["observatory", "validate-execution", "result.json"]);
+        match cli.command {
+            Commands::ValidateExecution { path } => assert_eq!(path, "result.json"),
+            other => panic!("unexpected command: {:?}", other),
+        }
+        assert!(validate_execution_file(&fixture_path("does_not_exist.json")).is_err());
+    }
+
+    #[test]
+    fn test_run_output_names() {
+        let cli = Cli::parse_from([
+            "observatory",
+            "run",
+            "--summary-name",
+            "summary-{date}.md",
+            "--summary-metric",
+            "/latency/p99",
+            "--summary-metric",
+            "/model",
+        ]);
+        match cli.command {
+            Commands::Run {
+                summary_name,
+                results_name,
+                summary_metrics,
+                ..
+            } => {
+                assert_eq!(summary_name.as_deref(), Some("summary-{date}.md"));
+                assert_eq!(results_name, None);
+                assert_eq!(summary_metrics, ["/latency/p99", "/model"]);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ndjson_output_format() {
+        let cli = Cli::parse_from(["observatory", "run", "--format", "ndjson"]);
+        match cli.command {
+            Commands::Run { format, .. } => assert_eq!(format, OutputFormat::Ndjson),
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+}
@@ -3,6 +3,6 @@
 fn main() {
     if let Err(e) = llm_observatory_cli::run() {
         eprintln!("Error: {}", e);
-        std::process::exit(1);
+        std::process::exit(llm_observatory_cli::exit_code(e.as_ref()));
     }
 }
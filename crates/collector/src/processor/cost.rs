@@ -10,11 +10,7 @@
 
 use super::SpanProcessor;
 use async_trait::async_trait;
-use llm_observatory_core::{
-    span::LlmSpan,
-    types::Cost,
-    Result,
-};
+use llm_observatory_core::{span::LlmSpan, types::Cost, Result};
 use llm_observatory_providers::PricingEngine;
 
 /// Cost calculation processor.
@@ -90,11 +86,11 @@ impl SpanProcessor for CostCalculationProcessor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Utc;
     use llm_observatory_core::{
-        span::{LlmSpan, LlmInput, SpanStatus},
-        types::{Provider, Latency, TokenUsage},
+        span::{LlmInput, LlmSpan, SpanStatus},
+        types::{Latency, Provider, TokenUsage},
     };
-    use chrono::Utc;
 
     #[tokio::test]
     async fn test_cost_calculation_gpt4() {
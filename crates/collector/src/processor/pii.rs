@@ -12,32 +12,22 @@
 use super::SpanProcessor;
 use async_trait::async_trait;
 use llm_observatory_core::{
-    span::{LlmSpan, LlmInput, LlmOutput, ChatMessage},
+    pii::{EMAIL_REGEX, PHONE_REGEX},
+    span::{ChatMessage, LlmInput, LlmOutput, LlmSpan},
     Result,
 };
-use regex::Regex;
 use once_cell::sync::Lazy;
+use regex::Regex;
 
-/// Regex patterns for PII detection.
-static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b").unwrap()
-});
-
-static PHONE_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b(?:\+?1[-.\s]?)?\(?([0-9]{3})\)?[-.\s]?([0-9]{3})[-.\s]?([0-9]{4})\b").unwrap()
-});
-
-static SSN_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap()
-});
+/// Regex patterns for PII detection. Email and phone patterns are shared with
+/// the upstream adapters through `llm_observatory_core::pii`.
+static SSN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap());
 
-static CREDIT_CARD_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b\d{4}[\s-]?\d{4}[\s-]?\d{4}[\s-]?\d{4}\b").unwrap()
-});
+static CREDIT_CARD_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{4}[\s-]?\d{4}[\s-]?\d{4}[\s-]?\d{4}\b").unwrap());
 
-static IP_ADDRESS_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b(?:[0-9]{1,3}\.){3}[0-9]{1,3}\b").unwrap()
-});
+static IP_ADDRESS_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(?:[0-9]{1,3}\.){3}[0-9]{1,3}\b").unwrap());
 
 /// PII redaction processor.
 #[derive(Debug, Clone)]
@@ -195,11 +185,11 @@ impl SpanProcessor for PiiRedactionProcessor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Utc;
     use llm_observatory_core::{
-        span::{LlmSpan, LlmInput, SpanStatus},
-        types::{Provider, Latency},
+        span::{LlmInput, LlmSpan, SpanStatus},
+        types::{Latency, Provider},
     };
-    use chrono::Utc;
 
     #[test]
     fn test_email_redaction() {
@@ -217,6 +207,13 @@ mod tests {
         assert_eq!(redacted, "Call me at [PHONE]");
     }
 
+    #[test]
+    fn test_phone_redaction_ignores_digit_runs() {
+        let processor = PiiRedactionProcessor::new();
+        let text = "Order 5551234567 shipped";
+        assert_eq!(processor.redact_text(text), text);
+    }
+
     #[test]
     fn test_ssn_redaction() {
         let processor = PiiRedactionProcessor::new();
@@ -273,9 +270,6 @@ mod tests {
             _ => panic!("Expected Text input"),
         }
 
-        assert_eq!(
-            processed.output.unwrap().content,
-            "Contact me at [EMAIL]"
-        );
+        assert_eq!(processed.output.unwrap().content, "Contact me at [EMAIL]");
     }
 }
@@ -16,13 +16,16 @@
 //! Core
 //!   └─ Repo (this repo)
 //!       └─ Agent (one or more)
+//!           └─ Agent (optional sub-agents, nested to any depth)
 //! ```
 //!
-//! If no agent span exists, execution is INVALID.
+//! If no agent span exists, execution is INVALID. Every agent span must
+//! resolve, through its chain of parent agents, to the repo span.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// Unique identifier for an execution (top-level orchestration unit).
@@ -74,6 +77,9 @@ impl Default for ExecutionSpanStatus {
     }
 }
 
+/// Default upper bound for inline artifact content (64 KiB).
+pub const DEFAULT_MAX_INLINE_ARTIFACT_BYTES: usize = 64 * 1024;
+
 /// Artifact content: either inline data or a reference URI.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "content_location", rename_all = "snake_case")]
@@ -93,6 +99,8 @@ pub enum ArtifactContent {
 /// An artifact produced by an agent and attached to its span.
 ///
 /// Artifacts have stable references via content-addressable SHA-256 hash.
+/// Prefer [`Artifact::from_inline`] or [`Artifact::from_reference`], which
+/// compute the hash from the content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Artifact {
     /// Unique artifact ID (UUID v4).
@@ -117,6 +125,103 @@ pub struct Artifact {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+impl Artifact {
+    /// Create an artifact whose content is stored inline.
+    ///
+    /// The hash, size, ID and creation time are derived from `data`. The
+    /// `agent_span_id` is filled in when the artifact is attached to a span.
+    pub fn from_inline(
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<String>,
+    ) -> Self {
+        let data = data.into();
+        let mut artifact = Self::with_content(name, content_type, data.as_bytes());
+        artifact.content = ArtifactContent::Inline { data };
+        artifact
+    }
+
+    /// Create an artifact whose content is stored externally at `uri`.
+    ///
+    /// `data` is the stored content, used only to compute the hash and size.
+    pub fn from_reference(
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        uri: impl Into<String>,
+        data: impl AsRef<[u8]>,
+    ) -> Self {
+        let mut artifact = Self::with_content(name, content_type, data.as_ref());
+        artifact.content = ArtifactContent::Reference { uri: uri.into() };
+        artifact
+    }
+
+    /// Check that `data` matches this artifact's content hash.
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> bool {
+        content_hash(data.as_ref()) == self.content_hash
+    }
+
+    /// Load the artifact's content, fetching references through `fetcher`.
+    ///
+    /// The content is checked against `content_hash` whether it is inline or
+    /// fetched; a mismatch is an error.
+    pub fn resolve(&self, fetcher: &dyn ArtifactFetcher) -> crate::Result<Vec<u8>> {
+        let data = match &self.content {
+            ArtifactContent::Inline { data } => data.clone().into_bytes(),
+            ArtifactContent::Reference { uri } => fetcher.fetch(uri)?,
+        };
+        if !self.verify(&data) {
+            return Err(crate::Error::execution(format!(
+                "Artifact {} content does not match hash {}",
+                self.artifact_id, self.content_hash
+            )));
+        }
+        Ok(data)
+    }
+
+    fn with_content(name: impl Into<String>, content_type: impl Into<String>, data: &[u8]) -> Self {
+        Self {
+            artifact_id: Uuid::new_v4().to_string(),
+            agent_span_id: String::new(),
+            name: name.into(),
+            content_type: content_type.into(),
+            content_hash: content_hash(data),
+            size_bytes: data.len() as u64,
+            content: ArtifactContent::Inline {
+                data: String::new(),
+            },
+            created_at: Utc::now(),
+            metadata: HashMap::new(),
+        }
+    }
+}
+
+/// Hex-encoded SHA-256 hash of artifact content.
+fn content_hash(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Backing store that [`ArtifactContent::Reference`] URIs resolve against.
+///
+/// Implementations typically handle one scheme (`file://`, `http(s)://`,
+/// `s3://`) and reject the rest.
+pub trait ArtifactFetcher {
+    /// Fetch the raw bytes stored at `uri`.
+    fn fetch(&self, uri: &str) -> crate::Result<Vec<u8>>;
+}
+
+/// [`ArtifactFetcher`] for `file://` URIs on the local filesystem.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileFetcher;
+
+impl ArtifactFetcher for FileFetcher {
+    fn fetch(&self, uri: &str) -> crate::Result<Vec<u8>> {
+        let path = uri.strip_prefix("file://").ok_or_else(|| {
+            crate::Error::invalid_input(format!("FileFetcher only supports file:// URIs: {}", uri))
+        })?;
+        Ok(std::fs::read(path)?)
+    }
+}
+
 /// A timestamped event within an execution span (append-only).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionEvent {
@@ -140,7 +245,8 @@ pub struct ExecutionSpan {
     /// The top-level execution ID from the calling agentics system.
     pub execution_id: ExecutionId,
     /// Parent span ID. For repo spans: the caller's span ID.
-    /// For agent spans: the repo span ID. REQUIRED.
+    /// For agent spans: the repo span ID, or the parent agent's span ID for
+    /// sub-agents. REQUIRED.
     pub parent_span_id: ExecutionSpanId,
     /// Whether this is a repo or agent span.
     pub kind: ExecutionSpanKind,
@@ -206,17 +312,44 @@ impl ExecutionSpan {
 
     /// Attach an artifact to this span.
     ///
-    /// Returns `Err` if this is not an agent span.
-    pub fn attach_artifact(&mut self, artifact: Artifact) -> crate::Result<()> {
+    /// Returns `Err` if this is not an agent span. An empty `agent_span_id`
+    /// is set to this span's ID.
+    pub fn attach_artifact(&mut self, mut artifact: Artifact) -> crate::Result<()> {
         if self.kind != ExecutionSpanKind::Agent {
             return Err(crate::Error::invalid_input(
                 "Artifacts can only be attached to agent spans",
             ));
         }
+        if artifact.agent_span_id.is_empty() {
+            artifact.agent_span_id = self.span_id.clone();
+        }
         self.artifacts.push(artifact);
         Ok(())
     }
 
+    /// Attach an artifact, rejecting inline content larger than `max_inline_bytes`.
+    ///
+    /// Reference artifacts are not subject to the limit. See
+    /// [`DEFAULT_MAX_INLINE_ARTIFACT_BYTES`] for a sensible default.
+    pub fn attach_artifact_checked(
+        &mut self,
+        artifact: Artifact,
+        max_inline_bytes: usize,
+    ) -> crate::Result<()> {
+        if let ArtifactContent::Inline { data } = &artifact.content {
+            if data.len() > max_inline_bytes {
+                return Err(crate::Error::invalid_input(format!(
+                    "Inline artifact '{}' is {} bytes, exceeding the {} byte limit; \
+                     store it externally and use ArtifactContent::Reference",
+                    artifact.name,
+                    data.len(),
+                    max_inline_bytes
+                )));
+            }
+        }
+        self.attach_artifact(artifact)
+    }
+
     /// Record an event on this span.
     pub fn record_event(
         &mut self,
@@ -230,6 +363,45 @@ impl ExecutionSpan {
         });
     }
 
+    /// Check that events are in non-decreasing timestamp order and fall
+    /// within `[start_time, end_time]` (the upper bound only applies once
+    /// the span has ended).
+    ///
+    /// Returns `Err` naming the first offending event.
+    pub fn validate_events(&self) -> crate::Result<()> {
+        let mut previous = self.start_time;
+        for event in &self.events {
+            if event.timestamp < self.start_time {
+                return Err(crate::Error::invalid_input(format!(
+                    "Event '{}' at {} precedes span start {}",
+                    event.name,
+                    event.timestamp.to_rfc3339(),
+                    self.start_time.to_rfc3339()
+                )));
+            }
+            if event.timestamp < previous {
+                return Err(crate::Error::invalid_input(format!(
+                    "Event '{}' at {} is earlier than the preceding event at {}",
+                    event.name,
+                    event.timestamp.to_rfc3339(),
+                    previous.to_rfc3339()
+                )));
+            }
+            if let Some(end_time) = self.end_time {
+                if event.timestamp > end_time {
+                    return Err(crate::Error::invalid_input(format!(
+                        "Event '{}' at {} follows span end {}",
+                        event.name,
+                        event.timestamp.to_rfc3339(),
+                        end_time.to_rfc3339()
+                    )));
+                }
+            }
+            previous = event.timestamp;
+        }
+        Ok(())
+    }
+
     /// Whether this span completed successfully.
     pub fn is_completed(&self) -> bool {
         self.status == ExecutionSpanStatus::Completed
@@ -239,6 +411,78 @@ impl ExecutionSpan {
     pub fn is_failed(&self) -> bool {
         self.status == ExecutionSpanStatus::Failed
     }
+
+    /// Convert this span to OpenTelemetry-style span JSON.
+    ///
+    /// Artifacts and events are folded into `attributes` alongside the
+    /// span's own attributes; the `execution.*` keys take precedence.
+    pub fn to_otel_span_json(&self) -> serde_json::Value {
+        let (kind, name) = match self.kind {
+            ExecutionSpanKind::Repo => ("repo", format!("repo.{}", self.repo_name)),
+            ExecutionSpanKind::Agent => (
+                "agent",
+                format!("agent.{}", self.agent_name.as_deref().unwrap_or("unknown")),
+            ),
+        };
+
+        let artifacts: Vec<serde_json::Value> = self
+            .artifacts
+            .iter()
+            .map(|a| {
+                serde_json::json!({
+                    "artifact_id": a.artifact_id,
+                    "name": a.name,
+                    "content_type": a.content_type,
+                    "content_hash": a.content_hash,
+                    "size_bytes": a.size_bytes
+                })
+            })
+            .collect();
+        let events: Vec<serde_json::Value> = self
+            .events
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "name": e.name,
+                    "timestamp": e.timestamp.to_rfc3339(),
+                    "attributes": e.attributes
+                })
+            })
+            .collect();
+
+        let mut attributes = serde_json::json!({
+            "execution.id": self.execution_id,
+            "execution.kind": kind,
+            "repo.name": self.repo_name,
+            "agent.name": self.agent_name,
+            "execution.artifact_count": artifacts.len(),
+            "execution.artifacts": artifacts,
+            "execution.event_count": events.len(),
+            "execution.events": events,
+            "error.message": self.error_message
+        });
+        if let Some(map) = attributes.as_object_mut() {
+            for (key, value) in &self.attributes {
+                map.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+
+        serde_json::json!({
+            "trace_id": self.execution_id,
+            "span_id": self.span_id,
+            "parent_span_id": self.parent_span_id,
+            "name": name,
+            "start_time": self.start_time.to_rfc3339(),
+            "end_time": self.end_time.map(|t| t.to_rfc3339()),
+            "duration_ms": self.duration_ms,
+            "status": match self.status {
+                ExecutionSpanStatus::Running => "unset",
+                ExecutionSpanStatus::Completed => "ok",
+                ExecutionSpanStatus::Failed | ExecutionSpanStatus::Cancelled => "error"
+            },
+            "attributes": attributes
+        })
+    }
 }
 
 /// Builder for [`ExecutionSpan`] instances.
@@ -340,9 +584,7 @@ impl ExecutionSpanBuilder {
 
     /// Build the [`ExecutionSpan`]. Returns `Err` if required fields are missing.
     pub fn build(self) -> crate::Result<ExecutionSpan> {
-        let span_id = self
-            .span_id
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        let span_id = self.span_id.unwrap_or_else(|| Uuid::new_v4().to_string());
         let execution_id = self
             .execution_id
             .ok_or_else(|| crate::Error::invalid_input("execution_id is required"))?;
@@ -422,7 +664,11 @@ pub struct ExecutionResult {
     /// Validation errors (populated when `valid` is false).
     #[serde(default)]
     pub validation_errors: Vec<String>,
-    /// Total artifacts across all agent spans.
+    /// Total logical artifacts across all agent spans.
+    ///
+    /// Every attached artifact counts, including entries that
+    /// [`ExecutionResult::dedup_artifacts`] replaced with a reference to a
+    /// canonical copy.
     pub total_artifacts: usize,
     /// Total duration in milliseconds (repo span duration).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -443,21 +689,119 @@ impl ExecutionResult {
         }
     }
 
+    /// Convert the repo span and every agent span to OpenTelemetry-style span JSON.
+    ///
+    /// The repo span comes first, followed by agent spans in their stored order.
+    pub fn to_otel_spans(&self) -> Vec<serde_json::Value> {
+        std::iter::once(&self.repo_span)
+            .chain(&self.agent_spans)
+            .map(ExecutionSpan::to_otel_span_json)
+            .collect()
+    }
+
+    /// Collapse artifacts that share a `content_hash` into a single stored copy.
+    ///
+    /// The first artifact seen for each hash (in agent span order) is kept as
+    /// the canonical copy and gains a `reference_count` metadata entry; when
+    /// run again after more spans arrive, new duplicates add to the existing
+    /// count. Later duplicates keep their own ID and name but their content
+    /// becomes a [`ArtifactContent::Reference`] to
+    /// `artifact://<canonical artifact_id>`, with the canonical ID recorded
+    /// under `deduplicated_from`. Artifacts with an empty hash are left alone.
+    /// `total_artifacts` is unchanged.
+    ///
+    /// Returns the number of artifacts that were replaced by references.
+    pub fn dedup_artifacts(&mut self) -> usize {
+        // content_hash -> (span index, artifact index) of the canonical copy
+        let mut canonical: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut references: HashMap<(usize, usize), u64> = HashMap::new();
+        let mut replaced = 0;
+
+        for span_index in 0..self.agent_spans.len() {
+            for artifact_index in 0..self.agent_spans[span_index].artifacts.len() {
+                let artifact = &self.agent_spans[span_index].artifacts[artifact_index];
+                if artifact.content_hash.is_empty()
+                    || artifact.metadata.contains_key("deduplicated_from")
+                {
+                    continue;
+                }
+
+                let Some(&(cs, ca)) = canonical.get(&artifact.content_hash) else {
+                    canonical.insert(artifact.content_hash.clone(), (span_index, artifact_index));
+                    continue;
+                };
+
+                let canonical_artifact = &self.agent_spans[cs].artifacts[ca];
+                let canonical_id = canonical_artifact.artifact_id.clone();
+                let count = references.entry((cs, ca)).or_insert_with(|| {
+                    canonical_artifact
+                        .metadata
+                        .get("reference_count")
+                        .and_then(serde_json::Value::as_u64)
+                        .unwrap_or(1)
+                });
+                *count += 1;
+
+                let artifact = &mut self.agent_spans[span_index].artifacts[artifact_index];
+                artifact.content = ArtifactContent::Reference {
+                    uri: format!("artifact://{}", canonical_id),
+                };
+                artifact
+                    .metadata
+                    .insert("deduplicated_from".to_string(), canonical_id.into());
+                replaced += 1;
+            }
+        }
+
+        for ((span_index, artifact_index), count) in references {
+            self.agent_spans[span_index].artifacts[artifact_index]
+                .metadata
+                .insert("reference_count".to_string(), count.into());
+        }
+        replaced
+    }
+
+    /// Build a validated result from a flat, unordered list of spans.
+    ///
+    /// Exactly one span must be a repo span; all others are treated as agent
+    /// spans and ordered by `start_time`. Returns `Err` if there are zero or
+    /// multiple repo spans.
+    pub fn from_spans(spans: Vec<ExecutionSpan>) -> crate::Result<Self> {
+        let (mut repo_spans, mut agent_spans): (Vec<_>, Vec<_>) = spans
+            .into_iter()
+            .partition(|s| s.kind == ExecutionSpanKind::Repo);
+
+        let repo_span = match repo_spans.len() {
+            1 => repo_spans.remove(0),
+            0 => return Err(crate::Error::invalid_input("No repo span found in spans")),
+            n => {
+                return Err(crate::Error::invalid_input(format!(
+                    "Expected exactly one repo span, found {}",
+                    n
+                )))
+            }
+        };
+
+        agent_spans.sort_by_key(|s| s.start_time);
+        Ok(Self::new(repo_span, agent_spans).validate())
+    }
+
     /// Validate the execution result according to enforcement rules.
     ///
     /// Checks:
     /// - Repo span has a non-empty `parent_span_id`
     /// - At least one agent span was emitted
-    /// - All agent spans reference the repo span as parent
+    /// - All agent spans resolve transitively to the repo span as ancestor
+    /// - Agent `parent_span_id` links contain no cycles
     /// - No duplicate span IDs
+    /// - Events in every span are time-ordered and within the span's bounds
     pub fn validate(mut self) -> Self {
         self.validation_errors.clear();
 
         // Rule: repo span must have a parent_span_id
         if self.repo_span.parent_span_id.is_empty() {
-            self.validation_errors.push(
-                "Repo span is missing parent_span_id from caller".to_string(),
-            );
+            self.validation_errors
+                .push("Repo span is missing parent_span_id from caller".to_string());
         }
 
         // Rule: must have at least one agent span
@@ -467,16 +811,31 @@ impl ExecutionResult {
             );
         }
 
-        // Rule: every agent span must have parent_span_id == repo span_id
+        // Rule: every agent span must resolve to the repo span through its parents
+        let parents: HashMap<&str, &str> = self
+            .agent_spans
+            .iter()
+            .map(|s| (s.span_id.as_str(), s.parent_span_id.as_str()))
+            .collect();
         for agent_span in &self.agent_spans {
-            if agent_span.parent_span_id != self.repo_span.span_id {
+            if !self.resolves_to_repo(&parents, agent_span) {
                 self.validation_errors.push(format!(
-                    "Agent span {} has parent_span_id {} but expected repo span {}",
+                    "Agent span {} has parent_span_id {} but expected repo span {} as ancestor",
                     agent_span.span_id, agent_span.parent_span_id, self.repo_span.span_id
                 ));
             }
         }
 
+        // Rule: parent links must not loop back on themselves
+        for cycle in Self::parent_cycles(&parents, &self.agent_spans) {
+            let mut chain = cycle.clone();
+            chain.push(cycle[0]);
+            self.validation_errors.push(format!(
+                "Agent spans form a parent_span_id cycle: {}",
+                chain.join(" -> ")
+            ));
+        }
+
         // Rule: no two agent spans should share the same span_id
         let mut seen_ids = std::collections::HashSet::new();
         for agent_span in &self.agent_spans {
@@ -486,15 +845,104 @@ impl ExecutionResult {
             }
         }
 
+        // Rule: span events must be causally ordered
+        for span in std::iter::once(&self.repo_span).chain(&self.agent_spans) {
+            if let Err(err) = span.validate_events() {
+                self.validation_errors
+                    .push(format!("Span {} has invalid events: {}", span.span_id, err));
+            }
+        }
+
         self.valid = self.validation_errors.is_empty();
-        self.total_artifacts = self
-            .agent_spans
-            .iter()
-            .map(|s| s.artifacts.len())
-            .sum();
+        self.total_artifacts = self.agent_spans.iter().map(|s| s.artifacts.len()).sum();
         self.total_duration_ms = self.repo_span.duration_ms;
         self
     }
+
+    /// Build the agent span hierarchy rooted at the repo span.
+    ///
+    /// Returns the agents whose parent is the repo span, each with its
+    /// sub-agents nested beneath it. Children keep the order of
+    /// `agent_spans`. Spans that do not resolve to the repo span are omitted.
+    pub fn agent_tree(&self) -> Vec<AgentNode<'_>> {
+        let mut visited = vec![false; self.agent_spans.len()];
+        self.agent_children(&self.repo_span.span_id, &mut visited)
+    }
+
+    fn agent_children<'a>(&'a self, parent_id: &str, visited: &mut [bool]) -> Vec<AgentNode<'a>> {
+        let mut children = Vec::new();
+        for (index, span) in self.agent_spans.iter().enumerate() {
+            if visited[index] || span.parent_span_id != parent_id {
+                continue;
+            }
+            visited[index] = true;
+            children.push(AgentNode {
+                span,
+                children: self.agent_children(&span.span_id, visited),
+            });
+        }
+        children
+    }
+
+    /// Cycles among agent `parent_span_id` links, each in child-to-parent order.
+    ///
+    /// Each span is walked at most once, so this terminates on any input.
+    fn parent_cycles<'a>(
+        parents: &HashMap<&'a str, &'a str>,
+        spans: &'a [ExecutionSpan],
+    ) -> Vec<Vec<&'a str>> {
+        let mut walked: HashSet<&str> = HashSet::new();
+        let mut cycles = Vec::new();
+        for span in spans {
+            let mut path: Vec<&str> = Vec::new();
+            let mut current = span.span_id.as_str();
+            while !walked.contains(current) {
+                if let Some(start) = path.iter().position(|id| *id == current) {
+                    cycles.push(path[start..].to_vec());
+                    break;
+                }
+                path.push(current);
+                match parents.get(current) {
+                    Some(parent) => current = *parent,
+                    None => break,
+                }
+            }
+            walked.extend(path);
+        }
+        cycles
+    }
+
+    fn resolves_to_repo(&self, parents: &HashMap<&str, &str>, span: &ExecutionSpan) -> bool {
+        let mut current = span.parent_span_id.as_str();
+        // A valid chain visits each agent at most once; anything longer is a cycle.
+        for _ in 0..=self.agent_spans.len() {
+            if current == self.repo_span.span_id {
+                return true;
+            }
+            match parents.get(current) {
+                Some(parent) => current = *parent,
+                None => return false,
+            }
+        }
+        false
+    }
+}
+
+/// A node in the agent span hierarchy returned by [`ExecutionResult::agent_tree`].
+#[derive(Debug, Clone)]
+pub struct AgentNode<'a> {
+    /// The agent span at this node.
+    pub span: &'a ExecutionSpan,
+    /// Sub-agents spawned by this agent.
+    pub children: Vec<AgentNode<'a>>,
+}
+
+impl AgentNode<'_> {
+    /// Depth of the subtree rooted at this node (1 for a leaf agent).
+    pub fn depth(&self) -> usize {
+        let deepest_child = self.children.iter().map(AgentNode::depth).max();
+        1 + deepest_child.unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
@@ -610,10 +1058,7 @@ mod tests {
         let mut span = make_repo_span("parent-1");
         span.fail("something went wrong");
         assert_eq!(span.status, ExecutionSpanStatus::Failed);
-        assert_eq!(
-            span.error_message.as_deref(),
-            Some("something went wrong")
-        );
+        assert_eq!(span.error_message.as_deref(), Some("something went wrong"));
         assert!(span.end_time.is_some());
     }
 
@@ -643,6 +1088,82 @@ mod tests {
         assert_eq!(agent_span.artifacts.len(), 1);
     }
 
+    #[test]
+    fn test_artifact_from_inline() {
+        let artifact = Artifact::from_inline("greeting", "text/plain", "hello");
+        assert_eq!(
+            artifact.content_hash,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(artifact.size_bytes, 5);
+        assert!(Uuid::parse_str(&artifact.artifact_id).is_ok());
+        assert!(matches!(
+            artifact.content,
+            ArtifactContent::Inline { ref data } if data == "hello"
+        ));
+        assert!(artifact.verify("hello"));
+        assert!(!artifact.verify("hello!"));
+
+        let mut agent_span = make_agent_span("repo-1");
+        agent_span.attach_artifact(artifact).unwrap();
+        assert_eq!(agent_span.artifacts[0].agent_span_id, agent_span.span_id);
+    }
+
+    #[test]
+    fn test_artifact_from_reference() {
+        let data = vec![0u8; 2048];
+        let artifact = Artifact::from_reference(
+            "blob",
+            "application/octet-stream",
+            "s3://bucket/blob",
+            &data,
+        );
+        assert_eq!(artifact.size_bytes, 2048);
+        assert!(artifact.verify(&data));
+        assert!(matches!(
+            artifact.content,
+            ArtifactContent::Reference { ref uri } if uri == "s3://bucket/blob"
+        ));
+    }
+
+    #[test]
+    fn test_artifact_resolve_file_reference() {
+        let data = b"artifact body".to_vec();
+        let path = std::env::temp_dir().join(format!("artifact-{}.txt", Uuid::new_v4()));
+        std::fs::write(&path, &data).unwrap();
+        let uri = format!("file://{}", path.display());
+
+        let artifact = Artifact::from_reference("report", "text/plain", &uri, &data);
+        assert_eq!(artifact.resolve(&FileFetcher).unwrap(), data);
+
+        std::fs::write(&path, b"tampered").unwrap();
+        assert!(artifact.resolve(&FileFetcher).is_err());
+        std::fs::remove_file(&path).unwrap();
+
+        let remote = Artifact::from_reference("blob", "text/plain", "s3://bucket/blob", &data);
+        assert!(remote.resolve(&FileFetcher).is_err());
+
+        let inline = Artifact::from_inline("note", "text/plain", "hello");
+        assert_eq!(inline.resolve(&FileFetcher).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn test_attach_artifact_checked_enforces_inline_limit() {
+        let mut agent_span = make_agent_span("repo-1");
+
+        let small = Artifact::from_inline("small", "text/plain", "x".repeat(16));
+        assert!(agent_span.attach_artifact_checked(small, 16).is_ok());
+
+        let large = Artifact::from_inline("large", "text/plain", "x".repeat(17));
+        let err = agent_span.attach_artifact_checked(large, 16).unwrap_err();
+        assert!(err.to_string().contains("Reference"));
+
+        let data = vec![0u8; 1024];
+        let reference = Artifact::from_reference("blob", "text/plain", "s3://b/k", &data);
+        assert!(agent_span.attach_artifact_checked(reference, 16).is_ok());
+        assert_eq!(agent_span.artifacts.len(), 2);
+    }
+
     #[test]
     fn test_record_event() {
         let mut span = make_repo_span("parent-1");
@@ -702,6 +1223,231 @@ mod tests {
             .any(|e| e.contains("wrong-parent")));
     }
 
+    #[test]
+    fn test_execution_result_accepts_nested_agents() {
+        let repo_span = make_repo_span("caller-span-1");
+        let agent = make_agent_span(&repo_span.span_id);
+        let sub_agent = make_agent_span(&agent.span_id);
+        let sub_sub_agent = make_agent_span(&sub_agent.span_id);
+        let sibling = make_agent_span(&repo_span.span_id);
+
+        let result = ExecutionResult::new(
+            repo_span,
+            vec![
+                agent.clone(),
+                sub_agent.clone(),
+                sub_sub_agent.clone(),
+                sibling.clone(),
+            ],
+        )
+        .validate();
+        assert!(result.valid, "{:?}", result.validation_errors);
+
+        let tree = result.agent_tree();
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].span.span_id, agent.span_id);
+        assert_eq!(tree[0].depth(), 3);
+        assert_eq!(tree[0].children[0].span.span_id, sub_agent.span_id);
+        assert_eq!(
+            tree[0].children[0].children[0].span.span_id,
+            sub_sub_agent.span_id
+        );
+        assert_eq!(tree[1].span.span_id, sibling.span_id);
+        assert!(tree[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_execution_result_rejects_unresolved_agent_chain() {
+        let repo_span = make_repo_span("caller-span-1");
+        let agent = make_agent_span(&repo_span.span_id);
+        let orphan = make_agent_span("missing-agent");
+        let mut cycle_a = make_agent_span("placeholder");
+        let cycle_b = make_agent_span(&cycle_a.span_id);
+        cycle_a.parent_span_id = cycle_b.span_id.clone();
+
+        let result =
+            ExecutionResult::new(repo_span, vec![agent, orphan, cycle_a, cycle_b]).validate();
+        assert!(!result.valid);
+        // orphan and both cycle members fail ancestry, plus one cycle error
+        assert_eq!(result.validation_errors.len(), 4);
+        assert!(result
+            .validation_errors
+            .iter()
+            .any(|e| e.contains("missing-agent")));
+        assert_eq!(result.agent_tree().len(), 1);
+    }
+
+    #[test]
+    fn test_execution_result_flags_parent_cycles() {
+        let repo_span = make_repo_span("caller-span-1");
+        let mut a = make_agent_span("placeholder");
+        let b = make_agent_span(&a.span_id);
+        a.parent_span_id = b.span_id.clone();
+        let mut selfish = make_agent_span("placeholder");
+        selfish.parent_span_id = selfish.span_id.clone();
+
+        let result =
+            ExecutionResult::new(repo_span, vec![a.clone(), b.clone(), selfish.clone()]).validate();
+        assert!(!result.valid);
+
+        let cycles: Vec<&String> = result
+            .validation_errors
+            .iter()
+            .filter(|e| e.contains("cycle"))
+            .collect();
+        assert_eq!(cycles.len(), 2);
+        assert!(cycles[0].ends_with(&format!("{} -> {} -> {}", a.span_id, b.span_id, a.span_id)));
+        assert!(cycles[1].ends_with(&format!("{} -> {}", selfish.span_id, selfish.span_id)));
+        assert!(result.agent_tree().is_empty());
+    }
+
+    #[test]
+    fn test_execution_result_from_spans() {
+        let repo_span = make_repo_span("caller-span-1");
+        let mut first = make_agent_span(&repo_span.span_id);
+        let mut second = make_agent_span(&repo_span.span_id);
+        second.start_time = first.start_time + chrono::Duration::milliseconds(10);
+        first.start_time -= chrono::Duration::milliseconds(10);
+
+        let spans = vec![second.clone(), repo_span.clone(), first.clone()];
+        let result = ExecutionResult::from_spans(spans).unwrap();
+        assert!(result.valid);
+        assert_eq!(result.repo_span.span_id, repo_span.span_id);
+        assert_eq!(result.agent_spans[0].span_id, first.span_id);
+        assert_eq!(result.agent_spans[1].span_id, second.span_id);
+
+        assert!(ExecutionResult::from_spans(vec![first.clone()]).is_err());
+        let spans = vec![repo_span.clone(), repo_span, first];
+        let err = ExecutionResult::from_spans(spans).unwrap_err();
+        assert!(err.to_string().contains("found 2"));
+    }
+
+    #[test]
+    fn test_execution_result_to_otel_spans() {
+        let mut repo_span = make_repo_span("caller-span-1");
+        let mut agent_span = make_agent_span(&repo_span.span_id);
+        agent_span
+            .attach_artifact(Artifact::from_inline("report", "text/plain", "done"))
+            .unwrap();
+        agent_span.record_event("tool_call", HashMap::new());
+        agent_span.fail("tool crashed");
+        repo_span.complete();
+
+        let result = ExecutionResult::new(repo_span.clone(), vec![agent_span.clone()]);
+        let spans = result.to_otel_spans();
+        assert_eq!(spans.len(), 2);
+
+        assert_eq!(spans[0]["span_id"], repo_span.span_id);
+        assert_eq!(spans[0]["parent_span_id"], "caller-span-1");
+        assert_eq!(spans[0]["name"], "repo.llm-observatory");
+        assert_eq!(spans[0]["status"], "ok");
+        assert_eq!(spans[0]["attributes"]["execution.kind"], "repo");
+
+        assert_eq!(spans[1]["parent_span_id"], repo_span.span_id);
+        assert_eq!(spans[1]["name"], "agent.test-agent");
+        assert_eq!(spans[1]["status"], "error");
+        assert_eq!(spans[1]["attributes"]["execution.artifact_count"], 1);
+        assert_eq!(spans[1]["attributes"]["execution.event_count"], 1);
+        assert_eq!(spans[1]["attributes"]["error.message"], "tool crashed");
+        assert!(spans[1]["end_time"].is_string());
+    }
+
+    #[test]
+    fn test_validate_events_ordering() {
+        let mut span = make_agent_span("repo-1");
+        span.record_event("first", HashMap::new());
+        span.record_event("second", HashMap::new());
+        span.complete();
+        assert!(span.validate_events().is_ok());
+
+        let mut out_of_order = span.clone();
+        out_of_order.events[1].timestamp =
+            out_of_order.events[0].timestamp - chrono::Duration::milliseconds(1);
+        let err = out_of_order.validate_events().unwrap_err();
+        assert!(err.to_string().contains("'second'"));
+
+        let mut after_end = span.clone();
+        after_end.events[1].timestamp = after_end.end_time.unwrap() + chrono::Duration::seconds(1);
+        assert!(after_end.validate_events().is_err());
+
+        let mut before_start = span;
+        before_start.events[0].timestamp = before_start.start_time - chrono::Duration::seconds(1);
+        let err = before_start.validate_events().unwrap_err();
+        assert!(err.to_string().contains("'first'"));
+    }
+
+    #[test]
+    fn test_execution_result_rejects_unordered_events() {
+        let repo_span = make_repo_span("caller-span-1");
+        let mut agent_span = make_agent_span(&repo_span.span_id);
+        agent_span.record_event("late", HashMap::new());
+        agent_span.record_event("early", HashMap::new());
+        agent_span.events[1].timestamp -= chrono::Duration::seconds(1);
+
+        let result = ExecutionResult::new(repo_span, vec![agent_span]).validate();
+        assert!(!result.valid);
+        assert!(result
+            .validation_errors
+            .iter()
+            .any(|e| e.contains("'early'")));
+    }
+
+    #[test]
+    fn test_dedup_artifacts_across_agents() {
+        let repo_span = make_repo_span("caller-span-1");
+        let mut agent1 = make_agent_span(&repo_span.span_id);
+        let mut agent2 = make_agent_span(&repo_span.span_id);
+        agent1
+            .attach_artifact(Artifact::from_inline("summary", "text/plain", "same"))
+            .unwrap();
+        agent2
+            .attach_artifact(Artifact::from_inline("summary", "text/plain", "same"))
+            .unwrap();
+        agent2
+            .attach_artifact(Artifact::from_inline("other", "text/plain", "different"))
+            .unwrap();
+
+        let mut result = ExecutionResult::new(repo_span, vec![agent1, agent2]).validate();
+        assert_eq!(result.dedup_artifacts(), 1);
+        assert_eq!(result.total_artifacts, 3);
+
+        let canonical = &result.agent_spans[0].artifacts[0];
+        let duplicate = &result.agent_spans[1].artifacts[0];
+        assert_eq!(canonical.metadata["reference_count"], 2);
+        let expected_uri = format!("artifact://{}", canonical.artifact_id);
+        assert!(matches!(
+            &duplicate.content,
+            ArtifactContent::Reference { uri } if *uri == expected_uri
+        ));
+        assert_eq!(
+            duplicate.metadata["deduplicated_from"],
+            canonical.artifact_id
+        );
+        assert!(matches!(
+            result.agent_spans[1].artifacts[1].content,
+            ArtifactContent::Inline { .. }
+        ));
+
+        // Idempotent
+        assert_eq!(result.dedup_artifacts(), 0);
+        assert_eq!(
+            result.agent_spans[0].artifacts[0].metadata["reference_count"],
+            2
+        );
+
+        // Duplicates arriving later add to the existing count
+        let mut agent3 = make_agent_span(&result.repo_span.span_id);
+        agent3
+            .attach_artifact(Artifact::from_inline("summary", "text/plain", "same"))
+            .unwrap();
+        result.agent_spans.push(agent3);
+        assert_eq!(result.dedup_artifacts(), 1);
+        assert_eq!(
+            result.agent_spans[0].artifacts[0].metadata["reference_count"],
+            3
+        );
+    }
+
     #[test]
     fn test_execution_result_rejects_duplicate_span_ids() {
         let repo_span = make_repo_span("caller-span-1");
@@ -12,12 +12,14 @@
 
 pub mod error;
 pub mod execution;
+pub mod pii;
 pub mod provider;
 pub mod span;
 pub mod types;
 
 pub use error::{Error, Result};
 pub use execution::{
-    Artifact, ArtifactContent, ExecutionContext, ExecutionEvent, ExecutionId, ExecutionResult,
-    ExecutionSpan, ExecutionSpanBuilder, ExecutionSpanId, ExecutionSpanKind, ExecutionSpanStatus,
+    AgentNode, Artifact, ArtifactContent, ArtifactFetcher, ExecutionContext, ExecutionEvent,
+    ExecutionId, ExecutionResult, ExecutionSpan, ExecutionSpanBuilder, ExecutionSpanId,
+    ExecutionSpanKind, ExecutionSpanStatus, FileFetcher, DEFAULT_MAX_INLINE_ARTIFACT_BYTES,
 };
@@ -0,0 +1,69 @@
+// Copyright 2025 LLM Observatory Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shared PII detection patterns.
+//!
+//! Every component that redacts prompt or response text uses these, so an
+//! address masked by the collector is masked the same way by the adapters.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Email addresses.
+pub static EMAIL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b").unwrap());
+
+/// North American phone numbers.
+///
+/// Matches `(555) 123-4567`, `555-123-4567`, `555.123.4567` and `555 123 4567`
+/// (optionally prefixed with `1` or `+1`), local numbers such as `555-1234`
+/// and `+15551234567`. A bare run of digits is not matched, since order
+/// numbers, timestamps and IDs look just like it.
+pub static PHONE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(?:\+1[-.\s]?|\b1[-.\s])?(?:\(\d{3}\)\s?|\b\d{3}[-.\s])\d{3}[-.\s]\d{4}\b",
+        r"|\b\d{3}[-.]\d{4}\b",
+        r"|\+1\d{10}\b",
+    ))
+    .unwrap()
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_regex() {
+        assert!(EMAIL_REGEX.is_match("jane.doe@example.com"));
+        assert!(!EMAIL_REGEX.is_match("user at example dot com"));
+    }
+
+    #[test]
+    fn test_phone_regex_matches_formatted_numbers() {
+        for phone in [
+            "(555) 123-4567",
+            "555-123-4567",
+            "555.123.4567",
+            "555 123 4567",
+            "+1 555.987.6543",
+            "1-555-123-4567",
+            "555-1234",
+            "+15551234567",
+        ] {
+            let found = PHONE_REGEX.find(phone).map(|m| m.as_str());
+            assert_eq!(found, Some(phone), "{}", phone);
+        }
+    }
+
+    #[test]
+    fn test_phone_regex_ignores_digit_runs() {
+        for text in [
+            "order 5551234567 shipped",
+            "timestamp 1700000000123",
+            "SSN 123-45-6789",
+            "released 2024-01-15",
+        ] {
+            assert!(!PHONE_REGEX.is_match(text), "{}", text);
+        }
+    }
+}
@@ -54,6 +54,25 @@ impl std::fmt::Display for Provider {
     }
 }
 
+impl std::str::FromStr for Provider {
+    type Err = std::convert::Infallible;
+
+    /// Parse a provider name, ignoring case.
+    ///
+    /// Unrecognized names become [`Provider::Custom`] with the name as given.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "openai" => Provider::OpenAI,
+            "anthropic" => Provider::Anthropic,
+            "google" => Provider::Google,
+            "mistral" => Provider::Mistral,
+            "cohere" => Provider::Cohere,
+            "self-hosted" | "self_hosted" | "selfhosted" => Provider::SelfHosted,
+            _ => Provider::Custom(s.to_string()),
+        })
+    }
+}
+
 /// Token usage statistics for an LLM call.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TokenUsage {
@@ -74,6 +93,20 @@ impl TokenUsage {
             total_tokens: prompt_tokens + completion_tokens,
         }
     }
+
+    /// Check that `total_tokens` equals `prompt_tokens + completion_tokens`.
+    pub fn validate(&self) -> bool {
+        u64::from(self.prompt_tokens) + u64::from(self.completion_tokens)
+            == u64::from(self.total_tokens)
+    }
+
+    /// Recompute `total_tokens` from the prompt and completion counts.
+    ///
+    /// Use this when usage comes from an external source whose total may be
+    /// missing or inconsistent.
+    pub fn reconcile(&mut self) {
+        self.total_tokens = self.prompt_tokens.saturating_add(self.completion_tokens);
+    }
 }
 
 /// Cost information for an LLM call.
@@ -146,6 +179,15 @@ pub struct Latency {
     pub start_time: DateTime<Utc>,
     /// End timestamp
     pub end_time: DateTime<Utc>,
+    /// Time spent queued before the request was sent, in milliseconds
+    #[serde(default)]
+    pub queue_ms: Option<u64>,
+    /// Time spent on the network, in milliseconds
+    #[serde(default)]
+    pub network_ms: Option<u64>,
+    /// Time spent in model inference, in milliseconds
+    #[serde(default)]
+    pub inference_ms: Option<u64>,
 }
 
 impl Latency {
@@ -159,6 +201,9 @@ impl Latency {
             ttft_ms: None,
             start_time,
             end_time,
+            queue_ms: None,
+            network_ms: None,
+            inference_ms: None,
         }
     }
 
@@ -167,12 +212,117 @@ impl Latency {
         self.ttft_ms = Some(ttft_ms);
         self
     }
+
+    /// Set time spent queued.
+    pub fn with_queue_time(mut self, queue_ms: u64) -> Self {
+        self.queue_ms = Some(queue_ms);
+        self
+    }
+
+    /// Set the queue, network and inference phase breakdown.
+    pub fn with_phases(mut self, queue_ms: u64, network_ms: u64, inference_ms: u64) -> Self {
+        self.queue_ms = Some(queue_ms);
+        self.network_ms = Some(network_ms);
+        self.inference_ms = Some(inference_ms);
+        self
+    }
+
+    /// Sum of the phases that are set, in milliseconds.
+    pub fn phases_ms(&self) -> u64 {
+        [self.queue_ms, self.network_ms, self.inference_ms]
+            .into_iter()
+            .flatten()
+            .fold(0u64, u64::saturating_add)
+    }
+
+    /// Check that the recorded phases add up to no more than `total_ms`.
+    ///
+    /// Phases may sum to less than the total, since not every moment of a
+    /// call is attributed to a phase.
+    pub fn validate(&self) -> bool {
+        self.phases_ms() <= self.total_ms
+    }
+}
+
+/// Distribution of `total_ms` over a set of [`Latency`] values.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    /// Number of latencies summarized
+    pub count: usize,
+    /// Median in milliseconds
+    pub p50_ms: u64,
+    /// 90th percentile in milliseconds
+    pub p90_ms: u64,
+    /// 99th percentile in milliseconds
+    pub p99_ms: u64,
+    /// Mean in milliseconds
+    pub mean_ms: f64,
+    /// Maximum in milliseconds
+    pub max_ms: u64,
+}
+
+impl LatencySummary {
+    /// Summarize `latencies`; an empty slice gives an all-zero summary.
+    ///
+    /// Percentiles use the nearest-rank method, so each is one of the
+    /// observed values.
+    pub fn from_latencies(latencies: &[Latency]) -> Self {
+        let mut values: Vec<u64> = latencies.iter().map(|l| l.total_ms).collect();
+        if values.is_empty() {
+            return Self::default();
+        }
+        values.sort_unstable();
+
+        let percentile = |p: f64| {
+            let rank = (p * values.len() as f64 / 100.0).ceil() as usize;
+            values[rank.clamp(1, values.len()) - 1]
+        };
+        let sum: u128 = values.iter().map(|&v| u128::from(v)).sum();
+
+        Self {
+            count: values.len(),
+            p50_ms: percentile(50.0),
+            p90_ms: percentile(90.0),
+            p99_ms: percentile(99.0),
+            mean_ms: sum as f64 / values.len() as f64,
+            max_ms: values[values.len() - 1],
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_latency_phases() {
+        let start = Utc::now();
+        let end = start + chrono::Duration::milliseconds(1000);
+
+        let latency = Latency::new(start, end);
+        assert_eq!(latency.phases_ms(), 0);
+        assert!(latency.validate());
+
+        let latency = Latency::new(start, end).with_phases(100, 150, 700);
+        assert_eq!(latency.queue_ms, Some(100));
+        assert_eq!(latency.phases_ms(), 950);
+        assert!(latency.validate());
+
+        let latency = latency.with_queue_time(200);
+        assert_eq!(latency.phases_ms(), 1050);
+        assert!(!latency.validate());
+
+        // Phases are optional when deserializing older payloads
+        let json = serde_json::json!({
+            "total_ms": 1000,
+            "ttft_ms": null,
+            "start_time": start,
+            "end_time": end,
+        });
+        let latency: Latency = serde_json::from_value(json).unwrap();
+        assert_eq!(latency.inference_ms, None);
+    }
+
     #[test]
     fn test_token_usage_calculation() {
         let usage = TokenUsage::new(100, 200);
@@ -181,6 +331,21 @@ mod tests {
         assert_eq!(usage.total_tokens, 300);
     }
 
+    #[test]
+    fn test_token_usage_reconcile() {
+        let mut usage = TokenUsage {
+            prompt_tokens: 100,
+            completion_tokens: 200,
+            total_tokens: 250,
+        };
+        assert!(!usage.validate());
+
+        usage.reconcile();
+        assert_eq!(usage.total_tokens, 300);
+        assert!(usage.validate());
+        assert!(TokenUsage::new(1, 2).validate());
+    }
+
     #[test]
     fn test_cost_with_breakdown() {
         let cost = Cost::with_breakdown(0.001, 0.002);
@@ -189,10 +354,62 @@ mod tests {
         assert_eq!(cost.completion_cost, Some(0.002));
     }
 
+    #[test]
+    fn test_latency_summary() {
+        let start = Utc::now();
+        let latencies: Vec<Latency> = (1..=100)
+            .rev()
+            .map(|ms| Latency::new(start, start + chrono::Duration::milliseconds(ms)))
+            .collect();
+
+        let summary = LatencySummary::from_latencies(&latencies);
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.p50_ms, 50);
+        assert_eq!(summary.p90_ms, 90);
+        assert_eq!(summary.p99_ms, 99);
+        assert_eq!(summary.max_ms, 100);
+        assert!((summary.mean_ms - 50.5).abs() < f64::EPSILON);
+
+        assert_eq!(
+            LatencySummary::from_latencies(&[]),
+            LatencySummary::default()
+        );
+    }
+
     #[test]
     fn test_provider_display() {
         assert_eq!(Provider::OpenAI.to_string(), "openai");
         assert_eq!(Provider::Anthropic.to_string(), "anthropic");
         assert_eq!(Provider::Custom("test".to_string()).to_string(), "test");
     }
+
+    #[test]
+    fn test_provider_from_str_round_trip() {
+        for provider in [
+            Provider::OpenAI,
+            Provider::Anthropic,
+            Provider::Google,
+            Provider::Mistral,
+            Provider::Cohere,
+            Provider::SelfHosted,
+            Provider::Custom("my-gateway".to_string()),
+        ] {
+            let parsed: Provider = provider.to_string().parse().unwrap();
+            assert_eq!(parsed, provider);
+        }
+
+        assert_eq!("OpenAI".parse::<Provider>().unwrap(), Provider::OpenAI);
+        assert_eq!(
+            "ANTHROPIC".parse::<Provider>().unwrap(),
+            Provider::Anthropic
+        );
+        assert_eq!(
+            "Self_Hosted".parse::<Provider>().unwrap(),
+            Provider::SelfHosted
+        );
+        assert_eq!(
+            "Azure".parse::<Provider>().unwrap(),
+            Provider::Custom("Azure".to_string())
+        );
+    }
 }
@@ -3,11 +3,11 @@
 //! This module contains the data models that represent database entities
 //! for traces, metrics, and logs.
 
-pub mod trace;
-pub mod metric;
 pub mod log;
+pub mod metric;
+pub mod trace;
 
 // Re-exports
-pub use trace::{Trace, TraceSpan, TraceEvent};
+pub use log::{LogLevel, LogRecord};
 pub use metric::{Metric, MetricDataPoint, MetricType};
-pub use log::{LogRecord, LogLevel};
+pub use trace::{Trace, TraceEvent, TraceSpan};
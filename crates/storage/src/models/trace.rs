@@ -3,14 +3,14 @@
 //! This module defines the data structures for storing distributed traces,
 //! spans, and events.
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
-use uuid::Uuid;
 use crate::error::{StorageError, StorageResult};
 use crate::validation::{
     validate_hex_string, validate_not_empty, validate_ordering, validate_status, Validate,
 };
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
 
 /// A distributed trace representing a request flow through the system.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -134,11 +134,7 @@ pub struct TraceEvent {
 
 impl Trace {
     /// Create a new trace.
-    pub fn new(
-        trace_id: String,
-        service_name: String,
-        start_time: DateTime<Utc>,
-    ) -> Self {
+    pub fn new(trace_id: String, service_name: String, start_time: DateTime<Utc>) -> Self {
         let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
@@ -263,8 +259,7 @@ impl Validate for TraceSpan {
         }
 
         // Validate name is not empty
-        validate_not_empty(&self.name, "name")
-            .map_err(|e| StorageError::validation(e))?;
+        validate_not_empty(&self.name, "name").map_err(|e| StorageError::validation(e))?;
 
         // Validate kind is one of the allowed values
         validate_status(
@@ -304,11 +299,7 @@ impl Validate for TraceSpan {
 
 impl TraceEvent {
     /// Create a new event.
-    pub fn new(
-        span_id: Uuid,
-        name: String,
-        timestamp: DateTime<Utc>,
-    ) -> Self {
+    pub fn new(span_id: Uuid, name: String, timestamp: DateTime<Utc>) -> Self {
         Self {
             id: Uuid::new_v4(),
             span_id,
@@ -323,8 +314,7 @@ impl TraceEvent {
 impl Validate for TraceEvent {
     fn validate(&self) -> StorageResult<()> {
         // Validate name is not empty
-        validate_not_empty(&self.name, "name")
-            .map_err(|e| StorageError::validation(e))?;
+        validate_not_empty(&self.name, "name").map_err(|e| StorageError::validation(e))?;
 
         Ok(())
     }
@@ -365,37 +355,70 @@ impl From<llm_observatory_core::span::LlmSpan> for TraceSpan {
         let mut attributes = serde_json::Map::new();
 
         // Add LLM-specific attributes
-        attributes.insert("llm.provider".to_string(), serde_json::json!(span.provider.as_str()));
+        attributes.insert(
+            "llm.provider".to_string(),
+            serde_json::json!(span.provider.as_str()),
+        );
         attributes.insert("llm.model".to_string(), serde_json::json!(span.model));
 
         // Add token usage if available
         if let Some(ref usage) = span.token_usage {
-            attributes.insert("llm.usage.prompt_tokens".to_string(), serde_json::json!(usage.prompt_tokens));
-            attributes.insert("llm.usage.completion_tokens".to_string(), serde_json::json!(usage.completion_tokens));
-            attributes.insert("llm.usage.total_tokens".to_string(), serde_json::json!(usage.total_tokens));
+            attributes.insert(
+                "llm.usage.prompt_tokens".to_string(),
+                serde_json::json!(usage.prompt_tokens),
+            );
+            attributes.insert(
+                "llm.usage.completion_tokens".to_string(),
+                serde_json::json!(usage.completion_tokens),
+            );
+            attributes.insert(
+                "llm.usage.total_tokens".to_string(),
+                serde_json::json!(usage.total_tokens),
+            );
         }
 
         // Add cost if available
         if let Some(ref cost) = span.cost {
-            attributes.insert("llm.cost.amount_usd".to_string(), serde_json::json!(cost.amount_usd));
+            attributes.insert(
+                "llm.cost.amount_usd".to_string(),
+                serde_json::json!(cost.amount_usd),
+            );
             if let Some(prompt_cost) = cost.prompt_cost {
-                attributes.insert("llm.cost.prompt_usd".to_string(), serde_json::json!(prompt_cost));
+                attributes.insert(
+                    "llm.cost.prompt_usd".to_string(),
+                    serde_json::json!(prompt_cost),
+                );
             }
             if let Some(completion_cost) = cost.completion_cost {
-                attributes.insert("llm.cost.completion_usd".to_string(), serde_json::json!(completion_cost));
+                attributes.insert(
+                    "llm.cost.completion_usd".to_string(),
+                    serde_json::json!(completion_cost),
+                );
             }
         }
 
         // Add latency metrics
-        attributes.insert("llm.latency.total_ms".to_string(), serde_json::json!(span.latency.total_ms));
+        attributes.insert(
+            "llm.latency.total_ms".to_string(),
+            serde_json::json!(span.latency.total_ms),
+        );
         if let Some(ttft_ms) = span.latency.ttft_ms {
-            attributes.insert("llm.latency.ttft_ms".to_string(), serde_json::json!(ttft_ms));
+            attributes.insert(
+                "llm.latency.ttft_ms".to_string(),
+                serde_json::json!(ttft_ms),
+            );
         }
 
         // Add input/output
-        attributes.insert("llm.input".to_string(), serde_json::to_value(&span.input).unwrap_or(serde_json::json!({})));
+        attributes.insert(
+            "llm.input".to_string(),
+            serde_json::to_value(&span.input).unwrap_or(serde_json::json!({})),
+        );
         if let Some(ref output) = span.output {
-            attributes.insert("llm.output".to_string(), serde_json::to_value(output).unwrap_or(serde_json::json!({})));
+            attributes.insert(
+                "llm.output".to_string(),
+                serde_json::to_value(output).unwrap_or(serde_json::json!({})),
+            );
         }
 
         // Add metadata
@@ -406,7 +429,10 @@ impl From<llm_observatory_core::span::LlmSpan> for TraceSpan {
             attributes.insert("session.id".to_string(), serde_json::json!(session_id));
         }
         if let Some(ref environment) = span.metadata.environment {
-            attributes.insert("deployment.environment".to_string(), serde_json::json!(environment));
+            attributes.insert(
+                "deployment.environment".to_string(),
+                serde_json::json!(environment),
+            );
         }
 
         // Merge custom attributes
@@ -432,7 +458,10 @@ impl From<llm_observatory_core::span::LlmSpan> for TraceSpan {
             parent_span_id: span.parent_span_id,
             name: span.name,
             kind: "internal".to_string(), // LlmSpan doesn't specify kind, default to internal
-            service_name: span.metadata.environment.unwrap_or_else(|| "llm-service".to_string()),
+            service_name: span
+                .metadata
+                .environment
+                .unwrap_or_else(|| "llm-service".to_string()),
             start_time: span.latency.start_time,
             end_time: Some(span.latency.end_time),
             duration_us: Some(span.latency.total_ms as i64 * 1000), // Convert ms to us
@@ -3,13 +3,15 @@
 //! This module provides repository interfaces for querying traces, metrics,
 //! and logs from the database.
 
-pub mod trace;
-pub mod metric;
-pub mod log;
 pub mod instrumented;
+pub mod log;
+pub mod metric;
+pub mod trace;
 
 // Re-exports
-pub use trace::TraceRepository;
-pub use metric::MetricRepository;
+pub use instrumented::{
+    InstrumentedLogRepository, InstrumentedMetricRepository, InstrumentedTraceRepository,
+};
 pub use log::LogRepository;
-pub use instrumented::{InstrumentedTraceRepository, InstrumentedMetricRepository, InstrumentedLogRepository};
+pub use metric::MetricRepository;
+pub use trace::TraceRepository;
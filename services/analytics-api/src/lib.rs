@@ -6,7 +6,14 @@ pub mod services;
 
 // Re-export commonly used types
 pub use errors::{ApiError, ErrorCategory, ErrorCode};
+pub use middleware::{
+    execution_context_middleware, ExecutionMiddlewareConfig, ReqExecutionContext,
+};
 pub use middleware::{AuthContext, JwtClaims, RequireAuth, Role};
-pub use middleware::{ExecutionMiddlewareConfig, ReqExecutionContext, execution_context_middleware};
-pub use models::{AppState, AnalyticsQuery, ErrorResponse, HealthResponse};
+pub use models::{AnalyticsQuery, AppState, ErrorResponse, HealthResponse};
+pub use services::executions::{ExecutionStore, InMemoryExecutionStore};
+pub use services::observations::{
+    InMemoryObservationStore, InsertOutcome, ObservationFeed, ObservationStore,
+};
+pub use services::service_metrics::ServiceMetrics;
 pub use services::timescaledb::TimescaleDBService;
@@ -1,4 +1,8 @@
-use analytics_api::{middleware::auth::JwtValidator, models::*, routes};
+use analytics_api::middleware::rate_limit::{RateLimitConfig, RateLimitLayer};
+use analytics_api::{
+    middleware::auth::JwtValidator, models::*, routes, InMemoryExecutionStore,
+    InMemoryObservationStore, ObservationFeed, ServiceMetrics,
+};
 use axum::{
     extract::State,
     http::{header, HeaderValue, Method, StatusCode},
@@ -35,10 +39,8 @@ async fn main() -> anyhow::Result<()> {
     info!("Starting Analytics API service");
 
     // Read configuration from environment
-    let database_url =
-        std::env::var("DATABASE_READONLY_URL").unwrap_or_else(|_| {
-            std::env::var("DATABASE_URL").expect("DATABASE_URL must be set")
-        });
+    let database_url = std::env::var("DATABASE_READONLY_URL")
+        .unwrap_or_else(|_| std::env::var("DATABASE_URL").expect("DATABASE_URL must be set"));
 
     let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| {
         let host = std::env::var("REDIS_HOST").unwrap_or_else(|_| "localhost".to_string());
@@ -107,11 +109,23 @@ async fn main() -> anyhow::Result<()> {
         .await?;
     info!("Redis connection established");
 
+    // Observations are held in memory; the oldest are evicted past this many
+    let observation_capacity = std::env::var("OBSERVATION_STORE_CAPACITY")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(analytics_api::services::observations::DEFAULT_OBSERVATION_STORE_CAPACITY);
+
     // Create application state
     let app_state = Arc::new(AppState {
         db_pool,
         redis_client,
         cache_ttl,
+        observations: Arc::new(InMemoryObservationStore::with_capacity(
+            observation_capacity,
+        )),
+        observation_feed: ObservationFeed::new(),
+        executions: Arc::new(InMemoryExecutionStore::new()),
+        metrics: ServiceMetrics::new(),
     });
 
     // Create JWT validator
@@ -125,7 +139,12 @@ async fn main() -> anyhow::Result<()> {
     info!("Analytics API listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    // Connect info gives the per-execution rate limiter a client IP to fall back on
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -152,7 +171,8 @@ fn build_router(
     // Execution context middleware configuration (permissive mode for backwards compatibility)
     let execution_config = analytics_api::middleware::ExecutionMiddlewareConfig::permissive(
         std::env::var("REPO_NAME").unwrap_or_else(|_| "llm-observatory".to_string()),
-    );
+    )
+    .with_metrics(state.metrics.clone());
 
     // Protected API routes (require authentication, rate limiting, and execution context)
     // Note: Axum layers execute in reverse order -- rate_limit runs first, then auth,
@@ -185,13 +205,45 @@ fn build_router(
             analytics_api::middleware::caching::cache_middleware(cache_config, req, next)
         }));
 
-    // Internal routes (no authentication required, service-to-service only)
-    let internal_routes = Router::new().merge(routes::observations::routes());
+    // Internal routes (service-to-service; observations, spans and executions require a role-scoped token)
+    // Per-execution rate limiting for ingestion, keyed on x-execution-id
+    let execution_rate_limit = RateLimitConfig {
+        requests_per_minute: std::env::var("EXECUTION_RATE_LIMIT_PER_MINUTE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(RateLimitConfig::per_execution_default().requests_per_minute),
+        ..RateLimitConfig::per_execution_default()
+    };
+    let ingest_rate_limit =
+        RateLimitLayer::new(state.redis_client.clone()).with_execution_limit(execution_rate_limit);
+    // Execution results are cached in memory per id; a re-POST invalidates the entry
+    let execution_cache_config = analytics_api::middleware::CacheConfig::new(
+        std::env::var("EXECUTION_CACHE_TTL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60),
+    );
+    let internal_routes = Router::new()
+        .merge(routes::observations::authorized_routes(
+            jwt_validator.clone(),
+        ))
+        .merge(routes::ingest::authorized_routes(jwt_validator.clone()))
+        .merge(routes::executions::authorized_routes(
+            jwt_validator,
+            execution_cache_config,
+        ))
+        .layer(middleware::from_fn_with_state(
+            ingest_rate_limit,
+            analytics_api::middleware::rate_limit::execution_rate_limit_middleware,
+        ));
 
     // Build main router
     Router::new()
         .route("/health", get(health_check))
-        .route("/metrics", get(move || async move { prometheus_handle.render() }))
+        .route(
+            "/metrics",
+            get(move || async move { prometheus_handle.render() }),
+        )
         .merge(protected_routes)
         .merge(public_routes)
         .merge(internal_routes)
@@ -206,7 +258,9 @@ fn build_router(
 }
 
 /// Health check endpoint
-async fn health_check(State(state): State<Arc<AppState>>) -> Result<Json<HealthResponse>, StatusCode> {
+async fn health_check(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<HealthResponse>, StatusCode> {
     // Check database
     let db_status = match sqlx::query("SELECT 1").execute(&state.db_pool).await {
         Ok(_) => "healthy",
@@ -215,15 +269,10 @@ async fn health_check(State(state): State<Arc<AppState>>) -> Result<Json<HealthR
 
     // Check Redis
     let redis_status = match state.redis_client.get_multiplexed_async_connection().await {
-        Ok(mut conn) => {
-            match redis::cmd("PING")
-                .query_async::<_, String>(&mut conn)
-                .await
-            {
-                Ok(_) => "healthy",
-                Err(_) => "unhealthy",
-            }
-        }
+        Ok(mut conn) => match redis::cmd("PING").query_async::<_, String>(&mut conn).await {
+            Ok(_) => "healthy",
+            Err(_) => "unhealthy",
+        },
         Err(_) => "unhealthy",
     };
 
@@ -254,12 +303,16 @@ fn setup_metrics_recorder() -> anyhow::Result<PrometheusHandle> {
     // Configure histogram buckets for latency metrics
     let builder = builder.set_buckets_for_metric(
         Matcher::Full("http_request_duration_seconds".to_string()),
-        &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0],
+        &[
+            0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+        ],
     )?;
 
     let builder = builder.set_buckets_for_metric(
         Matcher::Full("db_query_duration_seconds".to_string()),
-        &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0],
+        &[
+            0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+        ],
     )?;
 
     let handle = builder.install_recorder()?;
@@ -19,7 +19,6 @@
 ///!     .route("/traces", get(list_traces))
 ///!     .layer(RequireAuth::new(vec![Role::Admin, Role::Developer]));
 ///! ```
-
 use axum::{
     body::Body,
     extract::{FromRequestParts, Request, State},
@@ -138,6 +137,19 @@ impl Role {
         }
         self.default_permissions().contains(&permission.to_string())
     }
+
+    /// Roles granting at least this role's access
+    ///
+    /// Admin outranks Developer, which outranks Viewer. Billing sits outside
+    /// that chain and is only outranked by Admin.
+    pub fn at_least(&self) -> Vec<Role> {
+        match self {
+            Role::Admin => vec![Role::Admin],
+            Role::Developer => vec![Role::Admin, Role::Developer],
+            Role::Viewer => vec![Role::Admin, Role::Developer, Role::Viewer],
+            Role::Billing => vec![Role::Admin, Role::Billing],
+        }
+    }
 }
 
 /// Authentication context extracted from request
@@ -317,8 +329,8 @@ impl JwtValidator {
 
     /// Validate and decode JWT token
     pub fn validate(&self, token: &str) -> Result<JwtClaims, AuthError> {
-        let token_data = decode::<JwtClaims>(token, &self.decoding_key, &self.validation)
-            .map_err(|e| {
+        let token_data =
+            decode::<JwtClaims>(token, &self.decoding_key, &self.validation).map_err(|e| {
                 error!("JWT validation error: {}", e);
                 AuthError::InvalidToken
             })?;
@@ -403,6 +415,20 @@ impl RequireAuth {
             vec![Role::Admin, Role::Developer, Role::Viewer, Role::Billing],
         )
     }
+
+    /// Create auth middleware requiring `role` or any role outranking it,
+    /// sharing an existing validator
+    pub fn min_role(jwt_validator: Arc<JwtValidator>, role: Role) -> Self {
+        Self {
+            jwt_validator,
+            allowed_roles: role.at_least(),
+        }
+    }
+
+    /// Check if a role passes this guard
+    pub fn allows(&self, role: &Role) -> bool {
+        self.allowed_roles.contains(role)
+    }
 }
 
 /// Middleware function for authentication
@@ -411,6 +437,36 @@ pub async fn require_auth(
     mut req: Request,
     next: Next,
 ) -> Result<Response, AuthError> {
+    authenticate(&validator, &mut req)?;
+    Ok(next.run(req).await)
+}
+
+/// Middleware function enforcing a [`RequireAuth`] guard
+///
+/// Authenticates like [`require_auth`], then rejects roles the guard does not
+/// allow with `403 Forbidden`.
+pub async fn require_role(
+    State(guard): State<RequireAuth>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
+    let auth = authenticate(&guard.jwt_validator, &mut req)?;
+    if !guard.allows(&auth.role) {
+        warn!(
+            user_id = %auth.user_id,
+            role = ?auth.role,
+            allowed_roles = ?guard.allowed_roles,
+            request_id = %auth.request_id,
+            "Role not permitted"
+        );
+        return Err(AuthError::InsufficientPermissions);
+    }
+    Ok(next.run(req).await)
+}
+
+/// Validate the bearer token and attach the resulting [`AuthContext`] to the
+/// request extensions
+fn authenticate(validator: &JwtValidator, req: &mut Request) -> Result<AuthContext, AuthError> {
     let request_id = Uuid::new_v4().to_string();
 
     // Extract authorization header
@@ -441,10 +497,10 @@ pub async fn require_auth(
     );
 
     // Insert auth context into request extensions
-    req.extensions_mut().insert(auth_context);
+    req.extensions_mut().insert(auth_context.clone());
     req.extensions_mut().insert(request_id);
 
-    Ok(next.run(req).await)
+    Ok(auth_context)
 }
 
 /// Extract auth context from request
@@ -500,6 +556,20 @@ mod tests {
         assert!(!Role::Billing.has_permission("read:traces"));
     }
 
+    #[test]
+    fn test_require_auth_min_role() {
+        let validator = Arc::new(JwtValidator::new("secret"));
+        let writers = RequireAuth::min_role(validator.clone(), Role::Developer);
+        assert!(writers.allows(&Role::Admin));
+        assert!(writers.allows(&Role::Developer));
+        assert!(!writers.allows(&Role::Viewer));
+        assert!(!writers.allows(&Role::Billing));
+
+        let readers = RequireAuth::min_role(validator, Role::Viewer);
+        assert!(readers.allows(&Role::Viewer));
+        assert!(!readers.allows(&Role::Billing));
+    }
+
     #[test]
     fn test_jwt_claims_expiration() {
         let claims = JwtClaims::new(
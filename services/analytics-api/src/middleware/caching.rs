@@ -9,6 +9,8 @@
 ///! - Conditional request handling (If-None-Match, If-Modified-Since)
 ///! - Cache-Control header management
 ///! - Automatic 304 Not Modified responses
+///! - In-memory server-side [`ResponseCache`] for responses that are
+///!   expensive to rebuild, with explicit invalidation
 ///!
 ///! # Usage
 ///! ```rust,no_run
@@ -19,7 +21,6 @@
 ///!     .route("/api/v1/traces", get(list_traces))
 ///!     .layer(CacheMiddleware::new(60)); // 60 second cache TTL
 ///! ```
-
 use axum::{
     body::Body,
     extract::Request,
@@ -30,9 +31,16 @@ use axum::{
 use bytes::Bytes;
 use http_body_util::BodyExt;
 use sha2::{Digest, Sha256};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
 use tracing::{debug, info};
 
+/// Response header reporting whether a [`ResponseCache`] served the body
+/// (`HIT`) or it was rebuilt (`MISS`)
+pub const CACHE_STATUS_HEADER: &str = "x-cache";
+
 /// Cache configuration
 #[derive(Debug, Clone, Copy)]
 pub struct CacheConfig {
@@ -131,7 +139,8 @@ pub async fn cache_middleware(
     };
 
     // Check if client has a valid cached version
-    let not_modified = check_not_modified(&if_none_match, &if_modified_since, &etag, &last_modified);
+    let not_modified =
+        check_not_modified(&if_none_match, &if_modified_since, &etag, &last_modified);
 
     if not_modified {
         info!("Cache hit: returning 304 Not Modified");
@@ -144,10 +153,9 @@ pub async fn cache_middleware(
 
         // Copy cache headers
         if let Some(etag_value) = etag {
-            not_modified_response.headers_mut().insert(
-                header::ETAG,
-                HeaderValue::from_str(&etag_value).unwrap(),
-            );
+            not_modified_response
+                .headers_mut()
+                .insert(header::ETAG, HeaderValue::from_str(&etag_value).unwrap());
         }
 
         if let Some(modified_time) = last_modified {
@@ -172,10 +180,9 @@ pub async fn cache_middleware(
     debug!("Cache miss: returning full response with cache headers");
 
     if let Some(etag_value) = etag {
-        parts.headers.insert(
-            header::ETAG,
-            HeaderValue::from_str(&etag_value).unwrap(),
-        );
+        parts
+            .headers
+            .insert(header::ETAG, HeaderValue::from_str(&etag_value).unwrap());
     }
 
     if let Some(modified_time) = last_modified {
@@ -269,6 +276,109 @@ impl CacheMiddleware {
     }
 }
 
+/// In-memory cache of serialized response bodies
+///
+/// Entries expire after the configured TTL and can be dropped early with
+/// [`ResponseCache::invalidate`] when the underlying resource changes.
+///
+/// To avoid caching a body that was read before a concurrent invalidation,
+/// take [`ResponseCache::generation`] before reading the resource and store
+/// the body with [`ResponseCache::insert_if_current`].
+#[derive(Debug)]
+pub struct ResponseCache {
+    config: CacheConfig,
+    entries: RwLock<HashMap<String, CachedBody>>,
+    /// Bumped by every invalidation
+    generation: AtomicU64,
+}
+
+#[derive(Debug, Clone)]
+struct CachedBody {
+    body: Bytes,
+    expires_at: Instant,
+}
+
+impl ResponseCache {
+    /// Create an empty cache using the configuration's TTL
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: RwLock::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> CacheConfig {
+        self.config
+    }
+
+    /// Cached body for `key`, if present and not expired
+    pub async fn get(&self, key: &str) -> Option<Bytes> {
+        let entries = self.entries.read().await;
+        entries
+            .get(key)
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.body.clone())
+    }
+
+    /// Current invalidation generation, see [`ResponseCache::insert_if_current`]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Cache `body` under `key` for the configured TTL
+    pub async fn insert(&self, key: impl Into<String>, body: Bytes) {
+        let mut entries = self.entries.write().await;
+        Self::insert_locked(&mut entries, key.into(), body, self.config.ttl_seconds);
+    }
+
+    /// Cache `body` under `key` unless an invalidation happened since
+    /// `generation` was read, returning whether it was cached
+    ///
+    /// The body may then be stale, so it is left for the next read to cache.
+    pub async fn insert_if_current(
+        &self,
+        key: impl Into<String>,
+        body: Bytes,
+        generation: u64,
+    ) -> bool {
+        let mut entries = self.entries.write().await;
+        if self.generation() != generation {
+            return false;
+        }
+        Self::insert_locked(&mut entries, key.into(), body, self.config.ttl_seconds);
+        true
+    }
+
+    fn insert_locked(
+        entries: &mut HashMap<String, CachedBody>,
+        key: String,
+        body: Bytes,
+        ttl_seconds: u64,
+    ) {
+        let now = Instant::now();
+        entries.retain(|_, entry| entry.expires_at > now);
+        entries.insert(
+            key,
+            CachedBody {
+                body,
+                expires_at: now + Duration::from_secs(ttl_seconds),
+            },
+        );
+    }
+
+    /// Drop the entry for `key`, returning whether one was cached
+    ///
+    /// Also bumps the generation, so bodies read before this call are not
+    /// cached by [`ResponseCache::insert_if_current`].
+    pub async fn invalidate(&self, key: &str) -> bool {
+        let mut entries = self.entries.write().await;
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        entries.remove(key).is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,7 +431,12 @@ mod tests {
 
         // Different ETag should indicate modified
         let different_etag = Some("\"xyz789\"".to_string());
-        assert!(!check_not_modified(&if_none_match, &None, &different_etag, &None));
+        assert!(!check_not_modified(
+            &if_none_match,
+            &None,
+            &different_etag,
+            &None
+        ));
 
         // Wildcard should match
         let wildcard = Some("*".to_string());
@@ -346,11 +461,21 @@ mod tests {
         let if_modified_since = Some(now);
 
         // Client's cached version is newer, so not modified
-        assert!(check_not_modified(&None, &if_modified_since, &None, &last_modified));
+        assert!(check_not_modified(
+            &None,
+            &if_modified_since,
+            &None,
+            &last_modified
+        ));
 
         // Client's cached version is older, so modified
         let older_cache = Some(past - std::time::Duration::from_secs(3600));
-        assert!(!check_not_modified(&None, &older_cache, &None, &last_modified));
+        assert!(!check_not_modified(
+            &None,
+            &older_cache,
+            &None,
+            &last_modified
+        ));
     }
 
     #[test]
@@ -373,6 +498,43 @@ mod tests {
         assert!(etag.ends_with('"'));
     }
 
+    #[tokio::test]
+    async fn test_response_cache_expiry_and_invalidation() {
+        let cache = ResponseCache::new(CacheConfig::new(60));
+        cache.insert("execution:a", Bytes::from("{}")).await;
+        assert_eq!(cache.get("execution:a").await, Some(Bytes::from("{}")));
+        assert!(cache.invalidate("execution:a").await);
+        assert_eq!(cache.get("execution:a").await, None);
+
+        let expired = ResponseCache::new(CacheConfig::new(0));
+        expired.insert("execution:b", Bytes::from("{}")).await;
+        assert_eq!(expired.get("execution:b").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_skips_insert_after_invalidation() {
+        let cache = ResponseCache::new(CacheConfig::new(60));
+
+        // A read that started before a write must not cache what it read
+        let generation = cache.generation();
+        cache.invalidate("execution:a").await;
+        let stale = Bytes::from("{\"v\":1}");
+        assert!(
+            !cache
+                .insert_if_current("execution:a", stale, generation)
+                .await
+        );
+        assert_eq!(cache.get("execution:a").await, None);
+
+        let fresh = Bytes::from("{\"v\":2}");
+        assert!(
+            cache
+                .insert_if_current("execution:a", fresh.clone(), cache.generation())
+                .await
+        );
+        assert_eq!(cache.get("execution:a").await, Some(fresh));
+    }
+
     #[test]
     fn test_etag_with_large_body() {
         let large_body = Bytes::from(vec![0u8; 1_000_000]); // 1MB of zeros
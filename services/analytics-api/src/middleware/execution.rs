@@ -6,11 +6,13 @@
 //! Extracts agentic execution context from HTTP headers, creates a repo-level
 //! execution span, and injects the [`ExecutionContext`] into request extensions.
 //! Enforces that every externally-invoked operation has valid execution context
-//! when running in enforcing mode.
+//! when running in enforcing mode. Once the handler has produced a response,
+//! the repo span is completed or failed based on the status code and attached
+//! to the response extensions.
 
 use axum::{
-    extract::{FromRequestParts, Request},
-    http::{request::Parts, StatusCode},
+    extract::{FromRequestParts, MatchedPath, Request},
+    http::{request::Parts, HeaderMap, HeaderValue, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
@@ -20,9 +22,12 @@ use llm_observatory_core::execution::{
     headers, ExecutionContext, ExecutionSpan, ExecutionSpanKind, ExecutionSpanStatus,
 };
 use serde_json::json;
+use std::time::Instant;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::services::service_metrics::ServiceMetrics;
+
 /// Configuration for the execution context middleware.
 #[derive(Debug, Clone)]
 pub struct ExecutionMiddlewareConfig {
@@ -31,6 +36,8 @@ pub struct ExecutionMiddlewareConfig {
     /// Whether to enforce execution context (reject requests without it).
     /// Set to `false` for gradual rollout / backwards compatibility.
     pub enforce: bool,
+    /// Metrics for handler latency and finished repo spans.
+    metrics: ServiceMetrics,
 }
 
 impl ExecutionMiddlewareConfig {
@@ -39,6 +46,7 @@ impl ExecutionMiddlewareConfig {
         Self {
             repo_name: repo_name.into(),
             enforce: true,
+            metrics: ServiceMetrics::new(),
         }
     }
 
@@ -48,8 +56,15 @@ impl ExecutionMiddlewareConfig {
         Self {
             repo_name: repo_name.into(),
             enforce: false,
+            metrics: ServiceMetrics::new(),
         }
     }
+
+    /// Record into an existing metrics collector, such as the one in `AppState`.
+    pub fn with_metrics(mut self, metrics: ServiceMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
 }
 
 /// Execution context error response.
@@ -88,28 +103,24 @@ impl IntoResponse for ExecutionError {
 /// [`ExecutionContext`] and an [`ExecutionSpan`] (repo-level) into the
 /// request extensions. Route handlers can extract these via the
 /// `FromRequestParts` impl on `ExecutionContext`.
+///
+/// After the handler runs, the repo span is finalized with
+/// [`finalize_repo_span`], logged, and inserted into the response
+/// extensions so outer layers can store it.
+///
+/// Handler latency (`http_request_duration_seconds`) is recorded only for
+/// the routes this middleware is layered on; routes outside it, such as the
+/// ingest and execution routes, are not timed.
 pub async fn execution_context_middleware(
     config: ExecutionMiddlewareConfig,
     mut req: Request,
     next: Next,
 ) -> Result<Response, ExecutionError> {
-    let execution_id = req
-        .headers()
-        .get(headers::X_EXECUTION_ID)
-        .and_then(|v| v.to_str().ok())
-        .map(|s| s.to_string());
+    let mut active_span: Option<ExecutionSpan> = None;
 
-    let parent_span_id = req
-        .headers()
-        .get(headers::X_EXECUTION_PARENT_SPAN_ID)
-        .and_then(|v| v.to_str().ok())
-        .map(|s| s.to_string());
-
-    let repo_name_override = req
-        .headers()
-        .get(headers::X_EXECUTION_REPO_NAME)
-        .and_then(|v| v.to_str().ok())
-        .map(|s| s.to_string());
+    let execution_id = header_string(req.headers(), headers::X_EXECUTION_ID);
+    let parent_span_id = header_string(req.headers(), headers::X_EXECUTION_PARENT_SPAN_ID);
+    let repo_name_override = header_string(req.headers(), headers::X_EXECUTION_REPO_NAME);
 
     if config.enforce {
         let exec_id = execution_id.ok_or_else(|| ExecutionError {
@@ -161,7 +172,8 @@ pub async fn execution_context_middleware(
         );
 
         req.extensions_mut().insert(ctx);
-        req.extensions_mut().insert(repo_span);
+        req.extensions_mut().insert(repo_span.clone());
+        active_span = Some(repo_span);
     } else if let (Some(exec_id), Some(parent_id)) = (&execution_id, &parent_span_id) {
         // Permissive mode: create context when headers are present
         let repo_name = repo_name_override.unwrap_or_else(|| config.repo_name.clone());
@@ -188,14 +200,117 @@ pub async fn execution_context_middleware(
             );
 
             req.extensions_mut().insert(ctx);
-            req.extensions_mut().insert(repo_span);
+            req.extensions_mut().insert(repo_span.clone());
+            active_span = Some(repo_span);
         }
     } else {
         // No execution headers in permissive mode
         warn!("No execution context headers found (permissive mode, proceeding without context)");
     }
 
-    Ok(next.run(req).await)
+    let method = req.method().to_string();
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", |p| p.as_str())
+        .to_string();
+    let started = Instant::now();
+    let mut response = next.run(req).await;
+    config.metrics.record_handler_latency(
+        &method,
+        &path,
+        response.status().as_u16(),
+        started.elapsed().as_secs_f64(),
+    );
+
+    if let Some(mut repo_span) = active_span {
+        finalize_repo_span(&mut repo_span, response.status());
+        let status = if repo_span.is_failed() {
+            "FAILED"
+        } else {
+            "COMPLETED"
+        };
+        config.metrics.record_execution_span(status);
+        info!(
+            execution_id = %repo_span.execution_id,
+            repo_span_id = %repo_span.span_id,
+            status = ?repo_span.status,
+            duration_ms = ?repo_span.duration_ms,
+            "Repo execution span finished"
+        );
+        response.extensions_mut().insert(repo_span);
+    }
+
+    Ok(response)
+}
+
+/// Read a header as an owned string, ignoring non-UTF-8 values.
+fn header_string(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+}
+
+/// Build the [`ExecutionContext`] a service would see for incoming headers.
+///
+/// This mirrors the header handling in [`execution_context_middleware`]
+/// without creating a repo span, so `repo_span_id` is always `None`.
+/// Returns `None` unless both `x-execution-id` and
+/// `x-execution-parent-span-id` are present.
+pub fn extract_execution_context(
+    headers: &HeaderMap,
+    default_repo_name: &str,
+) -> Option<ExecutionContext> {
+    Some(ExecutionContext {
+        execution_id: header_string(headers, headers::X_EXECUTION_ID)?,
+        parent_span_id: header_string(headers, headers::X_EXECUTION_PARENT_SPAN_ID)?,
+        repo_span_id: None,
+        repo_name: header_string(headers, headers::X_EXECUTION_REPO_NAME)
+            .unwrap_or_else(|| default_repo_name.to_string()),
+    })
+}
+
+/// Write execution context headers for a downstream call.
+///
+/// `x-execution-id` is passed through unchanged and
+/// `x-execution-parent-span-id` is set to this service's repo span, so the
+/// downstream repo span becomes its child. If no repo span exists yet, the
+/// caller's parent span is forwarded instead. The repo name is not
+/// propagated; the downstream service reports its own.
+///
+/// Round trip: a service calling [`extract_execution_context`] (or running
+/// [`execution_context_middleware`]) on these headers sees the same
+/// `execution_id` and a `parent_span_id` equal to `ctx.repo_span_id`.
+pub fn inject_execution_headers(ctx: &ExecutionContext, headers: &mut HeaderMap) {
+    let parent_span_id = ctx.repo_span_id.as_deref().unwrap_or(&ctx.parent_span_id);
+
+    for (name, value) in [
+        (headers::X_EXECUTION_ID, ctx.execution_id.as_str()),
+        (headers::X_EXECUTION_PARENT_SPAN_ID, parent_span_id),
+    ] {
+        match HeaderValue::from_str(value) {
+            Ok(value) => {
+                headers.insert(name, value);
+            }
+            Err(_) => warn!(header = name, "Skipping invalid execution header value"),
+        }
+    }
+}
+
+/// Finish a repo span according to the HTTP status of the response.
+///
+/// 4xx and 5xx responses mark the span failed; anything else completes it.
+/// Both set `end_time` and `duration_ms`.
+pub fn finalize_repo_span(repo_span: &mut ExecutionSpan, status: StatusCode) {
+    if status.is_client_error() || status.is_server_error() {
+        repo_span.fail(format!(
+            "Request failed with HTTP status {}",
+            status.as_u16()
+        ));
+    } else {
+        repo_span.complete();
+    }
 }
 
 /// Newtype wrapper for extracting [`ExecutionContext`] from request parts.
@@ -245,3 +360,88 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn test_router() -> Router {
+        let config = ExecutionMiddlewareConfig::new("llm-observatory");
+        Router::new()
+            .route("/ok", get(|| async { StatusCode::OK }))
+            .route("/boom", get(|| async { StatusCode::INTERNAL_SERVER_ERROR }))
+            .layer(middleware::from_fn(move |req, next| {
+                execution_context_middleware(config.clone(), req, next)
+            }))
+    }
+
+    async fn finished_span(uri: &str) -> ExecutionSpan {
+        let request = Request::builder()
+            .uri(uri)
+            .header(headers::X_EXECUTION_ID, "exec-1")
+            .header(headers::X_EXECUTION_PARENT_SPAN_ID, "caller-span-1")
+            .body(Body::empty())
+            .unwrap();
+        let response = test_router().oneshot(request).await.unwrap();
+        response
+            .extensions()
+            .get::<ExecutionSpan>()
+            .cloned()
+            .expect("repo span attached to response")
+    }
+
+    #[test]
+    fn test_inject_then_extract_round_trip() {
+        let ctx = ExecutionContext {
+            execution_id: "exec-1".to_string(),
+            parent_span_id: "caller-span-1".to_string(),
+            repo_span_id: Some("repo-span-1".to_string()),
+            repo_name: "llm-observatory".to_string(),
+        };
+
+        let mut header_map = HeaderMap::new();
+        inject_execution_headers(&ctx, &mut header_map);
+
+        let downstream = extract_execution_context(&header_map, "downstream-repo").unwrap();
+        assert_eq!(downstream.execution_id, ctx.execution_id);
+        assert_eq!(Some(downstream.parent_span_id), ctx.repo_span_id);
+        assert_eq!(downstream.repo_name, "downstream-repo");
+        assert!(downstream.repo_span_id.is_none());
+    }
+
+    #[test]
+    fn test_inject_without_repo_span_forwards_parent() {
+        let ctx = ExecutionContext {
+            execution_id: "exec-1".to_string(),
+            parent_span_id: "caller-span-1".to_string(),
+            repo_span_id: None,
+            repo_name: "llm-observatory".to_string(),
+        };
+
+        let mut header_map = HeaderMap::new();
+        inject_execution_headers(&ctx, &mut header_map);
+        assert_eq!(
+            header_map.get(headers::X_EXECUTION_PARENT_SPAN_ID).unwrap(),
+            "caller-span-1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_success_response_completes_repo_span() {
+        let span = finished_span("/ok").await;
+        assert_eq!(span.status, ExecutionSpanStatus::Completed);
+        assert_eq!(span.parent_span_id, "caller-span-1");
+        assert!(span.end_time.is_some());
+        assert!(span.duration_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_error_response_fails_repo_span() {
+        let span = finished_span("/boom").await;
+        assert_eq!(span.status, ExecutionSpanStatus::Failed);
+        assert!(span.error_message.unwrap().contains("500"));
+        assert!(span.end_time.is_some());
+    }
+}
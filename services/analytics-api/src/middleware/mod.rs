@@ -5,6 +5,9 @@ pub mod execution;
 pub mod rate_limit;
 
 pub use auth::{AuthContext, JwtClaims, RequireAuth, Role};
-pub use caching::{CacheConfig, CacheMiddleware};
-pub use execution::{execution_context_middleware, ExecutionMiddlewareConfig, ReqExecutionContext};
+pub use caching::{CacheConfig, CacheMiddleware, ResponseCache};
+pub use execution::{
+    execution_context_middleware, extract_execution_context, inject_execution_headers,
+    ExecutionMiddlewareConfig, ReqExecutionContext,
+};
 pub use rate_limit::{RateLimitLayer, RateLimiter};
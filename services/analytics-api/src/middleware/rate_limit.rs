@@ -10,6 +10,17 @@
 ///! - Tiered rate limits based on user role
 ///! - Per-user and per-API-key rate limiting
 ///! - Rate limit headers in responses (X-RateLimit-*)
+///! - Optional per-execution buckets keyed on `x-execution-id`
+///!
+///! # Per-execution limiting
+///! [`execution_rate_limit_middleware`] buckets requests by the
+///! `x-execution-id` header, falling back to the client IP when the header is
+///! absent, so one runaway agent cannot starve the others. Its keys use their
+///! own `ratelimit:exec:` / `ratelimit:ip:` prefix and never share a bucket
+///! with the per-user limiter. When both middlewares are applied, a request
+///! must pass both: the per-user budget and the per-execution budget.
+///! The client IP is only known when the server is started with
+///! `into_make_service_with_connect_info::<SocketAddr>()`.
 ///!
 ///! # Usage
 ///! ```rust,no_run
@@ -20,18 +31,23 @@
 ///!     .route("/api/v1/traces", get(list_traces))
 ///!     .layer(RateLimitLayer::new(redis_client));
 ///! ```
-
 use axum::{
     body::Body,
-    extract::{Request, State},
-    http::{HeaderValue, StatusCode},
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
 };
+use llm_observatory_core::execution::headers::X_EXECUTION_ID;
 use redis::{aio::MultiplexedConnection, AsyncCommands};
 use serde_json::json;
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 use tracing::{error, info, warn};
 
 use super::auth::{AuthContext, Role};
@@ -68,6 +84,14 @@ impl RateLimitConfig {
         }
     }
 
+    /// Default per-execution budget used by [`RateLimitLayer::with_execution_limit`]
+    pub fn per_execution_default() -> Self {
+        Self {
+            requests_per_minute: 600,
+            burst_capacity: 720,
+        }
+    }
+
     /// Get refill rate (tokens per second)
     fn refill_rate(&self) -> f64 {
         self.requests_per_minute as f64 / 60.0
@@ -178,6 +202,25 @@ impl RateLimiter {
     fn rate_limit_key(user_id: &str, endpoint: &str) -> String {
         format!("ratelimit:{}:{}", user_id, endpoint)
     }
+
+    /// Get rate limit key for an execution, falling back to the client IP
+    /// when no `x-execution-id` header is present
+    fn execution_rate_limit_key(
+        headers: &HeaderMap,
+        client_ip: Option<IpAddr>,
+        endpoint: &str,
+    ) -> String {
+        let execution_id = headers
+            .get(X_EXECUTION_ID)
+            .and_then(|v| v.to_str().ok())
+            .filter(|id| !id.is_empty());
+
+        match (execution_id, client_ip) {
+            (Some(id), _) => format!("ratelimit:exec:{}:{}", id, endpoint),
+            (None, Some(ip)) => format!("ratelimit:ip:{}:{}", ip, endpoint),
+            (None, None) => format!("ratelimit:ip:unknown:{}", endpoint),
+        }
+    }
 }
 
 /// Rate limit state
@@ -265,6 +308,16 @@ impl IntoResponse for RateLimitError {
     }
 }
 
+/// Endpoint part of a rate limit key: the matched route template (e.g.
+/// `/api/v1/executions/:execution_id`), so requests for different ids share
+/// one bucket, or the raw path when no route matched.
+fn rate_limit_endpoint(req: &Request) -> String {
+    req.extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string())
+}
+
 /// Rate limiting middleware
 pub async fn rate_limit_middleware(
     auth: AuthContext,
@@ -275,8 +328,8 @@ pub async fn rate_limit_middleware(
     // Get rate limit configuration for user's role
     let config = RateLimitConfig::for_role(&auth.role);
 
-    // Extract endpoint path for rate limit key
-    let endpoint = req.uri().path().to_string();
+    // Extract endpoint route for rate limit key
+    let endpoint = rate_limit_endpoint(&req);
 
     // Create rate limit key
     let key = RateLimiter::rate_limit_key(&auth.user_id, &endpoint);
@@ -286,9 +339,9 @@ pub async fn rate_limit_middleware(
         .get_multiplexed_async_connection()
         .await
         .map_err(|e| {
-            error!("Failed to get Redis connection: {}", e);
-            RateLimitError::Internal("Rate limit service unavailable".to_string())
-        })?;
+        error!("Failed to get Redis connection: {}", e);
+        RateLimitError::Internal("Rate limit service unavailable".to_string())
+    })?;
 
     // Create rate limiter
     let mut limiter = RateLimiter::new(redis_conn);
@@ -326,10 +379,109 @@ pub async fn rate_limit_middleware(
     Ok(response)
 }
 
+/// Per-execution rate limiting middleware
+///
+/// Buckets requests by `x-execution-id` (or client IP when absent) using the
+/// layer's execution limit. Passes requests through untouched when the layer
+/// has no execution limit configured. Over-limit requests get `429` with a
+/// `Retry-After` header.
+pub async fn execution_rate_limit_middleware(
+    State(layer): State<RateLimitLayer>,
+    req: Request,
+    next: Next,
+) -> Result<Response, RateLimitError> {
+    let Some(config) = layer.execution_limit else {
+        return Ok(next.run(req).await);
+    };
+
+    let client_ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    let endpoint = rate_limit_endpoint(&req);
+    let key = RateLimiter::execution_rate_limit_key(req.headers(), client_ip, &endpoint);
+
+    let state = match &layer.local_buckets {
+        Some(buckets) => buckets.check(&key, config),
+        None => {
+            let redis_conn = layer
+                .redis_client
+                .get_multiplexed_async_connection()
+                .await
+                .map_err(|e| {
+                    error!("Failed to get Redis connection: {}", e);
+                    RateLimitError::Internal("Rate limit service unavailable".to_string())
+                })?;
+            let mut limiter = RateLimiter::new(redis_conn);
+            limiter.check_rate_limit(&key, config).await?
+        }
+    };
+
+    if !state.allowed {
+        info!(key = %key, "Execution rate limit exceeded");
+        let mut response = RateLimitError::Exceeded.into_response();
+        state.add_headers(&mut response);
+        return Ok(response);
+    }
+
+    let mut response = next.run(req).await;
+    state.add_headers(&mut response);
+    Ok(response)
+}
+
+/// Token buckets held in process memory
+///
+/// Same refill rules as the Redis script, but not shared between API
+/// instances. Buckets idle for longer than the window are dropped.
+#[derive(Debug, Clone, Default)]
+pub struct LocalBuckets {
+    /// Key -> (tokens, last refill in unix seconds)
+    buckets: Arc<Mutex<HashMap<String, (f64, u64)>>>,
+}
+
+impl LocalBuckets {
+    /// Window after which an idle bucket is forgotten, matching the Redis `EXPIRE`
+    const WINDOW_SECONDS: u64 = 60;
+
+    /// Check the rate limit for `key` and consume a token if allowed
+    pub fn check(&self, key: &str, config: RateLimitConfig) -> RateLimitState {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let capacity = config.burst_capacity as f64;
+
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets
+            .retain(|_, (_, last_refill)| now.saturating_sub(*last_refill) < Self::WINDOW_SECONDS);
+        let (tokens, last_refill) = buckets.entry(key.to_string()).or_insert((capacity, now));
+
+        let elapsed = now.saturating_sub(*last_refill) as f64;
+        *tokens = capacity.min(*tokens + elapsed * config.refill_rate());
+        *last_refill = now;
+
+        let allowed = *tokens >= 1.0;
+        if allowed {
+            *tokens -= 1.0;
+        } else {
+            warn!("Rate limit exceeded for key: {}", key);
+        }
+
+        RateLimitState {
+            allowed,
+            limit: config.burst_capacity,
+            remaining: *tokens as u32,
+            reset_at: now + Self::WINDOW_SECONDS,
+        }
+    }
+}
+
 /// Rate limit layer for Axum
 #[derive(Clone)]
 pub struct RateLimitLayer {
     redis_client: Arc<redis::Client>,
+    execution_limit: Option<RateLimitConfig>,
+    local_buckets: Option<LocalBuckets>,
 }
 
 impl RateLimitLayer {
@@ -337,8 +489,25 @@ impl RateLimitLayer {
     pub fn new(redis_client: redis::Client) -> Self {
         Self {
             redis_client: Arc::new(redis_client),
+            execution_limit: None,
+            local_buckets: None,
         }
     }
+
+    /// Enable per-execution buckets with the given budget
+    /// (see [`execution_rate_limit_middleware`])
+    pub fn with_execution_limit(mut self, config: RateLimitConfig) -> Self {
+        self.execution_limit = Some(config);
+        self
+    }
+
+    /// Keep per-execution buckets in process memory instead of Redis
+    ///
+    /// Only suitable for a single API instance.
+    pub fn with_local_buckets(mut self) -> Self {
+        self.local_buckets = Some(LocalBuckets::default());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -385,6 +554,136 @@ mod tests {
         assert_eq!(key, "ratelimit:user123:/api/v1/traces");
     }
 
+    #[test]
+    fn test_execution_rate_limit_key_generation() {
+        let ip: IpAddr = "10.0.0.7".parse().unwrap();
+        let endpoint = "/api/v1/observations";
+
+        let mut exec_a = HeaderMap::new();
+        exec_a.insert(X_EXECUTION_ID, HeaderValue::from_static("exec-a"));
+        let mut exec_b = HeaderMap::new();
+        exec_b.insert(X_EXECUTION_ID, HeaderValue::from_static("exec-b"));
+
+        let key_a = RateLimiter::execution_rate_limit_key(&exec_a, Some(ip), endpoint);
+        let key_b = RateLimiter::execution_rate_limit_key(&exec_b, Some(ip), endpoint);
+        assert_eq!(key_a, "ratelimit:exec:exec-a:/api/v1/observations");
+        assert_ne!(key_a, key_b);
+
+        let fallback = RateLimiter::execution_rate_limit_key(&HeaderMap::new(), Some(ip), endpoint);
+        assert_eq!(fallback, "ratelimit:ip:10.0.0.7:/api/v1/observations");
+    }
+
+    #[tokio::test]
+    async fn test_client_ips_have_independent_budgets() {
+        use axum::{routing::post, Router};
+        use tower::ServiceExt;
+
+        let layer = RateLimitLayer::new(redis::Client::open("redis://127.0.0.1/").unwrap())
+            .with_execution_limit(RateLimitConfig {
+                requests_per_minute: 1,
+                burst_capacity: 1,
+            })
+            .with_local_buckets();
+        let app = Router::new()
+            .route(
+                "/api/v1/observations",
+                post(|| async { StatusCode::ACCEPTED }),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                layer,
+                execution_rate_limit_middleware,
+            ));
+
+        let send = |ip: [u8; 4], execution_id: Option<&'static str>| {
+            let mut builder = axum::http::Request::builder()
+                .method("POST")
+                .uri("/api/v1/observations");
+            if let Some(id) = execution_id {
+                builder = builder.header(X_EXECUTION_ID, id);
+            }
+            let mut req = builder.body(Body::empty()).unwrap();
+            req.extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from((ip, 40000))));
+            app.clone().oneshot(req)
+        };
+
+        assert_eq!(
+            send([10, 0, 0, 1], None).await.unwrap().status(),
+            StatusCode::ACCEPTED
+        );
+        assert_eq!(
+            send([10, 0, 0, 1], None).await.unwrap().status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        // A different client still has its full budget
+        assert_eq!(
+            send([10, 0, 0, 2], None).await.unwrap().status(),
+            StatusCode::ACCEPTED
+        );
+
+        // So does an execution calling from an exhausted IP
+        assert_eq!(
+            send([10, 0, 0, 1], Some("exec-a")).await.unwrap().status(),
+            StatusCode::ACCEPTED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_executions_have_independent_budgets_per_route() {
+        use axum::{routing::post, Router};
+        use tower::ServiceExt;
+
+        let layer = RateLimitLayer::new(redis::Client::open("redis://127.0.0.1/").unwrap())
+            .with_execution_limit(RateLimitConfig {
+                requests_per_minute: 1,
+                burst_capacity: 2,
+            })
+            .with_local_buckets();
+        let app = Router::new()
+            .route(
+                "/api/v1/executions/:execution_id",
+                post(|| async { StatusCode::ACCEPTED }),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                layer,
+                execution_rate_limit_middleware,
+            ));
+
+        let send = |execution_id: &'static str, uri: &'static str| {
+            let mut req = axum::http::Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header(X_EXECUTION_ID, execution_id)
+                .body(Body::empty())
+                .unwrap();
+            req.extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 40000))));
+            app.clone().oneshot(req)
+        };
+
+        // Different paths of one route draw from the same bucket
+        for uri in ["/api/v1/executions/1", "/api/v1/executions/2"] {
+            assert_eq!(
+                send("exec-a", uri).await.unwrap().status(),
+                StatusCode::ACCEPTED
+            );
+        }
+        let limited = send("exec-a", "/api/v1/executions/3").await.unwrap();
+        assert_eq!(limited.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = limited.headers()["Retry-After"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(retry_after > 0 && retry_after <= LocalBuckets::WINDOW_SECONDS);
+
+        // Another execution from the same IP is still accepted
+        let response = send("exec-b", "/api/v1/executions/3").await.unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert!(response.headers().get("Retry-After").is_none());
+    }
+
     #[test]
     fn test_rate_limit_state() {
         let state = RateLimitState {
@@ -1,18 +1,27 @@
 pub mod costs;
+pub mod executions;
 pub mod export;
 pub mod filters;
 pub mod metrics;
+pub mod observations;
 pub mod traces;
 pub mod websocket;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::services::executions::ExecutionStore;
+use crate::services::observations::{ObservationFeed, ObservationStore};
+use crate::services::service_metrics::ServiceMetrics;
 
 pub use costs::*;
+pub use executions::*;
 pub use export::*;
 pub use filters::*;
 pub use metrics::*;
+pub use observations::*;
 pub use traces::*;
 pub use websocket::*;
 
@@ -326,6 +335,10 @@ pub struct AppState {
     pub db_pool: sqlx::PgPool,
     pub redis_client: redis::Client,
     pub cache_ttl: u64,
+    pub observations: Arc<dyn ObservationStore>,
+    pub observation_feed: ObservationFeed,
+    pub executions: Arc<dyn ExecutionStore>,
+    pub metrics: ServiceMetrics,
 }
 
 /// API error response
@@ -0,0 +1,101 @@
+///! Data models for submitting and retrieving complete execution results
+///!
+///! Agents post their finished execution tree to `/api/v1/executions`, either
+///! as a full [`ExecutionResult`] or as a flat list of spans.
+use chrono::{DateTime, Utc};
+use llm_observatory_core::execution::{ExecutionResult, ExecutionSpan};
+use serde::{Deserialize, Serialize};
+
+/// Body accepted by `POST /api/v1/executions`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ExecutionSubmission {
+    /// A flat, unordered list of spans containing exactly one repo span
+    Spans { spans: Vec<ExecutionSpan> },
+    /// A fully assembled execution result
+    Result(Box<ExecutionResult>),
+}
+
+/// Validation summary returned for a submitted execution
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecutionSummary {
+    /// Empty (and omitted) only when the submitted span list was empty
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub execution_id: String,
+    pub valid: bool,
+    #[serde(default)]
+    pub validation_errors: Vec<String>,
+    pub agent_span_count: usize,
+    pub total_artifacts: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_duration_ms: Option<u64>,
+}
+
+impl From<&ExecutionResult> for ExecutionSummary {
+    fn from(result: &ExecutionResult) -> Self {
+        Self {
+            execution_id: result.execution_id.clone(),
+            valid: result.valid,
+            validation_errors: result.validation_errors.clone(),
+            agent_span_count: result.agent_spans.len(),
+            total_artifacts: result.total_artifacts,
+            total_duration_ms: result.total_duration_ms,
+        }
+    }
+}
+
+/// Query parameters for listing stored executions
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExecutionQuery {
+    /// Only return executions of this repository
+    pub repo_name: Option<String>,
+    /// Only return executions whose repo span started at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only return executions with this validity
+    pub valid: Option<bool>,
+}
+
+impl ExecutionQuery {
+    /// Whether a result satisfies every filter that is set
+    pub fn matches(&self, result: &ExecutionResult) -> bool {
+        self.repo_name
+            .as_ref()
+            .map_or(true, |repo| &result.repo_span.repo_name == repo)
+            && self
+                .since
+                .map_or(true, |since| result.repo_span.start_time >= since)
+            && self.valid.map_or(true, |valid| result.valid == valid)
+    }
+}
+
+/// One stored execution in a listing, without its span tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionListItem {
+    pub execution_id: String,
+    pub repo_name: String,
+    pub valid: bool,
+    pub start_time: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_duration_ms: Option<u64>,
+    pub agent_span_count: usize,
+}
+
+impl From<&ExecutionResult> for ExecutionListItem {
+    fn from(result: &ExecutionResult) -> Self {
+        Self {
+            execution_id: result.execution_id.clone(),
+            repo_name: result.repo_span.repo_name.clone(),
+            valid: result.valid,
+            start_time: result.repo_span.start_time,
+            total_duration_ms: result.total_duration_ms,
+            agent_span_count: result.agent_spans.len(),
+        }
+    }
+}
+
+/// Response for listing stored executions, newest first
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecutionListResponse {
+    pub items: Vec<ExecutionListItem>,
+    pub total: usize,
+}
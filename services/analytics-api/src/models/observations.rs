@@ -0,0 +1,131 @@
+///! Data models for observation ingestion and retrieval
+///!
+///! Observations are lightweight events pushed by agents and services over the
+///! internal `/api/v1/observations` endpoints.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A single observation event reported by an agent or service
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObservationEvent {
+    pub source: String,
+    pub event_type: String,
+    pub execution_id: String,
+    pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub payload: Value,
+    /// Client-chosen dedup key; retried submissions with the same key are
+    /// stored only once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+}
+
+impl ObservationEvent {
+    /// Parse and validate a single event from raw JSON
+    ///
+    /// Returns a human-readable reason when the event is malformed (including
+    /// an unparseable timestamp) or `source`/`event_type` is empty.
+    pub fn from_value(value: Value) -> Result<Self, String> {
+        let event: Self = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        if event.source.trim().is_empty() {
+            return Err("source must not be empty".to_string());
+        }
+        if event.event_type.trim().is_empty() {
+            return Err("event_type must not be empty".to_string());
+        }
+        Ok(event)
+    }
+}
+
+/// Response for an accepted observation
+#[derive(Debug, Serialize)]
+pub struct ObservationResponse {
+    pub status: &'static str,
+    pub execution_id: String,
+    /// Set when an event with the same `event_id` was already stored
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub duplicate: bool,
+}
+
+/// Outcome for a single event in a batch submission
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchItemResult {
+    /// Position of the event in the submitted batch
+    pub index: usize,
+    /// "accepted", "rejected" or "failed" (valid but could not be stored)
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    /// Rejection or failure reason
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Set when an accepted event was a duplicate and not stored again
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub duplicate: bool,
+}
+
+/// Response for a batch submission, summarizing per-item outcomes
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchObservationResponse {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub results: Vec<BatchItemResult>,
+}
+
+/// Page size used when `limit` is not given
+pub const DEFAULT_OBSERVATION_PAGE_SIZE: usize = 50;
+
+/// Largest accepted `limit`
+pub const MAX_OBSERVATION_PAGE_SIZE: usize = 1000;
+
+/// Query parameters for listing stored observations
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ObservationQuery {
+    /// Only return observations for this execution
+    pub execution_id: Option<String>,
+    /// Only return observations from this source
+    pub source: Option<String>,
+    /// Maximum number of items to return (default 50, max 1000)
+    pub limit: Option<usize>,
+    /// Number of matching items to skip
+    pub offset: Option<usize>,
+}
+
+impl ObservationQuery {
+    /// Effective page size
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_OBSERVATION_PAGE_SIZE)
+    }
+
+    /// Effective number of items to skip
+    pub fn offset(&self) -> usize {
+        self.offset.unwrap_or(0)
+    }
+
+    /// Whether an event satisfies every filter that is set
+    pub fn matches(&self, event: &ObservationEvent) -> bool {
+        self.execution_id
+            .as_ref()
+            .map_or(true, |id| &event.execution_id == id)
+            && self.source.as_ref().map_or(true, |s| &event.source == s)
+    }
+}
+
+/// One page of stored observations, newest first
+#[derive(Debug, Clone, Default)]
+pub struct ObservationPage {
+    /// Events in this page
+    pub items: Vec<ObservationEvent>,
+    /// Number of events matching the filters across all pages
+    pub total: usize,
+}
+
+/// Response for listing stored observations
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ObservationListResponse {
+    pub items: Vec<ObservationEvent>,
+    pub total: usize,
+    /// Offset of the next page, absent on the last page
+    pub next_offset: Option<usize>,
+}
@@ -0,0 +1,211 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderName, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use bytes::Bytes;
+use llm_observatory_core::execution::ExecutionResult;
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
+
+use crate::errors::ApiError;
+use crate::middleware::auth::{require_role, JwtValidator, RequireAuth, Role};
+use crate::middleware::caching::{cache_middleware, CACHE_STATUS_HEADER};
+use crate::middleware::{CacheConfig, ResponseCache};
+use crate::models::{
+    AppState, ExecutionListResponse, ExecutionQuery, ExecutionSubmission, ExecutionSummary,
+};
+
+/// Execution routes with the default result-cache configuration
+pub fn routes() -> Router<Arc<AppState>> {
+    routes_with_cache(CacheConfig::default())
+}
+
+/// Execution routes caching `GET` responses for `config.ttl_seconds`
+///
+/// Serialized results are kept in a [`ResponseCache`] under
+/// [`execution_cache_key`]. A successful `POST` for an execution id drops that
+/// id's entry, so the next `GET` reflects the new result; a `GET` that raced
+/// with the `POST` serves what it read but does not cache it. Responses carry an
+/// `x-cache: HIT|MISS` header, plus the usual ETag/Cache-Control headers.
+pub fn routes_with_cache(config: CacheConfig) -> Router<Arc<AppState>> {
+    let cache = Arc::new(ResponseCache::new(config));
+
+    Router::new()
+        .route("/api/v1/executions", post(submit_execution))
+        .merge(read_routes(config))
+        .layer(Extension(cache))
+}
+
+/// Cached execution routes guarded by role: submitting requires `Developer`
+/// and listing or fetching requires `Viewer` (or any role outranking them)
+///
+/// The role check runs before the cache, so cached results are never served
+/// to unauthorized callers.
+pub fn authorized_routes(
+    jwt_validator: Arc<JwtValidator>,
+    config: CacheConfig,
+) -> Router<Arc<AppState>> {
+    let writers = RequireAuth::min_role(jwt_validator.clone(), Role::Developer);
+    let readers = RequireAuth::min_role(jwt_validator, Role::Viewer);
+    let cache = Arc::new(ResponseCache::new(config));
+
+    let write_routes = Router::new()
+        .route("/api/v1/executions", post(submit_execution))
+        .route_layer(middleware::from_fn_with_state(writers, require_role));
+    let read_routes =
+        read_routes(config).route_layer(middleware::from_fn_with_state(readers, require_role));
+
+    write_routes.merge(read_routes).layer(Extension(cache))
+}
+
+/// `GET` routes, with the per-result cache middleware on lookups by id
+fn read_routes(config: CacheConfig) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/v1/executions", get(list_executions))
+        .route(
+            "/api/v1/executions/:execution_id",
+            get(get_execution).layer(middleware::from_fn(move |req, next| {
+                cache_middleware(config, req, next)
+            })),
+        )
+}
+
+/// Cache key for an execution result: `execution:{execution_id}`
+pub fn execution_cache_key(execution_id: &str) -> String {
+    format!("execution:{}", execution_id)
+}
+
+/// Validate a submitted execution tree and store it.
+///
+/// Returns 200 with the validation summary, or 422 with the summary's
+/// `validation_errors` when the tree is invalid. Invalid results are stored
+/// too, with their `validation_errors`, so they can be listed with
+/// `valid=false`. Span lists that cannot be assembled into a tree at all are
+/// rejected without being stored.
+async fn submit_execution(
+    State(state): State<Arc<AppState>>,
+    Extension(cache): Extension<Arc<ResponseCache>>,
+    Json(submission): Json<ExecutionSubmission>,
+) -> Result<(StatusCode, Json<ExecutionSummary>), ApiError> {
+    let result = match submission {
+        ExecutionSubmission::Spans { spans } => {
+            // Echo the submitted id back even when no tree can be assembled
+            let execution_id = spans
+                .first()
+                .map(|span| span.execution_id.clone())
+                .unwrap_or_default();
+            match ExecutionResult::from_spans(spans) {
+                Ok(result) => result,
+                Err(e) => {
+                    warn!(execution_id = %execution_id, error = %e, "Rejected execution spans");
+                    return Ok((
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        Json(ExecutionSummary {
+                            execution_id,
+                            valid: false,
+                            validation_errors: vec![e.to_string()],
+                            agent_span_count: 0,
+                            total_artifacts: 0,
+                            total_duration_ms: None,
+                        }),
+                    ));
+                }
+            }
+        }
+        // Recompute derived fields rather than trusting the submitted ones
+        ExecutionSubmission::Result(result) => {
+            ExecutionResult::new(result.repo_span, result.agent_spans).validate()
+        }
+    };
+
+    let summary = ExecutionSummary::from(&result);
+    let status = if result.valid {
+        info!(
+            execution_id = %result.execution_id,
+            agent_spans = result.agent_spans.len(),
+            "Execution result stored"
+        );
+        StatusCode::OK
+    } else {
+        warn!(
+            execution_id = %result.execution_id,
+            errors = ?result.validation_errors,
+            "Stored invalid execution"
+        );
+        StatusCode::UNPROCESSABLE_ENTITY
+    };
+    let cache_key = execution_cache_key(&result.execution_id);
+    state.executions.insert(result).await.map_err(|e| {
+        error!("Failed to store execution result: {}", e);
+        ApiError::internal_error()
+    })?;
+    cache.invalidate(&cache_key).await;
+
+    Ok((status, Json(summary)))
+}
+
+/// List stored executions filtered by `repo_name`, `since` and `valid`,
+/// newest first, as summaries without their span trees
+async fn list_executions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ExecutionQuery>,
+) -> Result<Json<ExecutionListResponse>, ApiError> {
+    let items = state.executions.list(&query).await.map_err(|e| {
+        error!("Failed to list execution results: {}", e);
+        ApiError::internal_error()
+    })?;
+
+    Ok(Json(ExecutionListResponse {
+        total: items.len(),
+        items,
+    }))
+}
+
+/// Retrieve a previously stored execution result, from the cache if possible
+async fn get_execution(
+    State(state): State<Arc<AppState>>,
+    Extension(cache): Extension<Arc<ResponseCache>>,
+    Path(execution_id): Path<String>,
+) -> Result<Response, ApiError> {
+    let cache_key = execution_cache_key(&execution_id);
+    if let Some(body) = cache.get(&cache_key).await {
+        debug!(execution_id = %execution_id, "Execution result served from cache");
+        return Ok(json_response(body, "HIT"));
+    }
+    // Taken before the read, so a POST racing with it keeps this body uncached
+    let generation = cache.generation();
+
+    let result = state.executions.get(&execution_id).await.map_err(|e| {
+        error!("Failed to load execution result: {}", e);
+        ApiError::internal_error()
+    })?;
+    let result =
+        result.ok_or_else(|| ApiError::not_found(&format!("Execution {}", execution_id)))?;
+
+    let body = serde_json::to_vec(&result).map_err(|e| {
+        error!("Failed to serialize execution result: {}", e);
+        ApiError::internal_error()
+    })?;
+    let body = Bytes::from(body);
+    cache
+        .insert_if_current(cache_key, body.clone(), generation)
+        .await;
+
+    Ok(json_response(body, "MISS"))
+}
+
+/// A JSON response tagged with its cache status
+fn json_response(body: Bytes, cache_status: &'static str) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "application/json"),
+            (HeaderName::from_static(CACHE_STATUS_HEADER), cache_status),
+        ],
+        body,
+    )
+        .into_response()
+}
@@ -0,0 +1,176 @@
+use axum::{extract::State, http::StatusCode, middleware, routing::post, Json, Router};
+use chrono::{DateTime, Utc};
+use llm_observatory_adapters::upstream::schema::{SchemaAdapter, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::middleware::auth::{require_role, JwtValidator, RequireAuth, Role};
+use crate::models::{AppState, ObservationEvent};
+use crate::routes::observations::store_observation;
+
+/// `source` of the observations accepted spans are stored as
+pub const SPAN_OBSERVATION_SOURCE: &str = "traces";
+
+/// `event_type` of the observations accepted spans are stored as
+pub const SPAN_EVENT_TYPE: &str = "span";
+
+/// Span ingestion routes without authorization, for trusted in-process callers
+pub fn routes() -> Router<Arc<AppState>> {
+    Router::new().route("/api/v1/traces", post(receive_spans))
+}
+
+/// Span ingestion routes guarded by role: posting requires `Developer` (or
+/// any role outranking it), like the other write routes
+pub fn authorized_routes(jwt_validator: Arc<JwtValidator>) -> Router<Arc<AppState>> {
+    let writers = RequireAuth::min_role(jwt_validator, Role::Developer);
+    routes().route_layer(middleware::from_fn_with_state(writers, require_role))
+}
+
+/// Outcome for a single span in an ingestion request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpanIngestResult {
+    /// Position of the span in the submitted array
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span_id: Option<String>,
+    /// "accepted", "rejected" or "failed" (valid but could not be stored)
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ValidationError>,
+    /// Suspicious but legal data, see `SchemaAdapter::validate_span_json`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Whether an accepted span had already been stored
+    #[serde(default)]
+    pub duplicate: bool,
+}
+
+/// Response for a span ingestion request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpanIngestResponse {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub results: Vec<SpanIngestResult>,
+}
+
+/// Wrap a validated span as an observation event
+///
+/// The span is grouped under its `execution_id` (or `attributes.execution.id`),
+/// falling back to `trace_id` and then its own ID. Its trace and span IDs form
+/// the dedup key, so a retried request does not store a span twice.
+fn span_observation(span: &Value, span_id: &str) -> Result<ObservationEvent, ValidationError> {
+    let str_field = |value: Option<&Value>| value.and_then(Value::as_str).map(String::from);
+    let trace_id = str_field(span.get("trace_id"));
+    let execution_id = str_field(span.get("execution_id"))
+        .or_else(|| str_field(span.pointer("/attributes/execution.id")))
+        .or_else(|| trace_id.clone())
+        .unwrap_or_else(|| span_id.to_string());
+
+    let start_time = span
+        .get("start_time")
+        .or_else(|| span.pointer("/latency/start_time"))
+        .and_then(Value::as_str)
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .ok_or_else(|| ValidationError {
+            message: "start_time must be an RFC 3339 timestamp".to_string(),
+            field_path: Some("/start_time".to_string()),
+            code: "INVALID_FORMAT".to_string(),
+        })?;
+
+    Ok(ObservationEvent {
+        source: SPAN_OBSERVATION_SOURCE.to_string(),
+        event_type: SPAN_EVENT_TYPE.to_string(),
+        execution_id,
+        timestamp: start_time.with_timezone(&Utc),
+        payload: span.clone(),
+        event_id: Some(format!(
+            "span:{}:{}",
+            trace_id.as_deref().unwrap_or_default(),
+            span_id
+        )),
+    })
+}
+
+/// POST /api/v1/traces - Ingest span JSON from the telemetry adapters
+///
+/// Accepts an array of spans, either full LLM spans or the shape produced by
+/// the adapters' `*_to_span_json` exporters. Each span is validated
+/// independently; invalid spans are reported with their validation errors and
+/// do not block the rest. Accepted spans are stored as observations (see
+/// [`SPAN_EVENT_TYPE`]), so they can be listed and streamed like any other.
+/// Responds 202 when every span was accepted and 207 otherwise.
+async fn receive_spans(
+    State(state): State<Arc<AppState>>,
+    Json(spans): Json<Vec<Value>>,
+) -> (StatusCode, Json<SpanIngestResponse>) {
+    let schema = SchemaAdapter::new();
+    let mut results = Vec::with_capacity(spans.len());
+
+    for (index, span) in spans.iter().enumerate() {
+        let span_id = span.get("span_id").and_then(Value::as_str);
+        let span_id = span_id.map(String::from);
+        let validation = schema.validate_any_span_json(span);
+
+        let event = if validation.is_valid {
+            span_observation(span, span_id.as_deref().unwrap_or_default()).map_err(|e| vec![e])
+        } else {
+            Err(validation.errors)
+        };
+        let mut result = SpanIngestResult {
+            index,
+            span_id,
+            status: "rejected".to_string(),
+            errors: Vec::new(),
+            warnings: validation.warnings,
+            duplicate: false,
+        };
+
+        match event {
+            Ok(event) => match store_observation(&state, event).await {
+                Ok(stored) => {
+                    info!(
+                        span_id = ?result.span_id,
+                        trace_id = ?span.get("trace_id").and_then(Value::as_str),
+                        name = ?span.get("name").and_then(Value::as_str),
+                        duplicate = !stored,
+                        "Span ingested"
+                    );
+                    result.status = "accepted".to_string();
+                    result.duplicate = !stored;
+                }
+                Err(_) => {
+                    result.status = "failed".to_string();
+                    result.errors.push(ValidationError {
+                        message: "span could not be stored".to_string(),
+                        field_path: None,
+                        code: "STORAGE_ERROR".to_string(),
+                    });
+                }
+            },
+            Err(errors) => {
+                warn!(index, errors = errors.len(), "Rejected invalid span");
+                result.errors = errors;
+            }
+        }
+        results.push(result);
+    }
+
+    let accepted = results.iter().filter(|r| r.errors.is_empty()).count();
+    let rejected = results.len() - accepted;
+
+    let status = if rejected == 0 {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+    (
+        status,
+        Json(SpanIngestResponse {
+            accepted,
+            rejected,
+            results,
+        }),
+    )
+}
@@ -1,5 +1,7 @@
 pub mod costs;
+pub mod executions;
 pub mod export;
+pub mod ingest;
 pub mod metrics;
 pub mod models;
 pub mod observations;
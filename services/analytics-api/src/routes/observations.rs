@@ -1,35 +1,94 @@
-use axum::{http::StatusCode, routing::post, Json, Router};
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    http::StatusCode,
+    middleware,
+    response::Response,
+    routing::{get, post},
+    Json, Router,
+};
 use serde_json::Value;
 use std::sync::Arc;
-use tracing::info;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{debug, error, info, warn};
 
-use crate::models::AppState;
+use crate::errors::ApiError;
+use crate::middleware::auth::{require_role, JwtValidator, RequireAuth, Role};
+use crate::models::{
+    AppState, BatchItemResult, BatchObservationResponse, ObservationEvent, ObservationListResponse,
+    ObservationQuery, ObservationResponse, MAX_OBSERVATION_PAGE_SIZE,
+};
 
-#[derive(Debug, Deserialize)]
-pub struct ObservationEvent {
-    pub source: String,
-    pub event_type: String,
-    pub execution_id: String,
-    pub timestamp: DateTime<Utc>,
-    #[serde(default)]
-    pub payload: Value,
+/// Observation routes without authorization, for trusted in-process callers
+pub fn routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route(
+            "/api/v1/observations",
+            post(receive_observation).get(list_observations),
+        )
+        .route(
+            "/api/v1/observations/batch",
+            post(receive_observation_batch),
+        )
+        .route("/api/v1/observations/stream", get(stream_observations))
 }
 
-#[derive(Debug, Serialize)]
-pub struct ObservationResponse {
-    pub status: &'static str,
-    pub execution_id: String,
+/// Observation routes guarded by role: posting requires `Developer` and
+/// listing or streaming requires `Viewer` (or any role outranking them)
+pub fn authorized_routes(jwt_validator: Arc<JwtValidator>) -> Router<Arc<AppState>> {
+    let writers = RequireAuth::min_role(jwt_validator.clone(), Role::Developer);
+    let readers = RequireAuth::min_role(jwt_validator, Role::Viewer);
+
+    let write_routes = Router::new()
+        .route("/api/v1/observations", post(receive_observation))
+        .route(
+            "/api/v1/observations/batch",
+            post(receive_observation_batch),
+        )
+        .route_layer(middleware::from_fn_with_state(writers, require_role));
+    let read_routes = Router::new()
+        .route("/api/v1/observations", get(list_observations))
+        .route("/api/v1/observations/stream", get(stream_observations))
+        .route_layer(middleware::from_fn_with_state(readers, require_role));
+
+    write_routes.merge(read_routes)
 }
 
-pub fn routes() -> Router<Arc<AppState>> {
-    Router::new().route("/api/v1/observations", post(receive_observation))
+/// Store an event and update the service metrics, mapping storage failures
+/// to an internal error. Returns `false` if the event was a duplicate.
+pub(crate) async fn store_observation(
+    state: &AppState,
+    event: ObservationEvent,
+) -> Result<bool, ApiError> {
+    let source = event.source.clone();
+    let published = (state.observation_feed.subscriber_count() > 0).then(|| event.clone());
+    let outcome = state.observations.insert(event).await.map_err(|e| {
+        error!("Failed to store observation: {}", e);
+        ApiError::internal_error()
+    })?;
+    if !outcome.stored {
+        return Ok(false);
+    }
+    if outcome.evicted > 0 {
+        state.metrics.record_observations_dropped(outcome.evicted);
+    }
+
+    state.metrics.record_observation(&source);
+    if let Ok(size) = state.observations.len().await {
+        state.metrics.set_observation_store_size(size);
+    }
+    if let Some(event) = published {
+        state.observation_feed.publish(event);
+    }
+    Ok(true)
 }
 
 async fn receive_observation(
+    State(state): State<Arc<AppState>>,
     Json(event): Json<ObservationEvent>,
-) -> (StatusCode, Json<ObservationResponse>) {
+) -> Result<(StatusCode, Json<ObservationResponse>), ApiError> {
     info!(
         source = %event.source,
         event_type = %event.event_type,
@@ -38,11 +97,167 @@ async fn receive_observation(
         "Observation received"
     );
 
-    (
-        StatusCode::ACCEPTED,
+    let execution_id = event.execution_id.clone();
+    let duplicate = !store_observation(&state, event).await?;
+    let status = if duplicate {
+        info!(execution_id = %execution_id, "Duplicate observation ignored");
+        StatusCode::OK
+    } else {
+        StatusCode::ACCEPTED
+    };
+
+    Ok((
+        status,
         Json(ObservationResponse {
             status: "accepted",
-            execution_id: event.execution_id,
+            execution_id,
+            duplicate,
+        }),
+    ))
+}
+
+/// Accept many events at once. Invalid events are rejected individually and
+/// events the store fails to take are reported as `failed`, without undoing
+/// the rest; the response is 202 when every event was accepted and 207
+/// otherwise. Retrying only the failed items avoids duplicates; events with an
+/// `event_id` are deduplicated anyway.
+async fn receive_observation_batch(
+    State(state): State<Arc<AppState>>,
+    Json(events): Json<Vec<Value>>,
+) -> (StatusCode, Json<BatchObservationResponse>) {
+    let mut results = Vec::with_capacity(events.len());
+
+    for (index, value) in events.into_iter().enumerate() {
+        match ObservationEvent::from_value(value) {
+            Ok(event) => {
+                let execution_id = event.execution_id.clone();
+                let result = match store_observation(&state, event).await {
+                    Ok(stored) => BatchItemResult {
+                        index,
+                        status: "accepted".to_string(),
+                        execution_id: Some(execution_id),
+                        error: None,
+                        duplicate: !stored,
+                    },
+                    Err(_) => BatchItemResult {
+                        index,
+                        status: "failed".to_string(),
+                        execution_id: Some(execution_id),
+                        error: Some("observation could not be stored".to_string()),
+                        duplicate: false,
+                    },
+                };
+                results.push(result);
+            }
+            Err(reason) => {
+                warn!(index, reason = %reason, "Rejected observation in batch");
+                results.push(BatchItemResult {
+                    index,
+                    status: "rejected".to_string(),
+                    execution_id: None,
+                    error: Some(reason),
+                    duplicate: false,
+                });
+            }
+        }
+    }
+
+    let accepted = results.iter().filter(|r| r.error.is_none()).count();
+    let rejected = results.len() - accepted;
+    info!(accepted, rejected, "Observation batch received");
+
+    let status = if rejected == 0 {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+    (
+        status,
+        Json(BatchObservationResponse {
+            accepted,
+            rejected,
+            results,
         }),
     )
 }
+
+/// List stored observations, newest first, paginated with `limit`/`offset`
+async fn list_observations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ObservationQuery>,
+) -> Result<Json<ObservationListResponse>, ApiError> {
+    let limit = query.limit();
+    if limit == 0 || limit > MAX_OBSERVATION_PAGE_SIZE {
+        return Err(ApiError::invalid_field(
+            "limit",
+            &format!("must be between 1 and {}", MAX_OBSERVATION_PAGE_SIZE),
+        ));
+    }
+
+    let page = state.observations.query(&query).await.map_err(|e| {
+        error!("Failed to query observations: {}", e);
+        ApiError::internal_error()
+    })?;
+
+    let end = query.offset() + page.items.len();
+    Ok(Json(ObservationListResponse {
+        next_offset: (end < page.total).then_some(end),
+        items: page.items,
+        total: page.total,
+    }))
+}
+
+/// Upgrade to a WebSocket pushing each newly stored observation as a JSON
+/// text message, filtered by the `source`/`execution_id` query parameters
+/// (`limit` and `offset` are ignored)
+///
+/// Under [`authorized_routes`] the upgrade request needs the usual
+/// `Authorization: Bearer` header. Browsers cannot set headers on a
+/// WebSocket handshake, so browser clients must connect through a backend
+/// that adds it; the stream is meant for service-to-service consumers.
+async fn stream_observations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ObservationQuery>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    // Subscribe before upgrading so events stored during the handshake arrive
+    let events = state.observation_feed.subscribe();
+    ws.on_upgrade(move |socket| forward_observations(socket, events, query))
+}
+
+/// Forward matching events to the client until it disconnects
+async fn forward_observations(
+    mut socket: WebSocket,
+    mut events: broadcast::Receiver<ObservationEvent>,
+    query: ObservationQuery,
+) {
+    loop {
+        tokio::select! {
+            received = events.recv() => match received {
+                Ok(event) if query.matches(&event) => {
+                    let text = match serde_json::to_string(&event) {
+                        Ok(text) => text,
+                        Err(e) => {
+                            error!("Failed to serialize streamed observation: {}", e);
+                            continue;
+                        }
+                    };
+                    if socket.send(Message::Text(text)).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(skipped, "Observation stream client fell behind");
+                }
+                Err(RecvError::Closed) => break,
+            },
+            // Pings are answered by axum; anything else from the client is ignored
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    debug!("Observation stream client disconnected");
+}
@@ -57,10 +57,14 @@ async fn get_quality_metrics(
     );
 
     // Try to get from cache
-    let mut redis_conn = state.redis_client.get_async_connection().await.map_err(|e| {
-        error!("Redis connection error: {}", e);
-        ApiError::Internal("Failed to connect to cache".to_string())
-    })?;
+    let mut redis_conn = state
+        .redis_client
+        .get_async_connection()
+        .await
+        .map_err(|e| {
+            error!("Redis connection error: {}", e);
+            ApiError::Internal("Failed to connect to cache".to_string())
+        })?;
 
     // Check cache
     if let Ok(cached) = redis_conn.get::<_, String>(&cache_key).await {
@@ -72,13 +76,10 @@ async fn get_quality_metrics(
 
     // Query database
     let service = TimescaleDBService::new(state.db_pool.clone());
-    let metrics = service
-        .get_quality_metrics(&query)
-        .await
-        .map_err(|e| {
-            error!("Database query error: {}", e);
-            ApiError::Internal(format!("Failed to fetch quality metrics: {}", e))
-        })?;
+    let metrics = service.get_quality_metrics(&query).await.map_err(|e| {
+        error!("Database query error: {}", e);
+        ApiError::Internal(format!("Failed to fetch quality metrics: {}", e))
+    })?;
 
     // Cache the result
     let serialized = serde_json::to_string(&metrics).unwrap();
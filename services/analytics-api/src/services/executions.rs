@@ -0,0 +1,68 @@
+///! Storage for validated execution results
+///!
+///! The [`ExecutionStore`] trait decouples the execution endpoints from the
+///! backing storage. [`InMemoryExecutionStore`] is the default and keeps
+///! results in a `HashMap` keyed by `execution_id` for the lifetime of the
+///! process.
+use crate::models::{ExecutionListItem, ExecutionQuery};
+use anyhow::Result;
+use async_trait::async_trait;
+use llm_observatory_core::execution::ExecutionResult;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// Storage backend for validated execution results
+#[async_trait]
+pub trait ExecutionStore: Send + Sync {
+    /// Store a result, replacing any earlier result with the same `execution_id`
+    async fn insert(&self, result: ExecutionResult) -> Result<()>;
+
+    /// Look up a result by `execution_id`
+    async fn get(&self, execution_id: &str) -> Result<Option<ExecutionResult>>;
+
+    /// Summaries of stored results matching the query, ordered by repo span
+    /// `start_time` descending
+    async fn list(&self, query: &ExecutionQuery) -> Result<Vec<ExecutionListItem>>;
+}
+
+/// In-memory, `HashMap`-backed execution store
+#[derive(Debug, Default)]
+pub struct InMemoryExecutionStore {
+    results: RwLock<HashMap<String, ExecutionResult>>,
+}
+
+impl InMemoryExecutionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ExecutionStore for InMemoryExecutionStore {
+    async fn insert(&self, result: ExecutionResult) -> Result<()> {
+        self.results
+            .write()
+            .await
+            .insert(result.execution_id.clone(), result);
+        Ok(())
+    }
+
+    async fn get(&self, execution_id: &str) -> Result<Option<ExecutionResult>> {
+        Ok(self.results.read().await.get(execution_id).cloned())
+    }
+
+    async fn list(&self, query: &ExecutionQuery) -> Result<Vec<ExecutionListItem>> {
+        let results = self.results.read().await;
+        let mut items: Vec<ExecutionListItem> = results
+            .values()
+            .filter(|r| query.matches(r))
+            .map(ExecutionListItem::from)
+            .collect();
+        items.sort_by(|a, b| {
+            b.start_time
+                .cmp(&a.start_time)
+                .then_with(|| a.execution_id.cmp(&b.execution_id))
+        });
+        Ok(items)
+    }
+}
@@ -1 +1,4 @@
+pub mod executions;
+pub mod observations;
+pub mod service_metrics;
 pub mod timescaledb;
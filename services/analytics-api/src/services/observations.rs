@@ -0,0 +1,209 @@
+///! Storage for ingested observation events
+///!
+///! The [`ObservationStore`] trait decouples the observation endpoints from
+///! the backing storage. [`InMemoryObservationStore`] is the default and keeps
+///! events in a bounded ring buffer, evicting the oldest events once it holds
+///! `capacity` of them.
+///!
+///! Events carrying an `event_id` are deduplicated: a second event with the
+///! same id is ignored while the first is still stored.
+///!
+///! [`ObservationFeed`] broadcasts newly stored events to live subscribers,
+///! such as the observation WebSocket stream.
+use crate::models::{ObservationEvent, ObservationPage, ObservationQuery};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::{HashSet, VecDeque};
+use tokio::sync::{broadcast, RwLock};
+
+/// Events held by [`InMemoryObservationStore::new`] before eviction starts
+pub const DEFAULT_OBSERVATION_STORE_CAPACITY: usize = 100_000;
+
+/// Events buffered per feed subscriber before a slow subscriber starts
+/// missing events
+pub const OBSERVATION_FEED_CAPACITY: usize = 1024;
+
+/// Outcome of storing a single observation event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsertOutcome {
+    /// Whether the event was stored (`false` for a duplicate `event_id`)
+    pub stored: bool,
+    /// Number of older events evicted to make room
+    pub evicted: usize,
+}
+
+impl InsertOutcome {
+    /// The event was stored without evicting anything
+    pub fn stored() -> Self {
+        Self {
+            stored: true,
+            evicted: 0,
+        }
+    }
+
+    /// The event duplicated a stored `event_id` and was ignored
+    pub fn duplicate() -> Self {
+        Self {
+            stored: false,
+            evicted: 0,
+        }
+    }
+}
+
+/// Storage backend for observation events
+#[async_trait]
+pub trait ObservationStore: Send + Sync {
+    /// Store a single observation event
+    ///
+    /// Nothing is stored when an event with the same `event_id` is already
+    /// stored. Bounded stores may evict older events to make room.
+    async fn insert(&self, event: ObservationEvent) -> Result<InsertOutcome>;
+
+    /// Return one page of stored events matching the query, ordered by
+    /// `timestamp` descending
+    async fn query(&self, query: &ObservationQuery) -> Result<ObservationPage>;
+
+    /// Number of stored events
+    async fn len(&self) -> Result<usize>;
+
+    /// Maximum number of stored events, or `None` if unbounded
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// In-memory observation store backed by a fixed-capacity ring buffer
+#[derive(Debug)]
+pub struct InMemoryObservationStore {
+    capacity: usize,
+    entries: RwLock<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Stored events, oldest first
+    events: VecDeque<ObservationEvent>,
+    event_ids: HashSet<String>,
+    dropped: u64,
+}
+
+impl InMemoryObservationStore {
+    /// Create a store holding up to [`DEFAULT_OBSERVATION_STORE_CAPACITY`] events
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_OBSERVATION_STORE_CAPACITY)
+    }
+
+    /// Create a store holding up to `capacity` events (at least one)
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: RwLock::default(),
+        }
+    }
+
+    /// Total number of events evicted since the store was created
+    pub async fn dropped_count(&self) -> u64 {
+        self.entries.read().await.dropped
+    }
+}
+
+impl Default for InMemoryObservationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ObservationStore for InMemoryObservationStore {
+    async fn insert(&self, event: ObservationEvent) -> Result<InsertOutcome> {
+        let mut entries = self.entries.write().await;
+        if let Some(event_id) = &event.event_id {
+            if !entries.event_ids.insert(event_id.clone()) {
+                return Ok(InsertOutcome::duplicate());
+            }
+        }
+
+        let mut outcome = InsertOutcome::stored();
+        while entries.events.len() >= self.capacity {
+            let Some(oldest) = entries.events.pop_front() else {
+                break;
+            };
+            if let Some(event_id) = &oldest.event_id {
+                entries.event_ids.remove(event_id);
+            }
+            outcome.evicted += 1;
+        }
+        entries.dropped += outcome.evicted as u64;
+        entries.events.push_back(event);
+        Ok(outcome)
+    }
+
+    async fn query(&self, query: &ObservationQuery) -> Result<ObservationPage> {
+        let entries = self.entries.read().await;
+        let mut matching: Vec<&ObservationEvent> =
+            entries.events.iter().filter(|e| query.matches(e)).collect();
+        matching.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+        Ok(ObservationPage {
+            total: matching.len(),
+            items: matching
+                .into_iter()
+                .skip(query.offset())
+                .take(query.limit())
+                .cloned()
+                .collect(),
+        })
+    }
+
+    async fn len(&self) -> Result<usize> {
+        Ok(self.entries.read().await.events.len())
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.capacity)
+    }
+}
+
+/// Broadcasts newly stored observation events to live subscribers
+///
+/// Subscribers that fall more than the feed's capacity behind skip the
+/// events they missed rather than slowing down ingestion.
+#[derive(Debug, Clone)]
+pub struct ObservationFeed {
+    sender: broadcast::Sender<ObservationEvent>,
+}
+
+impl ObservationFeed {
+    /// Create a feed buffering [`OBSERVATION_FEED_CAPACITY`] events per subscriber
+    pub fn new() -> Self {
+        Self::with_capacity(OBSERVATION_FEED_CAPACITY)
+    }
+
+    /// Create a feed buffering `capacity` events per subscriber (at least one)
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    /// Send an event to every current subscriber
+    pub fn publish(&self, event: ObservationEvent) {
+        // Sending only fails when nobody is subscribed
+        let _ = self.sender.send(event);
+    }
+
+    /// Receive every event published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<ObservationEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Number of live subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for ObservationFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
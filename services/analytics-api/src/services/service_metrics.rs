@@ -0,0 +1,134 @@
+//! Prometheus metrics for monitoring the Analytics API itself.
+//!
+//! Metrics are recorded through the global `metrics` recorder installed at
+//! startup and exposed in Prometheus text format on `GET /metrics`:
+//! - `observations_received_total` - observations accepted, by `source`
+//! - `observation_store_size` - events held by the observation store
+//! - `observations_dropped_total` - events evicted from a full observation store
+//! - `http_request_duration_seconds` - handler latency, by method, path and status
+//! - `execution_spans_total` - finished repo execution spans, by status
+
+use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
+
+/// Service-level metrics collector.
+///
+/// Cheap to clone; all clones record into the same global recorder.
+#[derive(Debug, Clone)]
+pub struct ServiceMetrics {
+    _private: (),
+}
+
+impl ServiceMetrics {
+    /// Create a new metrics collector and register metric descriptions.
+    pub fn new() -> Self {
+        Self::register_metrics();
+        Self { _private: () }
+    }
+
+    /// Register all Prometheus metrics with descriptions.
+    fn register_metrics() {
+        describe_counter!(
+            "observations_received_total",
+            "Total number of observations accepted, by source"
+        );
+        describe_gauge!(
+            "observation_store_size",
+            "Number of observations held by the observation store"
+        );
+        describe_counter!(
+            "observations_dropped_total",
+            "Total number of observations evicted from a full observation store"
+        );
+        describe_histogram!(
+            "http_request_duration_seconds",
+            "Duration of request handling in seconds"
+        );
+        describe_counter!(
+            "execution_spans_total",
+            "Total number of finished repo execution spans, by status"
+        );
+    }
+
+    /// Record an accepted observation from `source`.
+    pub fn record_observation(&self, source: &str) {
+        counter!(
+            "observations_received_total",
+            "source" => source.to_string()
+        )
+        .increment(1);
+    }
+
+    /// Set the current number of stored observations.
+    pub fn set_observation_store_size(&self, size: usize) {
+        gauge!("observation_store_size").set(size as f64);
+    }
+
+    /// Record observations evicted from a full store.
+    pub fn record_observations_dropped(&self, count: usize) {
+        counter!("observations_dropped_total").increment(count as u64);
+    }
+
+    /// Record how long a handler took to produce a response.
+    pub fn record_handler_latency(
+        &self,
+        method: &str,
+        path: &str,
+        status: u16,
+        duration_secs: f64,
+    ) {
+        histogram!(
+            "http_request_duration_seconds",
+            "method" => method.to_string(),
+            "path" => path.to_string(),
+            "status" => status.to_string()
+        )
+        .record(duration_secs);
+    }
+
+    /// Record a finished repo execution span.
+    pub fn record_execution_span(&self, status: &str) {
+        counter!(
+            "execution_spans_total",
+            "status" => status.to_string()
+        )
+        .increment(1);
+    }
+}
+
+impl Default for ServiceMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics_exporter_prometheus::PrometheusBuilder;
+
+    #[test]
+    fn test_metrics_render_prometheus_text() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            let metrics = ServiceMetrics::new();
+            metrics.record_observation("agent-a");
+            metrics.record_observation("agent-a");
+            metrics.record_observation("agent-b");
+            metrics.set_observation_store_size(3);
+            metrics.record_observations_dropped(2);
+            metrics.record_handler_latency("GET", "/api/v1/traces", 200, 0.012);
+            metrics.record_execution_span("COMPLETED");
+        });
+
+        let output = handle.render();
+        assert!(output.contains("# TYPE observations_received_total counter"));
+        assert!(output.contains("observations_received_total{source=\"agent-a\"} 2"));
+        assert!(output.contains("observations_received_total{source=\"agent-b\"} 1"));
+        assert!(output.contains("observation_store_size 3"));
+        assert!(output.contains("observations_dropped_total 2"));
+        assert!(output.contains("http_request_duration_seconds"));
+        assert!(output.contains("execution_spans_total{status=\"COMPLETED\"} 1"));
+    }
+}